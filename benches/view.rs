@@ -0,0 +1,46 @@
+//! Compare parsing a typical INVITE into the owned `SipMessage`
+//! type with the borrowed `MessageView` path.
+//!
+//! Run with `cargo bench --bench view`.
+
+use libsip::*;
+
+use nom::error::VerboseError;
+
+use std::{hint::black_box, time::Instant};
+
+const ITERATIONS: u32 = 100_000;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:alice@pc33.atlanta.com>\r\n\
+Content-Type: application/sdp\r\n\
+Content-Length: 4\r\n\r\nv=0\n";
+
+fn bench<F: FnMut()>(name: &str, mut f: F) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    println!("{:<24} {:>8} ns/iter", name, elapsed.as_nanos() / u128::from(ITERATIONS));
+}
+
+fn main() {
+    bench("parse_message", || {
+        black_box(parse_message::<VerboseError<&[u8]>>(black_box(INVITE)).unwrap());
+    });
+    bench("parse_message_view", || {
+        black_box(parse_message_view::<(&[u8], nom::error::ErrorKind)>(black_box(INVITE)).unwrap());
+    });
+    bench("view and route", || {
+        let (_, view) = parse_message_view::<(&[u8], nom::error::ErrorKind)>(black_box(INVITE)).unwrap();
+        black_box(view.header("Via").map(|header| header.value()));
+        black_box(view.request_uri());
+    });
+}
@@ -0,0 +1,173 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::io::{
+    Result as IoResult,
+    Error as IoError,
+    ErrorKind as IoErrorKind
+};
+
+use crate::{*, request::DEFAULT_MAX_FORWARDS};
+
+/// State of a SIP dialog (RFC 3261 section 12) as seen by one of
+/// its participants. The local and remote uris are stored without
+/// their tags, `request` adds the tags back into From and To.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Dialog {
+    pub call_id: String,
+    pub local_tag: String,
+    pub remote_tag: Option<String>,
+    pub local_uri: NamedHeader,
+    pub remote_uri: NamedHeader,
+    /// The uri of the remote party's Contact, used as the
+    /// Request-URI of requests within the dialog.
+    pub remote_target: Uri,
+    /// The CSeq number of the last request sent in this dialog.
+    pub local_cseq: u32,
+    /// The CSeq number of the last INVITE sent in this dialog,
+    /// reused by its ACK (RFC 3261 section 13.2.2.4).
+    pub invite_cseq: Option<u32>,
+    /// The CSeq number of the last request received in this dialog.
+    pub remote_cseq: Option<u32>,
+    /// The Route headers for requests within the dialog, in the
+    /// order they are to be visited.
+    pub route_set: Vec<NamedHeader>,
+}
+
+impl Dialog {
+    /// Create the dialog of a UAC from the request it sent and the
+    /// 2xx or 101-199 response that established the dialog. The
+    /// route set is the response's Record-Route in reverse order.
+    pub fn uac(request: &SipMessage, response: &SipMessage) -> IoResult<Dialog> {
+        let headers = request.headers();
+        let from = named_header(headers.from(), "From")?;
+        let to = named_header(response.headers().to(), "To")?;
+        let local_tag = from.tag()
+            .ok_or_else(|| IoError::new(IoErrorKind::InvalidInput, "Dialog requires a From tag"))?
+            .to_string();
+        let local_cseq = cseq(headers)?;
+        Ok(Dialog {
+            call_id: call_id(headers)?,
+            local_tag,
+            remote_tag: to.tag().map(Into::into),
+            local_uri: without_tag(from),
+            remote_uri: without_tag(to),
+            remote_target: remote_target(response.headers())?,
+            local_cseq,
+            invite_cseq: if request.method() == Some(&Method::Invite) { Some(local_cseq) } else { None },
+            remote_cseq: None,
+            route_set: response.headers().record_routes().into_iter().rev().cloned().collect(),
+        })
+    }
+
+    /// Create the dialog of a UAS from the request that establishes
+    /// it. The request's To tag becomes the local tag, when it has
+    /// none a tag is generated which must then be added to the To
+    /// header of the responses. Local CSeq numbers start at 1.
+    pub fn uas(request: &SipMessage) -> IoResult<Dialog> {
+        let headers = request.headers();
+        let from = named_header(headers.from(), "From")?;
+        let to = named_header(headers.to(), "To")?;
+        Ok(Dialog {
+            call_id: call_id(headers)?,
+            local_tag: to.tag().map(Into::into).unwrap_or_else(gen_tag),
+            remote_tag: from.tag().map(Into::into),
+            local_uri: without_tag(to),
+            remote_uri: without_tag(from),
+            remote_target: remote_target(headers)?,
+            local_cseq: 0,
+            invite_cseq: None,
+            remote_cseq: Some(cseq(headers)?),
+            route_set: headers.record_routes().into_iter().cloned().collect(),
+        })
+    }
+
+    /// Start a request within the dialog. From, To, Call-ID, CSeq,
+    /// Max-Forwards and Route are filled in and the Request-URI is
+    /// chosen from the route set following RFC 3261 section 12.2.1.1.
+    /// The local CSeq is incremented except for ACK and CANCEL. ACK
+    /// reuses the sequence number of the last INVITE and CANCEL that
+    /// of the last request sent. The Via header is left to the caller
+    /// as it depends on the transport.
+    pub fn request(&mut self, method: Method) -> RequestBuilder {
+        if method != Method::Ack && method != Method::Cancel {
+            self.local_cseq += 1;
+        }
+        if method == Method::Invite {
+            self.invite_cseq = Some(self.local_cseq);
+        }
+        let cseq = match method {
+            Method::Ack => self.invite_cseq.unwrap_or(self.local_cseq),
+            _ => self.local_cseq,
+        };
+        let (uri, routes) = match self.route_set.first() {
+            Some(first) if !first.uri.lr() => {
+                let mut routes = self.route_set[1..].to_vec();
+                routes.push(NamedHeader::new(self.remote_target.clone()));
+                (first.uri.clone(), routes)
+            },
+            _ => (self.remote_target.clone(), self.route_set.clone()),
+        };
+        let mut to = self.remote_uri.clone();
+        if let Some(tag) = &self.remote_tag {
+            to = to.with_tag(tag.clone());
+        }
+        let mut builder = RequestBuilder::new(method, uri)
+            .from(self.local_uri.clone().with_tag(self.local_tag.clone()))
+            .to(to)
+            .call_id(self.call_id.clone())
+            .cseq(cseq)
+            .max_forwards(DEFAULT_MAX_FORWARDS);
+        if !routes.is_empty() {
+            builder = builder.header(Header::Route(routes));
+        }
+        builder
+    }
+
+    /// Start the PRACK acknowledging a reliable provisional response
+    /// (RFC 3262). The PRACK gets the next local CSeq like any other
+    /// request in the dialog and carries an RAck built from the
+    /// response's RSeq and CSeq. Fails when the response has no RSeq.
+    pub fn make_prack(&mut self, provisional: &SipMessage) -> IoResult<RequestBuilder> {
+        let rack = provisional.rack().ok_or_else(|| missing_header("RSeq"))?;
+        Ok(self.request(Method::PRack).header(rack))
+    }
+}
+
+fn missing_header(name: &str) -> IoError {
+    IoError::new(IoErrorKind::InvalidInput, format!("Dialog requires a {} header", name))
+}
+
+fn named_header(header: Option<Header>, name: &str) -> IoResult<NamedHeader> {
+    match header {
+        Some(Header::From(value)) | Some(Header::To(value)) => Ok(value),
+        _ => Err(missing_header(name)),
+    }
+}
+
+fn call_id(headers: &Headers) -> IoResult<String> {
+    match headers.call_id() {
+        Some(Header::CallId(call_id)) => Ok(call_id),
+        _ => Err(missing_header("Call-ID")),
+    }
+}
+
+fn cseq(headers: &Headers) -> IoResult<u32> {
+    match headers.cseq() {
+        Some(Header::CSeq(cseq, _)) => Ok(cseq),
+        _ => Err(missing_header("CSeq")),
+    }
+}
+
+fn remote_target(headers: &Headers) -> IoResult<Uri> {
+    headers.contacts()
+        .first()
+        .map(|contact| contact.uri.clone())
+        .ok_or_else(|| missing_header("Contact"))
+}
+
+fn without_tag(mut header: NamedHeader) -> NamedHeader {
+    header.params.retain(|(key, _)| !key.eq_ignore_ascii_case("tag"));
+    header
+}
@@ -120,7 +120,7 @@ impl InviteHelper {
         let mut out_headers = vec![];
         for header in self.headers.iter() {
             match header {
-                Header::CSeq(a, b) => out_headers.push(Header::CSeq(*a, *b)),
+                Header::CSeq(a, b) => out_headers.push(Header::CSeq(*a, b.clone())),
                 Header::CallId(call) => out_headers.push(Header::CallId(call.clone())),
                 Header::From(from) => out_headers.push(Header::From(from.clone())),
                 Header::To(to) => out_headers.push(Header::To(to.clone())),
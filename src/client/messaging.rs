@@ -4,7 +4,7 @@ use std::io::{
     Error as IoError
 };
 
-use crate::*;
+use crate::{*, request::DEFAULT_MAX_FORWARDS};
 
 macro_rules! impl_simple_header_method {
     ($name:ident, $variant:ident, $ty: ident) => {
@@ -31,7 +31,7 @@ impl MessageHelper {
 
     impl_simple_header_method!(to, To, NamedHeader);
 
-    impl_simple_header_method!(contact, Contact, NamedHeader);
+    impl_simple_header_method!(contact, Contact, ContactHeader);
 
     impl_simple_header_method!(call_id, CallId, String);
 
@@ -135,12 +135,12 @@ impl MessageWriter {
 
     /// Get a new Content-Type header.
     pub fn content_type(&self) -> Header {
-        Header::ContentType(ContentType::PlainText)
+        Header::ContentType(ContentType::PlainText.into())
     }
 
     /// Get a new Max-Forwards header.
     pub fn max_forwards(&self) -> Header {
-        Header::MaxForwards(70)
+        Header::MaxForwards(DEFAULT_MAX_FORWARDS.into())
     }
 
     /// Get a new Call-Id header.
@@ -11,6 +11,9 @@ pub use self::messaging::{MessageHelper, MessageWriter};
 mod invite;
 pub use self::invite::{InviteHelper, InviteWriter};
 
+mod dialog;
+pub use self::dialog::Dialog;
+
 use crate::{Header, Headers, Method, SipMessage, Uri};
 
 use std::io::Result as IoResult;
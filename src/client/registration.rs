@@ -53,7 +53,7 @@ impl RegistrationManager {
             auth_header: None,
             nonce_c: 1,
             c_nonce: None,
-            branch: format!("{:x}", md5::compute(rand::random::<[u8; 16]>())),
+            branch: gen_branch(),
             call_id: format!("{:x}", md5::compute(rand::random::<[u8; 16]>())),
             expires_header: None,
             user: None,
@@ -101,7 +101,7 @@ impl RegistrationManager {
         headers.push(Header::ContentLength(0));
         headers.push(Header::To(NamedHeader::new(to_header)));
         headers.push(Header::From(NamedHeader::new(from_header)));
-        headers.push(Header::Contact(NamedHeader::new(contact_header)));
+        headers.push(Header::Contact(NamedHeader::new(contact_header).into()));
         headers.push(Header::CSeq(self.cseq_counter, Method::Register));
         headers.push(Header::CallId(format!(
             "{}@{}",
@@ -128,7 +128,7 @@ impl RegistrationManager {
             for item in headers.into_iter() {
                 match item {
                     Header::WwwAuthenticate(auth) => {
-                        self.auth_header = Some(auth);
+                        self.auth_header = Some(auth.into());
                     },
                     Header::Expires(expire) => {
                         self.expires_header = Some(expire);
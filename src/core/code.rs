@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// Get the response code string for the given SIP response code.
 pub fn error_code_to_str(code: u32) -> Option<&'static str> {
     match code {
@@ -77,3 +79,67 @@ pub fn error_code_to_str(code: u32) -> Option<&'static str> {
         _ => None,
     }
 }
+
+/// SIP response status code. Codes that have no
+/// registered reason phrase are still representable
+/// and are classified by their first digit.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct StatusCode(u16);
+
+impl StatusCode {
+    pub fn from_u16(code: u16) -> StatusCode {
+        StatusCode(code)
+    }
+
+    /// The numeric value of this status code.
+    pub fn as_u16(&self) -> u16 {
+        self.0
+    }
+
+    /// The default reason phrase for this status code.
+    pub fn canonical_reason(&self) -> Option<&'static str> {
+        error_code_to_str(u32::from(self.0))
+    }
+
+    /// 1xx codes.
+    pub fn is_provisional(&self) -> bool {
+        self.0 / 100 == 1
+    }
+
+    /// 2xx codes.
+    pub fn is_success(&self) -> bool {
+        self.0 / 100 == 2
+    }
+
+    /// 3xx codes.
+    pub fn is_redirect(&self) -> bool {
+        self.0 / 100 == 3
+    }
+
+    /// 4xx codes.
+    pub fn is_request_failure(&self) -> bool {
+        self.0 / 100 == 4
+    }
+
+    /// 5xx codes.
+    pub fn is_server_failure(&self) -> bool {
+        self.0 / 100 == 5
+    }
+
+    /// 6xx codes.
+    pub fn is_global_failure(&self) -> bool {
+        self.0 / 100 == 6
+    }
+}
+
+impl From<u16> for StatusCode {
+    fn from(code: u16) -> StatusCode {
+        StatusCode(code)
+    }
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
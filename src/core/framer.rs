@@ -0,0 +1,128 @@
+use nom::error::VerboseError;
+
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::{
+    core::{
+        line::{parse_request_line, parse_status_line},
+        message::{parse_headers, parse_message},
+    },
+    Header, SipMessage,
+};
+
+/// Default upper bound on the size of a single buffered message.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 65_535;
+
+/// Incremental SIP message parser for stream transports such as TCP.
+///
+/// Bytes are fed in with `push` as they arrive, complete messages are
+/// returned as soon as their headers and `Content-Length` bytes of body
+/// are available, any trailing partial message is kept for the next call.
+#[derive(Debug, Clone)]
+pub struct MessageFramer {
+    buffer: Vec<u8>,
+    max_message_size: usize,
+}
+
+impl MessageFramer {
+    /// Create a new framer using `DEFAULT_MAX_MESSAGE_SIZE`.
+    pub fn new() -> MessageFramer {
+        MessageFramer::with_max_message_size(DEFAULT_MAX_MESSAGE_SIZE)
+    }
+
+    /// Create a new framer that rejects messages larger than `max` bytes.
+    pub fn with_max_message_size(max: usize) -> MessageFramer {
+        MessageFramer { buffer: vec![], max_message_size: max }
+    }
+
+    /// The largest message this framer will buffer.
+    pub fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Bytes received that are not yet part of a complete message.
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Discard any partially received message.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Append `data` to the internal buffer and return every message
+    /// that is now complete. If a message exceeds the configured maximum
+    /// size, or cannot be parsed, the buffer is cleared and an error is returned.
+    pub fn push(&mut self, data: &[u8]) -> IoResult<Vec<SipMessage>> {
+        self.buffer.extend_from_slice(data);
+        let mut messages = vec![];
+        loop {
+            // CRLF keep alives may be sent between messages.
+            let skip = self.buffer.iter().take_while(|c| **c == b'\r' || **c == b'\n').count();
+            self.buffer.drain(..skip);
+            match self.next_message_length() {
+                Ok(Some(length)) => {
+                    let message = match parse_message::<VerboseError<&[u8]>>(&self.buffer[..length]) {
+                        Ok((_, message)) => message,
+                        Err(_) => return Err(self.reset(IoErrorKind::InvalidData, "Failed to parse SIP message")),
+                    };
+                    self.buffer.drain(..length);
+                    messages.push(message);
+                },
+                Ok(None) => return Ok(messages),
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Length of the first complete message in the buffer, or `None` if more data is needed.
+    fn next_message_length(&mut self) -> IoResult<Option<usize>> {
+        let head_length = match self.buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            Some(index) => index + 4,
+            None => {
+                if self.buffer.len() > self.max_message_size {
+                    return Err(self.reset(IoErrorKind::InvalidData, "SIP message exceeds maximum size"));
+                }
+                return Ok(None);
+            },
+        };
+        let body_length = match parse_content_length(&self.buffer[..head_length]) {
+            Some(length) => length,
+            None => return Err(self.reset(IoErrorKind::InvalidData, "Failed to parse SIP message headers")),
+        };
+        let length = head_length + body_length;
+        if length > self.max_message_size {
+            return Err(self.reset(IoErrorKind::InvalidData, "SIP message exceeds maximum size"));
+        }
+        if self.buffer.len() < length {
+            Ok(None)
+        } else {
+            Ok(Some(length))
+        }
+    }
+
+    fn reset(&mut self, kind: IoErrorKind, msg: &str) -> IoError {
+        self.buffer.clear();
+        IoError::new(kind, msg)
+    }
+}
+
+impl Default for MessageFramer {
+    fn default() -> MessageFramer {
+        MessageFramer::new()
+    }
+}
+
+/// Parse the start line and headers of `head`, returning the value of
+/// the Content-Length header, a missing header counts as an empty body.
+pub(crate) fn parse_content_length(head: &[u8]) -> Option<usize> {
+    let input = match parse_request_line::<VerboseError<&[u8]>>(head) {
+        Ok((input, _)) => input,
+        Err(_) => parse_status_line::<VerboseError<&[u8]>>(head).ok()?.0,
+    };
+    let (_, headers) = parse_headers::<VerboseError<&[u8]>>(input).ok()?;
+    match headers.content_length() {
+        Some(Header::ContentLength(length)) => Some(length as usize),
+        _ => Some(0),
+    }
+}
@@ -0,0 +1,92 @@
+use nom::{
+    IResult,
+    bytes::complete::{tag, take_while, take_while1},
+    character::{is_digit, complete::char},
+    combinator::{map_res, opt},
+    error::{ErrorKind, ParseError}
+};
+
+use crate::{
+    core::{method::parse_method, version::parse_version, Method, Version},
+    parse::{parse_u32, slice_to_string_nullable},
+    uri::{parse_uri, Uri},
+};
+
+use std::fmt;
+
+/// The first line of a SIP request,
+/// e.g. `INVITE sip:bob@biloxi.com SIP/2.0`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RequestLine {
+    pub method: Method,
+    pub uri: Uri,
+    pub version: Version,
+}
+
+impl RequestLine {
+    pub fn new(method: Method, uri: Uri) -> RequestLine {
+        RequestLine {
+            method,
+            uri,
+            version: Version::default(),
+        }
+    }
+}
+
+impl fmt::Display for RequestLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.method, self.uri, self.version)
+    }
+}
+
+/// Parse a SIP request line including the trailing CRLF.
+pub fn parse_request_line<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], RequestLine, E> {
+    let (input, method) = parse_method(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, uri) = parse_uri(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, version) = parse_version(input)?;
+    let (input, _) = opt(char(' '))(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, RequestLine { method, uri, version }))
+}
+
+/// The first line of a SIP response, e.g. `SIP/2.0 200 OK`.
+/// The reason phrase may be empty.
+#[derive(Debug, PartialEq, Clone)]
+pub struct StatusLine {
+    pub version: Version,
+    pub code: u32,
+    pub reason: String,
+}
+
+impl StatusLine {
+    pub fn new<S: Into<String>>(code: u32, reason: S) -> StatusLine {
+        StatusLine {
+            version: Version::default(),
+            code,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for StatusLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.version, self.code, self.reason)
+    }
+}
+
+/// Parse a SIP status line including the trailing CRLF. Status
+/// codes outside of the 100-699 range are rejected.
+pub fn parse_status_line<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], StatusLine, E> {
+    let (input, version) = parse_version(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (rest, code) = map_res(take_while1(is_digit), parse_u32)(input)?;
+    if !(100..=699).contains(&code) {
+        return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify)));
+    }
+    let (input, _) = opt(char(' '))(rest)?;
+    let (input, reason) = map_res(take_while(|item| item != b'\r' && item != b'\n'), slice_to_string_nullable)(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, StatusLine { version, code, reason }))
+}
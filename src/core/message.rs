@@ -1,17 +1,20 @@
 use nom::{
     IResult,
-    branch::alt,
-    character::*
+    branch::alt
 };
 
-use std::fmt;
+use std::{
+    convert::TryFrom,
+    fmt,
+    io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult},
+};
 
 use crate::{
     *,
-    core::{code::error_code_to_str, method::parse_method, version::parse_version},
-    headers::parse_header,
-    parse::{parse_byte_vec, parse_u32, slice_to_string},
-    uri::parse_uri,
+    core::{line::{parse_request_line, parse_status_line, RequestLine, StatusLine}},
+    headers::{parse::parse_via_headers, parse_header},
+    parse::parse_byte_vec,
+    request::DEFAULT_MAX_FORWARDS,
 };
 
 /// Sip Protocol Message.
@@ -27,6 +30,7 @@ pub enum SipMessage {
     Response {
         code: u32,
         version: Version,
+        reason: String,
         headers: Headers,
         body: Vec<u8>,
     },
@@ -51,6 +55,16 @@ impl SipMessage {
         }
     }
 
+    /// Retreive the SIP request's method.
+    /// Returns None for responses.
+    pub fn method(&self) -> Option<&Method> {
+        if let SipMessage::Request { method, .. } = self {
+            Some(method)
+        } else {
+            None
+        }
+    }
+
     /// Retreive the SIP response's status code.
     /// Returns None for requests.
     pub fn status_code(&self) -> Option<u32> {
@@ -61,6 +75,16 @@ impl SipMessage {
         }
     }
 
+    /// Retreive the SIP response's reason phrase.
+    /// Returns None for requests.
+    pub fn reason(&self) -> Option<&str> {
+        if let SipMessage::Response { reason, .. } = self {
+            Some(reason)
+        } else {
+            None
+        }
+    }
+
     /// Retreive the body of this SIP Message.
     pub fn body(&self) -> &Vec<u8> {
         match self {
@@ -85,6 +109,18 @@ impl SipMessage {
         }
     }
 
+    /// Return the first header called `name`. The comparison ignores
+    /// case and treats compact header names as their full form.
+    pub fn header(&self, name: &str) -> Option<&Header> {
+        self.headers().iter().find(|h| h.is(name))
+    }
+
+    /// Iterate over every header called `name` in the order they
+    /// appear in the message, matched the same way as `header`.
+    pub fn headers_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Header> + 'a {
+        self.headers().iter().filter(move |h| h.is(name))
+    }
+
     /// Retreive a mutable reference to the SIP Message's header list.
     pub fn headers_mut(&mut self) -> &mut Headers {
         match self {
@@ -92,6 +128,252 @@ impl SipMessage {
             SipMessage::Response { headers, .. } => headers,
         }
     }
+
+    /// Set the Content-Length header to the current length of the body,
+    /// adding the header when the message does not have one yet.
+    pub fn update_content_length(&mut self) {
+        let length = self.body().len() as u32;
+        let headers = self.headers_mut();
+        for h in headers.0.iter_mut() {
+            if let Header::ContentLength(value) = h {
+                *value = length;
+                return;
+            }
+        }
+        headers.push(Header::ContentLength(length));
+    }
+
+    /// Create a response to this request. The Via, From, To, Call-ID
+    /// and CSeq headers are copied unchanged, except that a To tag is
+    /// generated for 2xx responses when the request had none. Record-Route
+    /// is copied into 101-299 responses as they may establish a dialog.
+    /// When `reason` is None the canonical reason phrase is used.
+    pub fn make_response(&self, code: u32, reason: Option<&str>) -> SipMessage {
+        let mut headers = Headers::new();
+        for header in self.headers().iter() {
+            match header {
+                Header::Via(_) | Header::From(_) | Header::CallId(_) | Header::CSeq(_, _) => {
+                    headers.push(header.clone())
+                },
+                Header::To(to) if (200..300).contains(&code) && to.tag().is_none() => {
+                    headers.push(Header::To(to.clone().with_tag(gen_tag())))
+                },
+                Header::To(_) => headers.push(header.clone()),
+                Header::RecordRoute(_) if (101..300).contains(&code) => headers.push(header.clone()),
+                _ => {},
+            }
+        }
+        headers.push(Header::ContentLength(0));
+        let reason = match reason {
+            Some(reason) => reason.to_string(),
+            None => StatusCode::from_u16(code as u16).canonical_reason().unwrap_or_default().to_string(),
+        };
+        SipMessage::Response {
+            code,
+            version: Version::default(),
+            reason,
+            headers,
+            body: vec![],
+        }
+    }
+
+    /// Create the ACK for `response`, a final response to this INVITE.
+    ///
+    /// A non-2xx ACK belongs to the INVITE transaction: it is sent to
+    /// the same Request-URI with the INVITE's top Via (and so its branch)
+    /// and Route headers. A 2xx ACK is a new transaction sent to the
+    /// response's Contact with a fresh branch, routed through the
+    /// reversed Record-Route set. In both cases the CSeq keeps the
+    /// INVITE's sequence number and To is taken from the response.
+    pub fn make_ack(&self, response: &SipMessage) -> IoResult<SipMessage> {
+        let (method, uri) = match self {
+            SipMessage::Request { method, uri, .. } => (method, uri),
+            SipMessage::Response { .. } => {
+                return Err(IoError::new(IoErrorKind::InvalidInput, "ACK can only be created from an INVITE request"))
+            },
+        };
+        if method != &Method::Invite {
+            return Err(IoError::new(IoErrorKind::InvalidInput, "ACK can only be created from an INVITE request"));
+        }
+        let code = match response.status_code() {
+            Some(code) if code >= 200 => code,
+            _ => return Err(IoError::new(IoErrorKind::InvalidInput, "ACK requires a final response")),
+        };
+        let missing = |name| IoError::new(IoErrorKind::InvalidInput, format!("ACK requires a {} header", name));
+        let via = self.headers().vias().first().map(|via| (*via).clone()).ok_or_else(|| missing("Via"))?;
+        let from = self.headers().from().ok_or_else(|| missing("From"))?;
+        let call_id = self.headers().call_id().ok_or_else(|| missing("Call-ID"))?;
+        let cseq = match self.headers().cseq() {
+            Some(Header::CSeq(cseq, _)) => cseq,
+            _ => return Err(missing("CSeq")),
+        };
+        let to = response.headers().to().ok_or_else(|| missing("To"))?;
+
+        let mut headers = Headers::new();
+        let uri = if (200..300).contains(&code) {
+            let contact = response.headers().contacts().first().map(|contact| contact.uri.clone());
+            headers.push(Header::Via(via.with_branch(gen_branch())));
+            headers.push(Header::MaxForwards(DEFAULT_MAX_FORWARDS.into()));
+            let routes: Vec<NamedHeader> = response.headers().record_routes().into_iter().rev().cloned().collect();
+            if !routes.is_empty() {
+                headers.push(Header::Route(routes));
+            }
+            contact.ok_or_else(|| missing("Contact"))?
+        } else {
+            headers.push(Header::Via(via));
+            headers.push(Header::MaxForwards(DEFAULT_MAX_FORWARDS.into()));
+            for header in self.headers().iter() {
+                if let Header::Route(_) = header {
+                    headers.push(header.clone());
+                }
+            }
+            uri.clone()
+        };
+        headers.push(to);
+        headers.push(from);
+        headers.push(call_id);
+        headers.push(Header::CSeq(cseq, Method::Ack));
+        headers.push(Header::ContentLength(0));
+        Ok(SipMessage::Request {
+            method: Method::Ack,
+            uri,
+            version: Version::default(),
+            headers,
+            body: vec![],
+        })
+    }
+
+    /// Create a CANCEL for this INVITE. The Request-URI, Call-ID, From,
+    /// CSeq sequence number and Route headers are copied, To is copied
+    /// without its tag and only the top Via is kept so the CANCEL
+    /// matches the INVITE's client transaction branch. Returns an
+    /// InvalidInput error when this is not an INVITE or one of those
+    /// headers is missing.
+    pub fn make_cancel(&self) -> IoResult<SipMessage> {
+        let (method, uri) = match self {
+            SipMessage::Request { method, uri, .. } => (method, uri),
+            SipMessage::Response { .. } => {
+                return Err(IoError::new(IoErrorKind::InvalidInput, "CANCEL can only be created from an INVITE request"))
+            },
+        };
+        if method != &Method::Invite {
+            return Err(IoError::new(IoErrorKind::InvalidInput, "CANCEL can only be created from an INVITE request"));
+        }
+        let missing = |name| IoError::new(IoErrorKind::InvalidInput, format!("CANCEL requires a {} header", name));
+        let via = self.headers().vias().first().map(|via| (*via).clone()).ok_or_else(|| missing("Via"))?;
+        let from = self.headers().from().ok_or_else(|| missing("From"))?;
+        let call_id = self.headers().call_id().ok_or_else(|| missing("Call-ID"))?;
+        let cseq = match self.headers().cseq() {
+            Some(Header::CSeq(cseq, _)) => cseq,
+            _ => return Err(missing("CSeq")),
+        };
+        let mut to = match self.headers().to() {
+            Some(Header::To(to)) => to,
+            _ => return Err(missing("To")),
+        };
+        to.params.retain(|(key, _)| !key.eq_ignore_ascii_case("tag"));
+
+        let mut headers = Headers::new();
+        headers.push(Header::Via(via));
+        headers.push(Header::MaxForwards(DEFAULT_MAX_FORWARDS.into()));
+        for header in self.headers().iter() {
+            if let Header::Route(_) = header {
+                headers.push(header.clone());
+            }
+        }
+        headers.push(Header::To(to));
+        headers.push(from);
+        headers.push(call_id);
+        headers.push(Header::CSeq(cseq, Method::Cancel));
+        headers.push(Header::ContentLength(0));
+        Ok(SipMessage::Request {
+            method: Method::Cancel,
+            uri: uri.clone(),
+            version: Version::default(),
+            headers,
+            body: vec![],
+        })
+    }
+
+    /// Build the RAck header acknowledging this reliable provisional
+    /// response (RFC 3262), from its RSeq and the CSeq of the INVITE
+    /// it answers. Returns None when either header is missing.
+    pub fn rack(&self) -> Option<Header> {
+        match (self.headers().rseq(), self.headers().cseq()) {
+            (Some(Header::RSeq(rseq)), Some(Header::CSeq(cseq, method))) => Some(Header::RAck(rseq, cseq, method)),
+            _ => None,
+        }
+    }
+
+    /// Decrement the Max-Forwards header before forwarding the request
+    /// and return the new value. A missing header is first added with
+    /// the default of 70. Fails, leaving the header untouched, when
+    /// the value is already 0. RFC 3261 section 20.22 limits the value
+    /// to 0-255, larger values are capped at 255 after decrementing.
+    pub fn decrement_max_forwards(&mut self) -> Result<u8, TooManyHops> {
+        let headers = self.headers_mut();
+        let value = headers.0.iter_mut().find_map(|h| match h {
+            Header::MaxForwards(value) => Some(value),
+            _ => None
+        });
+        match value {
+            Some(0) => Err(TooManyHops),
+            Some(value) => {
+                let hops = u8::try_from(*value - 1).unwrap_or(u8::MAX);
+                *value = hops.into();
+                Ok(hops)
+            },
+            None => {
+                let hops = DEFAULT_MAX_FORWARDS - 1;
+                headers.push(Header::MaxForwards(hops.into()));
+                Ok(hops)
+            },
+        }
+    }
+
+    /// Check that the mandatory headers are present. Requests need
+    /// From, To, Call-ID, CSeq, Max-Forwards and at least one Via,
+    /// responses need a Via and CSeq. Every missing header and every
+    /// repeated From, To, Call-ID, CSeq, Max-Forwards, Content-Length
+    /// or Content-Type is reported.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        const SINGLETONS: &[&str] = &["From", "To", "Call-ID", "CSeq", "Max-Forwards", "Content-Length", "Content-Type"];
+        let required: &[&'static str] = if self.is_request() {
+            &["Via", "From", "To", "Call-ID", "CSeq", "Max-Forwards"]
+        } else {
+            &["Via", "CSeq"]
+        };
+        let count = |name: &str| self.headers().iter().filter(|h| h.name().eq_ignore_ascii_case(name)).count();
+        let error = ValidationError {
+            missing: required.iter().copied().filter(|name| count(name) == 0).collect(),
+            duplicated: SINGLETONS.iter().copied().filter(|name| count(name) > 1).collect(),
+        };
+        if error.missing.is_empty() && error.duplicated.is_empty() {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Serialize the message for the wire. Unlike the `Display`
+    /// implementation the body is copied without any utf8 conversion.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = match self {
+            SipMessage::Request { method, uri, version, .. } => {
+                format!("{} {} {}\r\n", method, uri, version)
+            },
+            SipMessage::Response { code, version, reason, .. } => {
+                format!("{} {} {}\r\n", version, code, reason)
+            },
+        };
+        for header in self.headers().iter() {
+            out.push_str(&format!("{}\r\n", header));
+        }
+        out.push_str("\r\n");
+        let mut out = out.into_bytes();
+        out.extend_from_slice(self.body());
+        out
+    }
 }
 
 impl fmt::Display for SipMessage {
@@ -110,14 +392,11 @@ impl fmt::Display for SipMessage {
             SipMessage::Response {
                 code,
                 version,
+                reason,
                 headers,
                 body,
             } => {
-                if let Some(desc) = error_code_to_str(*code) {
-                    writeln!(f, "{} {} {}\r", version, code, desc)?;
-                } else {
-                    writeln!(f, "{} {}\r", version, code)?;
-                }
+                writeln!(f, "{} {} {}\r", version, code, reason)?;
                 display_headers_and_body(f, headers, body)
             },
         }
@@ -138,51 +417,59 @@ pub fn display_headers_and_body(
     Ok(())
 }
 
-/// Parse SIP headers recursivily
+/// Parse SIP headers recursivily. Comma separated Via values
+/// are split into one `Header::Via` entry per value.
 pub fn parse_headers<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Headers, E> {
     let mut headers = Headers(vec![]);
     let mut input = input;
-    while let Ok((data, value)) = parse_header::<E>(input) {
-        headers.push(value);
-        input = data;
+    loop {
+        if let Ok((data, values)) = parse_via_headers::<E>(input) {
+            headers.extend(values);
+            input = data;
+        } else if let Ok((data, value)) = parse_header::<E>(input) {
+            headers.push(value);
+            input = data;
+        } else {
+            break;
+        }
     }
     Ok((input, headers))
 }
 
 use nom::{
-    combinator::{map_res, opt},
-    bytes::complete::{take_while, tag},
-    character::complete::char,
+    bytes::complete::{tag, take},
     error::ParseError
 };
 
+/// Parse the message body. When a Content-Length header is
+/// present exactly that many bytes are taken, leaving any
+/// following message in the input. Otherwise the remaining
+/// input is the body.
+pub fn parse_body<'a, E: ParseError<&'a [u8]>>(input: &'a [u8], headers: &Headers) -> IResult<&'a [u8], Vec<u8>, E> {
+    match headers.content_length() {
+        Some(Header::ContentLength(length)) => {
+            let (input, body) = take(length as usize)(input)?;
+            Ok((input, body.to_vec()))
+        },
+        _ => parse_byte_vec::<E>(input),
+    }
+}
+
 /// Parse a SIP message assuming it is a SIP response.
 pub fn parse_response<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], SipMessage, E> {
-    let (input, version) = parse_version::<E>(input)?;
-    let (input, _) = char(' ')(input)?;
-    let (input, code) = map_res(take_while(is_digit), parse_u32)(input)?;
-    let (input, _) = char(' ')(input)?;
-    let (input, _) = opt(map_res::<_, _, _, _, E, _, _>(take_while(|item| is_alphabetic(item) || is_space(item)), slice_to_string))(input)?;
-    let (input, _) = opt(char(' '))(input)?;
-    let (input, _) = tag("\r\n")(input)?;
+    let (input, StatusLine { version, code, reason }) = parse_status_line::<E>(input)?;
     let (input, headers) = parse_headers::<E>(input)?;
     let (input, _) = tag("\r\n")(input)?;
-    let (input, body) = parse_byte_vec::<E>(input)?;
-    Ok((input, SipMessage::Response { code, version, headers, body }))
+    let (input, body) = parse_body::<E>(input, &headers)?;
+    Ok((input, SipMessage::Response { code, version, reason, headers, body }))
 }
 
 /// Parse a SIP message assuming it is a SIP request.
 pub fn parse_request<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], SipMessage, E> {
-    let (input, method) = parse_method(input)?;
-    let (input, _) = char(' ')(input)?;
-    let (input, uri) = parse_uri(input)?;
-    let (input, _) = char(' ')(input)?;
-    let (input, version) = parse_version(input)?;
-    let (input, _) = opt(char(' '))(input)?;
-    let (input, _) = tag("\r\n")(input)?;
+    let (input, RequestLine { method, uri, version }) = parse_request_line(input)?;
     let (input, headers) = parse_headers(input)?;
     let (input, _) = tag("\r\n")(input)?;
-    let (input, body) = parse_byte_vec(input)?;
+    let (input, body) = parse_body(input, &headers)?;
     Ok((input, SipMessage::Request { method, uri, version, headers, body }))
 }
 
@@ -1,14 +1,22 @@
-use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+    str::FromStr
+};
 use nom::{
     IResult,
-    branch::alt,
-    combinator::map,
     error::ParseError,
-    bytes::complete::tag_no_case
+    bytes::complete::take_while1
 };
 
-/// SIP protocol methods.
-#[derive(Debug, PartialEq, Clone, Copy)]
+use crate::parse::is_token_char;
+
+/// SIP protocol methods. Methods that are not
+/// known to libsip are kept as `Method::Extension`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Method {
     Invite,
     Ack,
@@ -24,6 +32,7 @@ pub enum Method {
     Refer,
     Message,
     Update,
+    Extension(String),
 }
 
 impl Method {
@@ -67,26 +76,48 @@ impl fmt::Display for Method {
             Method::Refer => write!(f, "REFER"),
             Method::Message => write!(f, "MESSAGE"),
             Method::Update => write!(f, "UPDATE"),
+            Method::Extension(method) => write!(f, "{}", method),
         }
     }
 }
 
-/// Parse SIP request Method.
+impl FromStr for Method {
+    type Err = IoError;
+
+    /// Known methods are matched case-insensitively,
+    /// any other token becomes `Method::Extension`.
+    fn from_str(s: &str) -> Result<Method, IoError> {
+        if s.is_empty() || !s.bytes().all(is_token_char) {
+            return Err(IoError::new(IoErrorKind::InvalidInput, "Invalid SIP method"));
+        }
+        Ok(method_from_token(s.as_bytes()))
+    }
+}
+
+fn method_from_token(token: &[u8]) -> Method {
+    match token.to_ascii_uppercase().as_slice() {
+        b"INVITE" => Method::Invite,
+        b"ACK" => Method::Ack,
+        b"BYE" => Method::Bye,
+        b"CANCEL" => Method::Cancel,
+        b"REGISTER" => Method::Register,
+        b"OPTIONS" => Method::Options,
+        b"PRACK" => Method::PRack,
+        b"SUBSCRIBE" => Method::Subscribe,
+        b"NOTIFY" => Method::Notify,
+        b"PUBLISH" => Method::Publish,
+        b"INFO" => Method::Info,
+        b"REFER" => Method::Refer,
+        b"MESSAGE" => Method::Message,
+        b"UPDATE" => Method::Update,
+        _ => Method::Extension(String::from_utf8_lossy(token).into()),
+    }
+}
+
+/// Parse SIP request Method. Known methods are matched
+/// case-insensitively, any other token is parsed as
+/// an extension method.
 pub fn parse_method<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Method, E> {
-    alt::<_, _, E, _>((
-        map(tag_no_case("INVITE"), |_| Method::Invite),
-        map(tag_no_case("ACK"), |_| Method::Ack),
-        map(tag_no_case("BYE"), |_| Method::Bye),
-        map(tag_no_case("CANCEL"), |_| Method::Cancel),
-        map(tag_no_case("REGISTER"), |_| Method::Register),
-        map(tag_no_case("OPTIONS"), |_| Method::Options),
-        map(tag_no_case("PRACK"), |_| Method::PRack),
-        map(tag_no_case("SUBSCRIBE"), |_| Method::Subscribe),
-        map(tag_no_case("NOTIFY"), |_| Method::Notify),
-        map(tag_no_case("PUBLISH"), |_| Method::Publish),
-        map(tag_no_case("INFO"), |_| Method::Info),
-        map(tag_no_case("REFER"), |_| Method::Refer),
-        map(tag_no_case("MESSAGE"), |_| Method::Message),
-        map(tag_no_case("UPDATE"), |_| Method::Update)
-    ))(input)
-}
\ No newline at end of file
+    let (input, token) = take_while1(is_token_char)(input)?;
+    Ok((input, method_from_token(token)))
+}
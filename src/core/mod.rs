@@ -1,4 +1,5 @@
 pub mod code;
+pub use self::code::StatusCode;
 
 pub mod method;
 pub use self::method::{parse_method, Method};
@@ -9,5 +10,17 @@ pub use self::transport::{parse_transport, Transport};
 pub mod version;
 pub use self::version::{parse_version, Version};
 
+pub mod line;
+pub use self::line::{parse_request_line, parse_status_line, RequestLine, StatusLine};
+
 pub mod message;
 pub use self::message::{parse_message, parse_request, parse_response, SipMessage};
+
+pub mod transaction;
+pub use self::transaction::TransactionId;
+
+pub mod framer;
+pub use self::framer::MessageFramer;
+
+pub mod view;
+pub use self::view::{parse_message_view, HeaderView, HeaderViews, MessageView};
@@ -0,0 +1,38 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Header, Method, SipMessage};
+
+/// Key identifying the transaction a message belongs to, following
+/// the matching rules of RFC 3261 sections 17.1.3 and 17.2.3.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TransactionId {
+    /// The `branch` parameter of the top Via.
+    pub branch: String,
+    /// The sent-by host and port of the top Via, lowercased.
+    pub sent_by: String,
+    /// The CSeq method, ACK is stored as INVITE so that the
+    /// ACK of a non-2xx response matches the INVITE transaction.
+    pub method: Method,
+}
+
+impl SipMessage {
+    /// Compute the transaction key of this message. A response has
+    /// the same key as the request it answers since it echoes the
+    /// top Via and CSeq. Returns None if the message has no Via with
+    /// a branch or no CSeq header.
+    pub fn transaction_id(&self) -> Option<TransactionId> {
+        let via = self.headers().vias().into_iter().next()?;
+        let method = match self.headers().cseq()? {
+            Header::CSeq(_, Method::Ack) => Method::Invite,
+            Header::CSeq(_, method) => method,
+            _ => return None,
+        };
+        Some(TransactionId {
+            branch: via.branch()?.to_string(),
+            sent_by: via.uri.host.to_string().to_lowercase(),
+            method,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use nom::{
     IResult,
@@ -10,10 +11,17 @@ use nom::{
 use std::fmt;
 
 /// SIP protocol transport.
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Transport {
     Udp,
     Tcp,
+    Tls,
+    Sctp,
+    /// SIP over WebSocket (RFC 7118).
+    Ws,
+    /// SIP over secure WebSocket (RFC 7118).
+    Wss,
 }
 
 impl Default for Transport {
@@ -22,11 +30,41 @@ impl Default for Transport {
     }
 }
 
+impl Transport {
+    /// Value used in the uri `transport` parameter. The parameter
+    /// is compared case-insensitively, all values are written in
+    /// lowercase as in the RFC 3261 and RFC 7118 grammars.
+    pub fn param_value(&self) -> &'static str {
+        match self {
+            Transport::Udp => "udp",
+            Transport::Tcp => "tcp",
+            Transport::Tls => "tls",
+            Transport::Sctp => "sctp",
+            Transport::Ws => "ws",
+            Transport::Wss => "wss",
+        }
+    }
+
+    /// Port used when a uri using this transport does not specify one.
+    pub fn default_port(&self) -> u16 {
+        match self {
+            Transport::Udp | Transport::Tcp | Transport::Sctp => 5060,
+            Transport::Tls => 5061,
+            Transport::Ws => 80,
+            Transport::Wss => 443,
+        }
+    }
+}
+
 impl fmt::Display for Transport {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Transport::Udp => write!(f, "UDP"),
             Transport::Tcp => write!(f, "TCP"),
+            Transport::Tls => write!(f, "TLS"),
+            Transport::Sctp => write!(f, "SCTP"),
+            Transport::Ws => write!(f, "WS"),
+            Transport::Wss => write!(f, "WSS"),
         }
     }
 }
@@ -35,6 +73,10 @@ impl fmt::Display for Transport {
 pub fn parse_transport<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Transport, E> {
     alt::<_, _, E, _>((
         map(tag_no_case::<_, _, E>("TCP"), |_| Transport::Tcp),
-        map(tag_no_case::<_, _, E>("UDP"), |_| Transport::Udp)
+        map(tag_no_case::<_, _, E>("UDP"), |_| Transport::Udp),
+        map(tag_no_case::<_, _, E>("TLS"), |_| Transport::Tls),
+        map(tag_no_case::<_, _, E>("SCTP"), |_| Transport::Sctp),
+        map(tag_no_case::<_, _, E>("WSS"), |_| Transport::Wss),
+        map(tag_no_case::<_, _, E>("WS"), |_| Transport::Ws)
     ))(input)
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use nom::{
     IResult,
     bytes::complete::tag,
@@ -13,32 +15,37 @@ use std::fmt;
 
 /// SIP Protocol version struct.
 /// default: 2.0
-#[derive(Debug, PartialEq, Clone)]
-pub struct Version(u8, u8);
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+}
 
 impl Default for Version {
     fn default() -> Version {
-        Version(2, 0)
+        Version { major: 2, minor: 0 }
     }
 }
 
 impl fmt::Display for Version {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SIP/{}.{}", self.0, self.1)
+        write!(f, "SIP/{}.{}", self.major, self.minor)
     }
 }
 
 impl Version {
-    pub fn new(maj: u8, min: u8) -> Version {
-        Version(maj, min)
+    pub fn new(major: u8, minor: u8) -> Version {
+        Version { major, minor }
     }
 }
 
-/// Parse the SIP protocol version.
+/// Parse the SIP protocol version, e.g. `SIP/2.0`. Any major and
+/// minor number is accepted but the protocol name must be `SIP`.
 pub fn parse_version<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Version, E> {
     let (input, _) = tag("SIP/")(input)?;
     let (input, major) = map_res(take_while1(is_digit), parse_u8)(input)?;
     let (input, _) = parse_char('.')(input)?;
     let (input, minor) = map_res(take_while1(is_digit), parse_u8)(input)?;
-    Ok((input, Version(major, minor)))
+    Ok((input, Version { major, minor }))
 }
\ No newline at end of file
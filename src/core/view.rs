@@ -0,0 +1,245 @@
+use nom::{
+    error::{ErrorKind, ParseError},
+    IResult,
+};
+
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::{
+    core::{message::parse_headers, parse_request_line, parse_status_line, RequestLine, StatusLine},
+    headers::parse_header,
+    Header, SipMessage,
+};
+
+/// Error type used for the internal parses, it doesn't allocate.
+type ViewError<'a> = (&'a [u8], ErrorKind);
+
+/// Compact header names and the full names they stand for.
+const COMPACT_NAMES: &[(&str, &str)] = &[
+    ("a", "Accept-Contact"),
+    ("b", "Referred-By"),
+    ("d", "Request-Disposition"),
+    ("i", "Call-ID"),
+    ("m", "Contact"),
+    ("o", "Event"),
+    ("r", "Refer-To"),
+    ("e", "Content-Encoding"),
+    ("l", "Content-Length"),
+    ("c", "Content-Type"),
+    ("f", "From"),
+    ("j", "Reject-Contact"),
+    ("s", "Subject"),
+    ("k", "Supported"),
+    ("t", "To"),
+    ("u", "Allow-Events"),
+    ("v", "Via"),
+    ("x", "Session-Expires"),
+];
+
+/// Borrowed view of a SIP message.
+///
+/// Nothing is copied out of the input buffer while parsing, header
+/// values are only parsed when converted with `HeaderView::to_header`
+/// or `MessageView::to_message`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MessageView<'a> {
+    raw: &'a [u8],
+    start_line: &'a str,
+    head: &'a [u8],
+    body: &'a [u8],
+}
+
+impl<'a> MessageView<'a> {
+    /// The complete message as received.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// The request or status line without the trailing CRLF.
+    pub fn start_line(&self) -> &'a str {
+        self.start_line
+    }
+
+    /// Determine if this is a SIP request.
+    pub fn is_request(&self) -> bool {
+        !self.is_response()
+    }
+
+    /// Determine if this is a SIP response.
+    pub fn is_response(&self) -> bool {
+        self.start_line.starts_with("SIP/")
+    }
+
+    /// The request method, returns None for responses.
+    pub fn method(&self) -> Option<&'a str> {
+        if self.is_request() {
+            self.start_line.split(' ').next()
+        } else {
+            None
+        }
+    }
+
+    /// The request uri, returns None for responses.
+    pub fn request_uri(&self) -> Option<&'a str> {
+        if self.is_request() {
+            self.start_line.split(' ').nth(1)
+        } else {
+            None
+        }
+    }
+
+    /// The response status code, returns None for requests.
+    pub fn status_code(&self) -> Option<u32> {
+        if self.is_response() {
+            self.start_line.split(' ').nth(1)?.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// The message body.
+    pub fn body(&self) -> &'a [u8] {
+        self.body
+    }
+
+    /// Iterate over the headers in the order received.
+    pub fn headers(&self) -> HeaderViews<'a> {
+        HeaderViews { input: self.head }
+    }
+
+    /// Return the first header called `name`. The comparison ignores
+    /// case and treats compact header names as their full form.
+    pub fn header(&self, name: &str) -> Option<HeaderView<'a>> {
+        self.headers().find(|h| h.is(name))
+    }
+
+    /// Convert into the owned `SipMessage` type. The start line and
+    /// headers are parsed from the slices found by `parse_message_view`
+    /// so the message boundaries are not searched for again.
+    pub fn to_message(&self) -> IoResult<SipMessage> {
+        let invalid = || IoError::new(IoErrorKind::InvalidData, "Failed to parse SIP message");
+        let line = &self.raw[..self.start_line.len() + 2];
+        let headers = match parse_headers::<ViewError>(self.head) {
+            Ok((&[], headers)) => headers,
+            _ => return Err(invalid()),
+        };
+        let body = self.body.to_vec();
+        if self.is_response() {
+            let (_, StatusLine { version, code, reason }) = parse_status_line::<ViewError>(line).map_err(|_| invalid())?;
+            Ok(SipMessage::Response { code, version, reason, headers, body })
+        } else {
+            let (_, RequestLine { method, uri, version }) = parse_request_line::<ViewError>(line).map_err(|_| invalid())?;
+            Ok(SipMessage::Request { method, uri, version, headers, body })
+        }
+    }
+}
+
+/// Borrowed view of a single header line.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HeaderView<'a> {
+    raw: &'a [u8],
+    name: &'a str,
+    value: &'a str,
+}
+
+impl<'a> HeaderView<'a> {
+    /// The header name as it appeared on the wire.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// The header value with surrounding whitespace removed.
+    pub fn value(&self) -> &'a str {
+        self.value
+    }
+
+    /// Determine if this header is called `name`, ignoring case
+    /// and expanding compact header names.
+    pub fn is(&self, name: &str) -> bool {
+        expand_compact(self.name).eq_ignore_ascii_case(expand_compact(name))
+    }
+
+    /// Parse this header into the owned `Header` type.
+    pub fn to_header(&self) -> IoResult<Header> {
+        match parse_header::<ViewError>(self.raw) {
+            Ok((_, header)) => Ok(header),
+            Err(_) => Err(IoError::new(IoErrorKind::InvalidData, format!("Failed to parse {} header", self.name))),
+        }
+    }
+}
+
+/// Iterator over the headers of a `MessageView`.
+#[derive(Debug, Clone)]
+pub struct HeaderViews<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Iterator for HeaderViews<'a> {
+    type Item = HeaderView<'a>;
+
+    fn next(&mut self) -> Option<HeaderView<'a>> {
+        let (rest, header) = parse_header_view::<ViewError>(self.input).ok()?;
+        self.input = rest;
+        Some(header)
+    }
+}
+
+pub(crate) fn expand_compact(name: &str) -> &str {
+    for (compact, full) in COMPACT_NAMES {
+        if name.eq_ignore_ascii_case(compact) {
+            return full;
+        }
+    }
+    name
+}
+
+fn find_crlf(input: &[u8]) -> Option<usize> {
+    input.windows(2).position(|w| w == b"\r\n")
+}
+
+fn view_str<'a, E: ParseError<&'a [u8]>>(slice: &'a [u8]) -> Result<&'a str, nom::Err<E>> {
+    std::str::from_utf8(slice).map_err(|_| nom::Err::Error(E::from_error_kind(slice, ErrorKind::IsNot)))
+}
+
+/// Parse a single header line, including any folded continuation lines.
+fn parse_header_view<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], HeaderView<'a>, E> {
+    let mut end = find_crlf(input).ok_or_else(|| nom::Err::Error(E::from_error_kind(input, ErrorKind::CrLf)))?;
+    if end == 0 {
+        return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::CrLf)));
+    }
+    while let Some(b' ') | Some(b'\t') = input.get(end + 2) {
+        end += 2 + find_crlf(&input[end + 2..]).ok_or_else(|| nom::Err::Error(E::from_error_kind(input, ErrorKind::CrLf)))?;
+    }
+    let line = &input[..end];
+    let colon = line.iter().position(|c| *c == b':').ok_or_else(|| nom::Err::Error(E::from_error_kind(input, ErrorKind::Char)))?;
+    let name = view_str::<E>(&line[..colon])?.trim();
+    let value = view_str::<E>(&line[colon + 1..])?.trim();
+    Ok((&input[end + 2..], HeaderView { raw: &input[..end + 2], name, value }))
+}
+
+/// Parse a SIP message without copying any of its contents.
+/// The body is sliced using the Content-Length header when present,
+/// otherwise it extends to the end of the input.
+pub fn parse_message_view<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], MessageView<'a>, E> {
+    let line_end = find_crlf(input).ok_or_else(|| nom::Err::Error(E::from_error_kind(input, ErrorKind::CrLf)))?;
+    let start_line = view_str::<E>(&input[..line_end])?;
+    let mut rest = &input[line_end + 2..];
+    let head_start = rest;
+    while !rest.starts_with(b"\r\n") {
+        rest = parse_header_view::<E>(rest)?.0;
+    }
+    let head = &head_start[..head_start.len() - rest.len()];
+    rest = &rest[2..];
+    let mut view = MessageView { raw: input, start_line, head, body: rest };
+    let body_length = match view.header("Content-Length") {
+        Some(header) => header.value().parse::<usize>()
+            .map_err(|_| nom::Err::Error(E::from_error_kind(header.raw, ErrorKind::Digit)))?,
+        None => rest.len(),
+    };
+    if rest.len() < body_length {
+        return Err(nom::Err::Error(E::from_error_kind(rest, ErrorKind::Eof)));
+    }
+    view.body = &rest[..body_length];
+    view.raw = &input[..input.len() - rest.len() + body_length];
+    Ok((&rest[body_length..], view))
+}
@@ -0,0 +1,200 @@
+use nom::{
+    IResult,
+    error::{VerboseError, VerboseErrorKind}
+};
+
+use std::{convert::TryFrom, error::Error, fmt};
+
+use crate::{
+    core::{framer::parse_content_length, message::{parse_request, parse_response}},
+    SipMessage,
+};
+
+/// Error produced when a SIP message fails to parse, recording where
+/// in the input the failure happened.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    /// Byte offset of the offending byte from the start of the input.
+    pub offset: usize,
+    /// Description of what the parser expected.
+    pub description: String,
+    line: String,
+    column: usize,
+}
+
+impl ParseError {
+    /// Create a new error for the byte `offset` into `input`.
+    pub fn new<S: Into<String>>(input: &[u8], offset: usize, description: S) -> ParseError {
+        let offset = offset.min(input.len());
+        let start = input[..offset].iter().rposition(|c| *c == b'\n').map(|i| i + 1).unwrap_or(0);
+        let end = input[offset..].iter().position(|c| *c == b'\r' || *c == b'\n').map(|i| i + offset).unwrap_or(input.len());
+        let line = String::from_utf8_lossy(&input[start..end]).into_owned();
+        let column = String::from_utf8_lossy(&input[start..offset]).chars().count();
+        ParseError { offset, description: description.into(), line, column }
+    }
+
+    /// Convert a nom error produced while parsing `input`.
+    pub fn from_nom(input: &[u8], err: nom::Err<VerboseError<&[u8]>>) -> ParseError {
+        let err = match err {
+            nom::Err::Incomplete(_) => return ParseError::new(input, input.len(), "unexpected end of input"),
+            nom::Err::Error(err) | nom::Err::Failure(err) => err,
+        };
+        let (remaining, kind) = match err.errors.first() {
+            Some(error) => error,
+            None => return ParseError::new(input, 0, "invalid input"),
+        };
+        let offset = input.len().saturating_sub(remaining.len());
+        let mut description = match kind {
+            VerboseErrorKind::Char(c) => format!("expected '{}'", c.escape_default()),
+            VerboseErrorKind::Context(ctx) => format!("invalid {}", ctx),
+            VerboseErrorKind::Nom(kind) => format!("unexpected input ({})", kind.description()),
+        };
+        // Name the innermost enclosing context when the parsers provide one.
+        if let Some(ctx) = err.errors.iter().skip(1).find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(ctx) => Some(ctx),
+            _ => None
+        }) {
+            description = format!("{} in {}", description, ctx);
+        }
+        ParseError::new(input, offset, description)
+    }
+
+    /// The line of input containing the offending byte.
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    /// Character position of the offending byte within `line`.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} at offset {}", self.description, self.offset)?;
+        writeln!(f, "{}", self.line)?;
+        write!(f, "{:width$}^", "", width = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error produced by `SipMessage::validate`, listing every
+/// problem found rather than stopping at the first one.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ValidationError {
+    /// Mandatory headers the message does not contain.
+    pub missing: Vec<&'static str>,
+    /// Headers allowed only once that appear more than once.
+    pub duplicated: Vec<&'static str>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.missing.is_empty() {
+            write!(f, "missing headers: {}", self.missing.join(", "))?;
+            if !self.duplicated.is_empty() {
+                write!(f, "; ")?;
+            }
+        }
+        if !self.duplicated.is_empty() {
+            write!(f, "duplicated headers: {}", self.duplicated.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Error returned by `SipMessage::decrement_max_forwards` when a
+/// request arrives with a Max-Forwards of 0. A proxy must answer
+/// such a request with `483 Too Many Hops`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TooManyHops;
+
+impl fmt::Display for TooManyHops {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Max-Forwards reached 0")
+    }
+}
+
+impl Error for TooManyHops {}
+
+/// Error produced when converting a complete datagram into a `SipMessage`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MessageError {
+    /// The input ends before the headers or the body
+    /// announced by Content-Length, more data is needed.
+    Incomplete,
+    /// A message was parsed but the given number of bytes follow it.
+    TrailingData(usize),
+    /// The input is not a valid SIP message.
+    Invalid(ParseError),
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MessageError::Incomplete => write!(f, "incomplete SIP message"),
+            MessageError::TrailingData(len) => write!(f, "{} bytes of trailing data after SIP message", len),
+            MessageError::Invalid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for MessageError {}
+
+/// Check that a parser consumed the whole of `input`, any input
+/// left over is reported at the first byte that was not consumed.
+pub(crate) fn parse_complete<'a, T>(input: &'a [u8], result: IResult<&'a [u8], T, VerboseError<&'a [u8]>>) -> Result<T, ParseError> {
+    match result {
+        Ok((&[], value)) => Ok(value),
+        Ok((rest, _)) => Err(ParseError::new(input, input.len() - rest.len(), "unexpected trailing input")),
+        Err(err) => Err(ParseError::from_nom(input, err)),
+    }
+}
+
+/// Parse a complete SIP message, reporting failures as a `ParseError`.
+/// Unlike `parse_message` the start line decides whether the input is
+/// parsed as a request or a response so the error points at the
+/// failure within that message kind.
+pub fn try_parse_message(input: &[u8]) -> Result<SipMessage, ParseError> {
+    let result = if input.starts_with(b"SIP/") {
+        parse_response::<VerboseError<&[u8]>>(input)
+    } else {
+        parse_request::<VerboseError<&[u8]>>(input)
+    };
+    match result {
+        Ok((_, message)) => Ok(message),
+        Err(err) => Err(ParseError::from_nom(input, err)),
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SipMessage {
+    type Error = MessageError;
+
+    /// Parse a datagram holding exactly one message. The body ends
+    /// where Content-Length says, any bytes after it are an error.
+    fn try_from(input: &'a [u8]) -> Result<SipMessage, MessageError> {
+        let head_length = match input.windows(4).position(|w| w == b"\r\n\r\n") {
+            Some(index) => index + 4,
+            None => return Err(MessageError::Incomplete),
+        };
+        if let Some(body_length) = parse_content_length(&input[..head_length]) {
+            if input.len() < head_length + body_length {
+                return Err(MessageError::Incomplete);
+            }
+        }
+        let result = if input.starts_with(b"SIP/") {
+            parse_response::<VerboseError<&[u8]>>(input)
+        } else {
+            parse_request::<VerboseError<&[u8]>>(input)
+        };
+        match result {
+            Ok((&[], message)) => Ok(message),
+            Ok((rest, _)) => Err(MessageError::TrailingData(rest.len())),
+            Err(err) => Err(MessageError::Invalid(ParseError::from_nom(input, err))),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{tag_no_case, take_while, take_while1, take_while_m_n},
+    character::{complete::char, is_digit, is_space},
+    combinator::{map, map_res, opt},
+    error::{ErrorKind, ParseError},
+    sequence::{preceded, tuple}
+};
+
+use std::fmt;
+
+use crate::parse::{is_token_char, slice_to_string};
+
+use super::named::{parse_named_field_param, write_generic_params, GenericParams};
+
+/// Preference weight of an Accept entry, stored in thousandths
+/// so that entries can be compared and sorted exactly.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QValue(u16);
+
+impl QValue {
+    /// Create a q-value from `thousandths`, returns None above 1000.
+    pub fn new(thousandths: u16) -> Option<QValue> {
+        if thousandths > 1000 {
+            None
+        } else {
+            Some(QValue(thousandths))
+        }
+    }
+
+    /// The q-value in thousandths.
+    pub fn thousandths(self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for QValue {
+    /// Entries without a `q` parameter have a weight of 1.
+    fn default() -> QValue {
+        QValue(1000)
+    }
+}
+
+impl fmt::Display for QValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            1000 => write!(f, "1"),
+            0 => write!(f, "0"),
+            value => {
+                let digits = format!("{:03}", value);
+                write!(f, "0.{}", digits.trim_end_matches('0'))
+            },
+        }
+    }
+}
+
+/// Media range of an Accept header, e.g. `application/sdp;q=0.8`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MediaRange {
+    pub ty: String,
+    pub subtype: String,
+    pub q: Option<QValue>,
+    /// Parameters other than `q` in the order received.
+    pub params: GenericParams,
+}
+
+impl MediaRange {
+    pub fn new<T: Into<String>, S: Into<String>>(ty: T, subtype: S) -> MediaRange {
+        MediaRange { ty: ty.into(), subtype: subtype.into(), q: None, params: vec![] }
+    }
+
+    /// Set the q-value.
+    pub fn with_q(mut self, q: QValue) -> MediaRange {
+        self.q = Some(q);
+        self
+    }
+
+    /// The weight of this range, 1 when no q-value was given.
+    pub fn q(&self) -> QValue {
+        self.q.unwrap_or_default()
+    }
+}
+
+impl fmt::Display for MediaRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.ty, self.subtype)?;
+        write_generic_params(&self.params, f)?;
+        write_q(self.q, f)
+    }
+}
+
+/// Token entry of an Accept-Encoding or Accept-Language
+/// header, e.g. `gzip` or `en-GB;q=0.5`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AcceptValue {
+    pub value: String,
+    pub q: Option<QValue>,
+    /// Parameters other than `q` in the order received.
+    pub params: GenericParams,
+}
+
+impl AcceptValue {
+    pub fn new<S: Into<String>>(value: S) -> AcceptValue {
+        AcceptValue { value: value.into(), q: None, params: vec![] }
+    }
+
+    /// Set the q-value.
+    pub fn with_q(mut self, q: QValue) -> AcceptValue {
+        self.q = Some(q);
+        self
+    }
+
+    /// The weight of this entry, 1 when no q-value was given.
+    pub fn q(&self) -> QValue {
+        self.q.unwrap_or_default()
+    }
+}
+
+impl fmt::Display for AcceptValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)?;
+        write_generic_params(&self.params, f)?;
+        write_q(self.q, f)
+    }
+}
+
+fn write_q(q: Option<QValue>, f: &mut fmt::Formatter) -> fmt::Result {
+    match q {
+        Some(q) => write!(f, ";q={}", q),
+        None => Ok(()),
+    }
+}
+
+/// Parse a q-value, `0` or `1` with up to three decimals.
+pub fn parse_qvalue<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], QValue, E> {
+    alt((
+        map(
+            preceded(char('0'), opt(preceded(char('.'), take_while_m_n(0, 3, is_digit)))),
+            |digits: Option<&[u8]>| {
+                let digits = digits.unwrap_or_default();
+                let value = digits.iter().fold(0, |acc, digit| acc * 10 + u16::from(digit - b'0'));
+                QValue(value * 10u16.pow(3 - digits.len() as u32))
+            }
+        ),
+        map(
+            preceded(char('1'), opt(preceded(char('.'), take_while_m_n(0, 3, |c| c == b'0')))),
+            |_| QValue(1000)
+        )
+    ))(input)
+}
+
+/// Parse the parameters of an Accept entry, splitting out the q-value.
+fn parse_accept_params<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (Option<QValue>, GenericParams), E> {
+    let mut input = input;
+    let mut q = None;
+    let mut params = vec![];
+    loop {
+        let (rest, _) = take_while(is_space)(input)?;
+        if let Ok((rest, value)) = preceded(
+            tuple((char(';'), take_while(is_space), tag_no_case("q"), take_while(is_space), char('='), take_while(is_space))),
+            parse_qvalue::<E>
+        )(rest) {
+            q = Some(value);
+            input = rest;
+            continue;
+        }
+        match parse_named_field_param::<E>(rest) {
+            Ok((_, (key, _))) if key.eq_ignore_ascii_case("q") => {
+                return Err(nom::Err::Error(E::from_error_kind(rest, ErrorKind::Verify)));
+            },
+            Ok((rest, param)) => {
+                params.push(param);
+                input = rest;
+            },
+            Err(_) => return Ok((input, (q, params))),
+        }
+    }
+}
+
+/// Parse a single Accept media range.
+pub fn parse_media_range<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], MediaRange, E> {
+    let (input, ty) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, _) = char('/')(input)?;
+    let (input, subtype) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, (q, params)) = parse_accept_params(input)?;
+    Ok((input, MediaRange { ty, subtype, q, params }))
+}
+
+/// Parse a single Accept-Encoding or Accept-Language entry.
+pub fn parse_accept_value<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], AcceptValue, E> {
+    let (input, value) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, (q, params)) = parse_accept_params(input)?;
+    Ok((input, AcceptValue { value, q, params }))
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use sha::{
     sha256::Sha256,
     sha512::Sha512,
@@ -12,8 +14,11 @@ use std::{
 
 use crate::Uri;
 
+use super::named::write_quoted_string;
+
 /// The SIP Authentication schema.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AuthSchema {
     Digest,
 }
@@ -29,30 +34,176 @@ impl fmt::Display for AuthSchema {
 /// AuthHeader used for headers such as Authorization
 /// or WWWAuthenticate.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AuthHeader(pub AuthSchema, pub HashMap<String, String>);
 
 impl fmt::Display for AuthHeader {
+    /// Parameters are quoted except for `qop`, `nc`
+    /// and `algorithm` which are written as tokens.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)?;
         for (index, (key, value)) in self.1.iter().enumerate() {
-            if index == 0 && key == &"qop".to_string() {
-                write!(f, "{}={}", key, value)?;
-            } else if index == 0 && key == &"auth".to_string() {
-                write!(f, "{}={:08}", key, value)?;
-            } else if index == 0 {
-                write!(f, " {}=\"{}\"", key, value)?;
-            } else if key == &"qop".to_string() {
-                write!(f, ", {}={}", key, value)?;
-            } else if key == &"auth".to_string() {
-                write!(f, ", {}={:08}", key, value)?;
-            } else {
-                write!(f, ", {}=\"{}\"", key, value)?;
+            let separator = if index == 0 { " " } else { ", " };
+            match key.as_str() {
+                "qop" | "nc" | "algorithm" => write!(f, "{}{}={}", separator, key, value)?,
+                _ => write!(f, "{}{}=\"{}\"", separator, key, value)?,
             }
         }
         Ok(())
     }
 }
 
+/// Digest challenge carried by the WWW-Authenticate
+/// and Proxy-Authenticate headers. Parameters without
+/// a dedicated field are kept in `params` in the order
+/// they were received.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuthChallenge {
+    pub schema: AuthSchema,
+    pub realm: String,
+    pub nonce: String,
+    pub opaque: Option<String>,
+    pub algorithm: Option<String>,
+    pub qop: Vec<String>,
+    pub stale: bool,
+    pub params: Vec<(String, String)>,
+}
+
+impl AuthChallenge {
+    pub fn new<R: Into<String>, N: Into<String>>(realm: R, nonce: N) -> AuthChallenge {
+        AuthChallenge {
+            schema: AuthSchema::Digest,
+            realm: realm.into(),
+            nonce: nonce.into(),
+            opaque: None,
+            algorithm: None,
+            qop: vec![],
+            stale: false,
+            params: vec![],
+        }
+    }
+
+    /// Perform the authenticate action against this challenge.
+    pub fn authenticate<'a>(&self, ctx: AuthContext<'a>) -> IoResult<AuthHeader> {
+        AuthHeader::from(self.clone()).authenticate(ctx)
+    }
+}
+
+/// realm, nonce, opaque and the remaining parameters are quoted,
+/// algorithm, stale and a single qop value are written as tokens.
+impl fmt::Display for AuthChallenge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} realm=", self.schema)?;
+        write_quoted_string(&self.realm, f)?;
+        write!(f, ", nonce=")?;
+        write_quoted_string(&self.nonce, f)?;
+        if let Some(opaque) = &self.opaque {
+            write!(f, ", opaque=")?;
+            write_quoted_string(opaque, f)?;
+        }
+        if let Some(algorithm) = &self.algorithm {
+            write!(f, ", algorithm={}", algorithm)?;
+        }
+        if self.qop.len() == 1 {
+            write!(f, ", qop={}", self.qop[0])?;
+        } else if !self.qop.is_empty() {
+            write!(f, ", qop=\"{}\"", self.qop.join(","))?;
+        }
+        if self.stale {
+            write!(f, ", stale=true")?;
+        }
+        for (key, value) in self.params.iter() {
+            write!(f, ", {}=", key)?;
+            write_quoted_string(value, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Authentication-Info header returned after a successful
+/// digest authentication. Parameters without a dedicated
+/// field are kept in `params` in the order they were received.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuthenticationInfo {
+    /// Nonce the client should use for its next request.
+    pub nextnonce: Option<String>,
+    pub qop: Option<String>,
+    pub rspauth: Option<String>,
+    pub cnonce: Option<String>,
+    pub nc: Option<String>,
+    pub params: Vec<(String, String)>,
+}
+
+impl AuthenticationInfo {
+    pub fn new() -> AuthenticationInfo {
+        AuthenticationInfo::default()
+    }
+
+    /// Set the nonce to use for the next request.
+    pub fn nextnonce<S: Into<String>>(mut self, nextnonce: S) -> AuthenticationInfo {
+        self.nextnonce = Some(nextnonce.into());
+        self
+    }
+}
+
+/// nextnonce, rspauth, cnonce and the remaining parameters
+/// are quoted, qop and nc are written as tokens.
+impl fmt::Display for AuthenticationInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        let fields = [
+            ("nextnonce", &self.nextnonce),
+            ("qop", &self.qop),
+            ("rspauth", &self.rspauth),
+            ("cnonce", &self.cnonce),
+            ("nc", &self.nc),
+        ];
+        for (key, value) in fields.iter() {
+            if let Some(value) = value {
+                match *key {
+                    "qop" | "nc" => write!(f, "{}{}={}", separator, key, value)?,
+                    _ => {
+                        write!(f, "{}{}=", separator, key)?;
+                        write_quoted_string(value, f)?;
+                    },
+                }
+                separator = ", ";
+            }
+        }
+        for (key, value) in self.params.iter() {
+            write!(f, "{}{}=", separator, key)?;
+            write_quoted_string(value, f)?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
+impl From<AuthChallenge> for AuthHeader {
+    fn from(challenge: AuthChallenge) -> AuthHeader {
+        let mut map: HashMap<String, String> = challenge.params.into_iter().collect();
+        map.insert("realm".into(), challenge.realm);
+        map.insert("nonce".into(), challenge.nonce);
+        if let Some(opaque) = challenge.opaque {
+            map.insert("opaque".into(), opaque);
+        }
+        if let Some(algorithm) = challenge.algorithm {
+            map.insert("algorithm".into(), algorithm);
+        }
+        if challenge.qop.iter().any(|qop| qop == "auth") {
+            map.insert("qop".into(), "auth".into());
+        } else if !challenge.qop.is_empty() {
+            map.insert("qop".into(), challenge.qop.join(","));
+        }
+        if challenge.stale {
+            map.insert("stale".into(), "true".into());
+        }
+        AuthHeader(challenge.schema, map)
+    }
+}
+
 /// Context struct used when calculating the Auth Headers.
 pub struct AuthContext<'a> {
     pub user: &'a str,
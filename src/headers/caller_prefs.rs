@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    character::complete::char,
+    error::ParseError
+};
+
+use std::fmt;
+
+use super::named::{parse_generic_params, write_generic_params, GenericParams};
+
+/// Entry of an Accept-Contact or Reject-Contact header (RFC 3841),
+/// e.g. `*;+sip.audio;language="en";require`. Feature parameters
+/// are kept in the order received, with their leading `+` if any.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CallerPreference {
+    pub params: GenericParams,
+}
+
+impl CallerPreference {
+    /// Create a preference without any feature parameters.
+    pub fn new() -> CallerPreference {
+        CallerPreference::default()
+    }
+
+    /// Add a parameter with a value.
+    pub fn param<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> CallerPreference {
+        self.params.push((key.into(), Some(value.into())));
+        self
+    }
+
+    /// Add a parameter without a value, such as `+sip.audio` or `require`.
+    pub fn flag<K: Into<String>>(mut self, key: K) -> CallerPreference {
+        self.params.push((key.into(), None));
+        self
+    }
+
+    /// Get the value of a parameter, the name is matched case-insensitively.
+    pub fn get_param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Determine if a parameter is present, the name is
+    /// matched case-insensitively.
+    pub fn has_param(&self, key: &str) -> bool {
+        self.params.iter().any(|(k, _)| k.eq_ignore_ascii_case(key))
+    }
+
+    /// Whether contacts lacking the features must be discarded.
+    pub fn require(&self) -> bool {
+        self.has_param("require")
+    }
+
+    /// Whether only contacts explicitly listing the features match.
+    pub fn explicit(&self) -> bool {
+        self.has_param("explicit")
+    }
+}
+
+impl fmt::Display for CallerPreference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "*")?;
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Parse a single `*` entry with its feature parameters.
+pub fn parse_caller_preference<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], CallerPreference, E> {
+    let (input, _) = char('*')(input)?;
+    let (input, params) = parse_generic_params(input)?;
+    Ok((input, CallerPreference { params }))
+}
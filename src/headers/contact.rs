@@ -0,0 +1,79 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    branch::alt,
+    combinator::map,
+    multi::separated_list1,
+    sequence::tuple,
+    bytes::complete::take_while,
+    character::{is_space, complete::char},
+    error::ParseError
+};
+
+use super::named::{parse_named_header, NamedHeader};
+
+use std::fmt;
+
+/// Value of the Contact header. `*` is only valid in a
+/// REGISTER request together with `Expires: 0` and removes
+/// all of the bindings of an address of record.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ContactHeader {
+    Wildcard,
+    Values(Vec<NamedHeader>),
+}
+
+impl ContactHeader {
+    /// Retrieve the contact values, empty for the wildcard.
+    pub fn values(&self) -> &[NamedHeader] {
+        match self {
+            ContactHeader::Wildcard => &[],
+            ContactHeader::Values(values) => values,
+        }
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self, ContactHeader::Wildcard)
+    }
+}
+
+impl From<NamedHeader> for ContactHeader {
+    fn from(value: NamedHeader) -> ContactHeader {
+        ContactHeader::Values(vec![value])
+    }
+}
+
+impl fmt::Display for ContactHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContactHeader::Wildcard => write!(f, "*"),
+            ContactHeader::Values(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    if index == 0 {
+                        write!(f, "{}", value)?;
+                    } else {
+                        write!(f, ", {}", value)?;
+                    }
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Parse the value of a Contact header, either the
+/// wildcard or a comma separated list of named headers.
+pub fn parse_contact_value<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], ContactHeader, E> {
+    alt((
+        map(char('*'), |_| ContactHeader::Wildcard),
+        map(
+            separated_list1(
+                tuple((take_while(is_space), char(','), take_while(is_space))),
+                parse_named_header
+            ),
+            ContactHeader::Values
+        )
+    ))(input)
+}
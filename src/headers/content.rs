@@ -1,7 +1,14 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::parse::*;
+
+use super::named::{insert_param, parse_generic_params, write_generic_params, GenericParams};
+
 /// Sip protocol Content-Type value.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ContentType {
     Csv,
     Sdp,
@@ -46,33 +53,172 @@ impl fmt::Display for ContentType {
     }
 }
 
+/// Sip protocol Content-Type header value, a media type
+/// made up of a type, a subtype and its parameters,
+/// e.g. `multipart/mixed; boundary=unique-boundary-1`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MediaType {
+    pub ty: String,
+    pub subtype: String,
+    /// Parameters in the order they were received.
+    pub params: GenericParams,
+}
+
+impl MediaType {
+    pub fn new<T: Into<String>, S: Into<String>>(ty: T, subtype: S) -> MediaType {
+        MediaType {
+            ty: ty.into(),
+            subtype: subtype.into(),
+            params: vec![],
+        }
+    }
+
+    /// Add a parameter, replacing any previous value.
+    pub fn param<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> MediaType {
+        insert_param(&mut self.params, key.into(), Some(value.into()));
+        self
+    }
+
+    /// Retrieve a parameter value, the name is
+    /// matched case-insensitively.
+    pub fn get_param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| value.as_deref())
+    }
+}
+
+impl From<ContentType> for MediaType {
+    fn from(ty: ContentType) -> MediaType {
+        let value = format!("{}", ty);
+        let mut parts = value.splitn(2, '/');
+        let ty = parts.next().unwrap_or_default();
+        let subtype = parts.next().unwrap_or_default();
+        MediaType::new(ty, subtype)
+    }
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.ty, self.subtype)?;
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Value of the `handling` parameter of Content-Disposition.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Handling {
+    Optional,
+    Required,
+}
+
+impl fmt::Display for Handling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Handling::Optional => write!(f, "optional"),
+            Handling::Required => write!(f, "required"),
+        }
+    }
+}
+
+/// Sip protocol Content-Disposition header value,
+/// e.g. `session;handling=optional`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContentDisposition {
+    pub disposition: String,
+    /// Parameters in the order they were received.
+    pub params: GenericParams,
+}
+
+impl ContentDisposition {
+    pub fn new<S: Into<String>>(disposition: S) -> ContentDisposition {
+        ContentDisposition { disposition: disposition.into(), params: vec![] }
+    }
+
+    /// Set the `handling` parameter, replacing any previous value.
+    pub fn with_handling(mut self, handling: Handling) -> ContentDisposition {
+        self.params.retain(|(key, _)| !key.eq_ignore_ascii_case("handling"));
+        self.params.push(("handling".into(), Some(handling.to_string())));
+        self
+    }
+
+    /// The `handling` parameter, None when it is missing
+    /// or is neither `optional` nor `required`.
+    pub fn handling(&self) -> Option<Handling> {
+        let value = self.get_param("handling")?;
+        if value.eq_ignore_ascii_case("optional") {
+            Some(Handling::Optional)
+        } else if value.eq_ignore_ascii_case("required") {
+            Some(Handling::Required)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve a parameter value, the name is
+    /// matched case-insensitively.
+    pub fn get_param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| value.as_deref())
+    }
+}
+
+impl fmt::Display for ContentDisposition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.disposition)?;
+        write_generic_params(&self.params, f)
+    }
+}
+
 use nom::{
     IResult,
     branch::alt,
-    combinator::map,
-    bytes::complete::tag_no_case,
+    combinator::map_res,
+    bytes::complete::{take_while, take_while1},
+    character::{is_space, complete::char},
     error::ParseError
 };
 
+/// Parse a media type along with any parameters it has.
+pub fn parse_media_type<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], MediaType, E> {
+    let (input, ty) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, _) = char('/')(input)?;
+    let (input, subtype) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let mut media = MediaType::new(ty, subtype);
+    let mut input = input;
+    while let Ok((data, (key, value))) = parse_media_type_param::<E>(input) {
+        insert_param(&mut media.params, key, Some(value));
+        input = data;
+    }
+    Ok((input, media))
+}
+
+/// Parse a single media type parameter, the value
+/// can either be a token or a quoted string.
+pub fn parse_media_type_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (String, String), E> {
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, _) = char(';')(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, key) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, _) = char('=')(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, value) = alt((
+        parse_quoted_string::<E>,
+        map_res(take_while1(is_token_char), slice_to_string::<E>)
+    ))(input)?;
+    Ok((input, (key, value)))
+}
 
-pub fn parse_content_type<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], ContentType, E> {
-    Ok(alt((
-      map(tag_no_case("application/sdp"), |_| ContentType::Sdp),
-      map(tag_no_case("text/plain"), |_| ContentType::PlainText),
-      map(tag_no_case("text/html"), |_| ContentType::Html),
-      map(tag_no_case("applications/xml"), |_| ContentType::Xml),
-      map(tag_no_case("text/vcard"), |_| ContentType::VCard),
-      map(tag_no_case("text/calendar"), |_| ContentType::Calendar),
-      map(tag_no_case("text/markdown"), |_| ContentType::MarkDown),
-      map(tag_no_case("application/msword"), |_| ContentType::MsWord),
-      map(tag_no_case("application/pdf"), |_| ContentType::Pdf),
-      map(tag_no_case("image/png"), |_| ContentType::Png),
-      map(tag_no_case("text/csv"), |_| ContentType::Csv),
-      map(tag_no_case("application/zip"), |_| ContentType::Zip),
-      map(tag_no_case("application/sql"), |_| ContentType::Sql),
-      map(tag_no_case("application/json"), |_| ContentType::Json),
-      map(tag_no_case("application/gzip"), |_| ContentType::GZip),
-      map(tag_no_case("application/javascript"), |_| ContentType::Javascript),
-      map(tag_no_case("application/css"), |_| ContentType::Css)
-    ))(input)?)
+/// Parse a Content-Disposition value along with its parameters.
+pub fn parse_content_disposition<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], ContentDisposition, E> {
+    let (input, disposition) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, params) = parse_generic_params(input)?;
+    Ok((input, ContentDisposition { disposition, params }))
 }
@@ -0,0 +1,151 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::{complete::char, is_digit},
+    combinator::{map, map_res},
+    error::{ErrorKind, ParseError}
+};
+
+use std::fmt;
+
+use crate::parse::parse_u32;
+
+/// Day of the week used in a SIP Date header.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Month used in a SIP Date header.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Month {
+    Jan,
+    Feb,
+    Mar,
+    Apr,
+    May,
+    Jun,
+    Jul,
+    Aug,
+    Sep,
+    Oct,
+    Nov,
+    Dec,
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// RFC 1123 date as used by the SIP Date header,
+/// e.g. `Sat, 13 Nov 2010 23:29:00 GMT`. The time is always GMT.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SipDate {
+    pub weekday: Weekday,
+    pub day: u8,
+    pub month: Month,
+    pub year: u16,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl fmt::Display for SipDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            self.weekday, self.day, self.month, self.year,
+            self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// Parse a Date weekday token.
+pub fn parse_weekday<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Weekday, E> {
+    alt::<_, _, E, _>((
+        map(tag("Mon"), |_| Weekday::Mon),
+        map(tag("Tue"), |_| Weekday::Tue),
+        map(tag("Wed"), |_| Weekday::Wed),
+        map(tag("Thu"), |_| Weekday::Thu),
+        map(tag("Fri"), |_| Weekday::Fri),
+        map(tag("Sat"), |_| Weekday::Sat),
+        map(tag("Sun"), |_| Weekday::Sun)
+    ))(input)
+}
+
+/// Parse a Date month token.
+pub fn parse_month<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Month, E> {
+    alt::<_, _, E, _>((
+        map(tag("Jan"), |_| Month::Jan),
+        map(tag("Feb"), |_| Month::Feb),
+        map(tag("Mar"), |_| Month::Mar),
+        map(tag("Apr"), |_| Month::Apr),
+        map(tag("May"), |_| Month::May),
+        map(tag("Jun"), |_| Month::Jun),
+        map(tag("Jul"), |_| Month::Jul),
+        map(tag("Aug"), |_| Month::Aug),
+        map(tag("Sep"), |_| Month::Sep),
+        map(tag("Oct"), |_| Month::Oct),
+        map(tag("Nov"), |_| Month::Nov),
+        map(tag("Dec"), |_| Month::Dec)
+    ))(input)
+}
+
+fn parse_digits<'a, E: ParseError<&'a [u8]>>(input: &'a [u8], count: usize, max: u32) -> IResult<&'a [u8], u32, E> {
+    let (rest, value) = map_res(take_while_m_n(count, count, is_digit), parse_u32)(input)?;
+    if value > max {
+        return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify)));
+    }
+    Ok((rest, value))
+}
+
+/// Parse an RFC 1123 date, only the GMT zone is accepted.
+pub fn parse_sip_date<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], SipDate, E> {
+    let (input, weekday) = parse_weekday(input)?;
+    let (input, _) = tag(", ")(input)?;
+    let (rest, day) = parse_digits(input, 2, 31)?;
+    if day == 0 {
+        return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify)));
+    }
+    let (input, _) = char(' ')(rest)?;
+    let (input, month) = parse_month(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, year) = parse_digits(input, 4, 9999)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, hour) = parse_digits(input, 2, 23)?;
+    let (input, _) = char(':')(input)?;
+    let (input, minute) = parse_digits(input, 2, 59)?;
+    let (input, _) = char(':')(input)?;
+    let (input, second) = parse_digits(input, 2, 60)?;
+    let (input, _) = tag(" GMT")(input)?;
+    Ok((input, SipDate {
+        weekday,
+        day: day as u8,
+        month,
+        year: year as u16,
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+    }))
+}
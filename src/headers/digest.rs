@@ -0,0 +1,157 @@
+//! Digest access authentication as described in RFC 2617
+//! and RFC 8760. Given a parsed challenge and the user's
+//! credentials these functions compute the Authorization
+//! header to send back.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult}
+};
+
+use sha::{
+    sha256::Sha256,
+    utils::{Digest, DigestExt},
+};
+
+use super::auth::{AuthChallenge, AuthHeader};
+use crate::Method;
+
+/// Digest hash algorithms supported by `authorize`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DigestAlgorithm {
+    Md5,
+    Md5Sess,
+    Sha256,
+    Sha256Sess,
+}
+
+impl DigestAlgorithm {
+    /// Look up the algorithm named by a challenge, a missing
+    /// algorithm parameter means MD5.
+    pub fn from_challenge(challenge: &AuthChallenge) -> Option<DigestAlgorithm> {
+        match challenge.algorithm.as_ref().map(|item| item.to_ascii_uppercase()) {
+            None => Some(DigestAlgorithm::Md5),
+            Some(alg) => match alg.as_str() {
+                "MD5" => Some(DigestAlgorithm::Md5),
+                "MD5-SESS" => Some(DigestAlgorithm::Md5Sess),
+                "SHA-256" => Some(DigestAlgorithm::Sha256),
+                "SHA-256-SESS" => Some(DigestAlgorithm::Sha256Sess),
+                _ => None,
+            },
+        }
+    }
+
+    /// Hash `data` returning the lowercase hex digest.
+    pub fn hash(self, data: &str) -> String {
+        match self {
+            DigestAlgorithm::Md5 | DigestAlgorithm::Md5Sess => format!("{:x}", md5::compute(data)),
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Sha256Sess => {
+                Sha256::default().digest(data.as_bytes()).to_hex()
+            },
+        }
+    }
+
+    /// Whether HA1 is salted with the nonce and cnonce.
+    pub fn is_session(self) -> bool {
+        match self {
+            DigestAlgorithm::Md5Sess | DigestAlgorithm::Sha256Sess => true,
+            DigestAlgorithm::Md5 | DigestAlgorithm::Sha256 => false,
+        }
+    }
+
+    fn strength(self) -> u8 {
+        match self {
+            DigestAlgorithm::Md5 | DigestAlgorithm::Md5Sess => 1,
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Sha256Sess => 2,
+        }
+    }
+}
+
+/// Pick the challenge using the strongest supported algorithm,
+/// typically from several WWW-Authenticate headers of the same
+/// response. Ties go to the challenge that came first.
+pub fn strongest_challenge(challenges: &[AuthChallenge]) -> Option<&AuthChallenge> {
+    let mut best: Option<(&AuthChallenge, u8)> = None;
+    for challenge in challenges {
+        if let Some(alg) = DigestAlgorithm::from_challenge(challenge) {
+            match best {
+                Some((_, strength)) if strength >= alg.strength() => {},
+                _ => best = Some((challenge, alg.strength())),
+            }
+        }
+    }
+    best.map(|(challenge, _)| challenge)
+}
+
+/// Answer `challenge` for a request with the given `method` and
+/// digest `uri`. A random cnonce is generated and a nonce count
+/// of 1 is used.
+pub fn authorize<U: fmt::Display>(
+    challenge: &AuthChallenge,
+    username: &str,
+    password: &str,
+    method: &Method,
+    uri: U,
+) -> IoResult<AuthHeader> {
+    let cnonce = format!("{:x}", md5::compute(rand::random::<[u8; 16]>()));
+    authorize_with(challenge, username, password, method, uri, &cnonce, 1)
+}
+
+/// Answer `challenge` using the given `cnonce` and nonce count `nc`.
+/// When the challenge offers `qop=auth` it is used, otherwise the
+/// RFC 2069 compatible response is computed.
+pub fn authorize_with<U: fmt::Display>(
+    challenge: &AuthChallenge,
+    username: &str,
+    password: &str,
+    method: &Method,
+    uri: U,
+    cnonce: &str,
+    nc: u32,
+) -> IoResult<AuthHeader> {
+    let alg = DigestAlgorithm::from_challenge(challenge).ok_or_else(|| IoError::new(
+        IoErrorKind::InvalidInput,
+        format!("Unsupported digest algorithm: {}", challenge.algorithm.as_deref().unwrap_or_default()),
+    ))?;
+    let uri = format!("{}", uri);
+    let mut ha1 = alg.hash(&format!("{}:{}:{}", username, challenge.realm, password));
+    if alg.is_session() {
+        ha1 = alg.hash(&format!("{}:{}:{}", ha1, challenge.nonce, cnonce));
+    }
+    let ha2 = alg.hash(&format!("{}:{}", method, uri));
+
+    let mut map = HashMap::new();
+    map.insert("username".into(), username.to_string());
+    map.insert("realm".into(), challenge.realm.clone());
+    map.insert("nonce".into(), challenge.nonce.clone());
+    map.insert("uri".into(), uri);
+    if let Some(algorithm) = &challenge.algorithm {
+        map.insert("algorithm".into(), algorithm.clone());
+    }
+    if let Some(opaque) = &challenge.opaque {
+        map.insert("opaque".into(), opaque.clone());
+    }
+
+    let response = if challenge.qop.iter().any(|qop| qop.eq_ignore_ascii_case("auth")) {
+        map.insert("qop".into(), "auth".into());
+        map.insert("cnonce".into(), cnonce.to_string());
+        map.insert("nc".into(), format!("{:08x}", nc));
+        alg.hash(&format!(
+            "{}:{}:{:08x}:{}:auth:{}",
+            ha1, challenge.nonce, nc, cnonce, ha2
+        ))
+    } else if challenge.qop.is_empty() {
+        alg.hash(&format!("{}:{}:{}", ha1, challenge.nonce, ha2))
+    } else {
+        return Err(IoError::new(
+            IoErrorKind::InvalidInput,
+            format!("Unsupported digest qop: {}", challenge.qop.join(",")),
+        ));
+    };
+    if alg.is_session() {
+        map.insert("cnonce".into(), cnonce.to_string());
+    }
+    map.insert("response".into(), response);
+    Ok(AuthHeader(challenge.schema, map))
+}
@@ -0,0 +1,148 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    error::ParseError
+};
+
+use std::fmt;
+
+use crate::parse::parse_token;
+
+use super::named::{parse_generic_params, write_generic_params};
+
+fn find_param<'a>(params: &'a [(String, Option<String>)], name: &str) -> Option<&'a (String, Option<String>)> {
+    params.iter().find(|(key, _)| key.eq_ignore_ascii_case(name))
+}
+
+/// Value of the Event header, e.g. `presence;id=1234`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EventHeader {
+    /// Event package, including any `.template` suffix.
+    pub event: String,
+    /// Parameters in the order they were received.
+    pub params: Vec<(String, Option<String>)>,
+}
+
+impl EventHeader {
+    pub fn new<S: Into<String>>(event: S) -> EventHeader {
+        EventHeader { event: event.into(), params: vec![] }
+    }
+
+    /// Retrieve the value of the `id` parameter.
+    pub fn id(&self) -> Option<&str> {
+        find_param(&self.params, "id")?.1.as_deref()
+    }
+
+    /// Set the `id` parameter, replacing any existing one.
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> EventHeader {
+        self.params.retain(|(key, _)| !key.eq_ignore_ascii_case("id"));
+        self.params.push(("id".into(), Some(id.into())));
+        self
+    }
+}
+
+impl fmt::Display for EventHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.event)?;
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// State of a subscription as carried by the Subscription-State header.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SubscriptionStatus {
+    Active,
+    Pending,
+    Terminated,
+    Other(String),
+}
+
+impl fmt::Display for SubscriptionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubscriptionStatus::Active => write!(f, "active"),
+            SubscriptionStatus::Pending => write!(f, "pending"),
+            SubscriptionStatus::Terminated => write!(f, "terminated"),
+            SubscriptionStatus::Other(state) => write!(f, "{}", state),
+        }
+    }
+}
+
+impl From<String> for SubscriptionStatus {
+    fn from(state: String) -> SubscriptionStatus {
+        match state.to_ascii_lowercase().as_str() {
+            "active" => SubscriptionStatus::Active,
+            "pending" => SubscriptionStatus::Pending,
+            "terminated" => SubscriptionStatus::Terminated,
+            _ => SubscriptionStatus::Other(state),
+        }
+    }
+}
+
+/// Value of the Subscription-State header, e.g. `active;expires=599`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SubscriptionState {
+    pub state: SubscriptionStatus,
+    /// Parameters in the order they were received.
+    pub params: Vec<(String, Option<String>)>,
+}
+
+impl SubscriptionState {
+    pub fn new(state: SubscriptionStatus) -> SubscriptionState {
+        SubscriptionState { state, params: vec![] }
+    }
+
+    /// Retrieve the value of the `expires` parameter.
+    pub fn expires(&self) -> Option<u32> {
+        find_param(&self.params, "expires")?.1.as_ref()?.parse().ok()
+    }
+
+    /// Set the `expires` parameter, replacing any existing one.
+    pub fn with_expires(mut self, expires: u32) -> SubscriptionState {
+        self.params.retain(|(key, _)| !key.eq_ignore_ascii_case("expires"));
+        self.params.push(("expires".into(), Some(expires.to_string())));
+        self
+    }
+
+    /// Retrieve the value of the `reason` parameter, e.g. `timeout`.
+    pub fn reason(&self) -> Option<&str> {
+        find_param(&self.params, "reason")?.1.as_deref()
+    }
+
+    /// Set the `reason` parameter, replacing any existing one.
+    pub fn with_reason<S: Into<String>>(mut self, reason: S) -> SubscriptionState {
+        self.params.retain(|(key, _)| !key.eq_ignore_ascii_case("reason"));
+        self.params.push(("reason".into(), Some(reason.into())));
+        self
+    }
+
+    /// Retrieve the value of the `retry-after` parameter.
+    pub fn retry_after(&self) -> Option<u32> {
+        find_param(&self.params, "retry-after")?.1.as_ref()?.parse().ok()
+    }
+}
+
+impl fmt::Display for SubscriptionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.state)?;
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Parse the value of an Event header.
+pub fn parse_event<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], EventHeader, E> {
+    let (input, event) = parse_token(input)?;
+    let (input, params) = parse_generic_params(input)?;
+    Ok((input, EventHeader { event, params }))
+}
+
+/// Parse the value of a Subscription-State header.
+pub fn parse_subscription_state<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], SubscriptionState, E> {
+    let (input, state) = parse_token(input)?;
+    let (input, params) = parse_generic_params(input)?;
+    Ok((input, SubscriptionState { state: state.into(), params }))
+}
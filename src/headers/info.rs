@@ -0,0 +1,67 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::take_while1,
+    character::complete::char,
+    combinator::map_res,
+    error::ParseError
+};
+
+use std::fmt;
+
+use crate::parse::slice_to_string;
+
+use super::named::{parse_generic_params, write_generic_params, GenericParams};
+
+/// Entry of an Alert-Info or Call-Info header, e.g.
+/// `<http://www.example.com/alice/photo.jpg>;purpose=icon`.
+/// The uri is usually not a SIP uri, it is kept as written.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InfoValue {
+    pub uri: String,
+    pub params: GenericParams,
+}
+
+impl InfoValue {
+    /// Create a value for `uri` without parameters.
+    pub fn new<S: Into<String>>(uri: S) -> InfoValue {
+        InfoValue { uri: uri.into(), params: vec![] }
+    }
+
+    /// Add a parameter.
+    pub fn param<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> InfoValue {
+        self.params.push((key.into(), Some(value.into())));
+        self
+    }
+
+    /// Get the value of a parameter, the name is matched case-insensitively.
+    pub fn get_param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// The `purpose` parameter of a Call-Info entry, e.g. `icon` or `card`.
+    pub fn purpose(&self) -> Option<&str> {
+        self.get_param("purpose")
+    }
+}
+
+impl fmt::Display for InfoValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}>", self.uri)?;
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Parse a single `<uri>` entry with its parameters.
+pub fn parse_info_value<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], InfoValue, E> {
+    let (input, _) = char('<')(input)?;
+    let (input, uri) = map_res(take_while1(|c| c != b'>' && c != b'\r'), slice_to_string::<E>)(input)?;
+    let (input, _) = char('>')(input)?;
+    let (input, params) = parse_generic_params(input)?;
+    Ok((input, InfoValue { uri, params }))
+}
@@ -1,23 +1,52 @@
+mod accept;
 pub mod auth;
+mod caller_prefs;
+mod contact;
 mod content;
+pub mod date;
+pub mod digest;
+mod event;
+mod info;
 mod language;
 mod named;
 pub mod parse;
+mod priority;
+mod reason;
+mod replaces;
+mod retry_after;
+mod session;
 pub mod via;
+mod warning;
 mod write;
 pub use self::{
-    auth::{AuthHeader, AuthContext, AuthSchema},
-    content::ContentType,
+    accept::{AcceptValue, MediaRange, QValue},
+    auth::{AuthChallenge, AuthHeader, AuthContext, AuthSchema, AuthenticationInfo},
+    caller_prefs::CallerPreference,
+    contact::ContactHeader,
+    content::{ContentDisposition, ContentType, Handling, MediaType},
+    date::SipDate,
+    event::{EventHeader, SubscriptionState, SubscriptionStatus},
+    info::InfoValue,
     language::Language,
-    named::NamedHeader,
-    parse::parse_header
+    named::{NamedHeader, decode_encoded_words},
+    parse::parse_header,
+    priority::Priority,
+    reason::Reason,
+    replaces::Replaces,
+    retry_after::RetryAfter,
+    session::{Refresher, SessionExpires},
+    warning::Warning
 };
 
 use crate::core::Method;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Wrapper around a Vec<Header> to simplify creating
 /// and a list of headers
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Headers(pub Vec<Header>);
 
 impl Headers {
@@ -50,11 +79,41 @@ impl Headers {
         None
     }
 
+    /// Return the Min-Expires header if one is present.
+    pub fn min_expires(&self) -> Option<Header> {
+        for h in &self.0 {
+            if let Header::MinExpires(i) = h {
+                return Some(Header::MinExpires(*i));
+            }
+        }
+        None
+    }
+
+    /// Return the Content-Length header if one is present.
+    pub fn content_length(&self) -> Option<Header> {
+        for h in &self.0 {
+            if let Header::ContentLength(i) = h {
+                return Some(Header::ContentLength(*i));
+            }
+        }
+        None
+    }
+
     /// Return the CSeq header if one is present.
     pub fn cseq(&self) -> Option<Header> {
         for h in &self.0 {
             if let Header::CSeq(a, b) = h {
-                return Some(Header::CSeq(*a, *b));
+                return Some(Header::CSeq(*a, b.clone()));
+            }
+        }
+        None
+    }
+
+    /// Return the RSeq header if one is present.
+    pub fn rseq(&self) -> Option<Header> {
+        for h in &self.0 {
+            if let Header::RSeq(a) = h {
+                return Some(Header::RSeq(*a));
             }
         }
         None
@@ -110,6 +169,71 @@ impl Headers {
         None
     }
 
+    /// Return every Via value in the order received, regardless
+    /// of whether they were sent on separate or comma joined lines.
+    pub fn vias(&self) -> Vec<&via::ViaHeader> {
+        self.0.iter().filter_map(|h| match h {
+            Header::Via(via) => Some(via),
+            _ => None
+        }).collect()
+    }
+
+    /// Return every Route value in the order received.
+    pub fn routes(&self) -> Vec<&NamedHeader> {
+        self.0.iter().filter_map(|h| match h {
+            Header::Route(routes) => Some(routes.iter()),
+            _ => None
+        }).flatten().collect()
+    }
+
+    /// Return every Record-Route value in the order received.
+    pub fn record_routes(&self) -> Vec<&NamedHeader> {
+        self.0.iter().filter_map(|h| match h {
+            Header::RecordRoute(routes) => Some(routes.iter()),
+            _ => None
+        }).flatten().collect()
+    }
+
+    /// Return every Path value in the order received.
+    pub fn paths(&self) -> Vec<&NamedHeader> {
+        self.0.iter().filter_map(|h| match h {
+            Header::Path(paths) => Some(paths.iter()),
+            _ => None
+        }).flatten().collect()
+    }
+
+    /// Return every Service-Route value in the order received.
+    pub fn service_routes(&self) -> Vec<&NamedHeader> {
+        self.0.iter().filter_map(|h| match h {
+            Header::ServiceRoute(routes) => Some(routes.iter()),
+            _ => None
+        }).flatten().collect()
+    }
+
+    /// Return every Contact value in the order received.
+    pub fn contacts(&self) -> Vec<&NamedHeader> {
+        self.0.iter().filter_map(|h| match h {
+            Header::Contact(contact) => Some(contact.values().iter()),
+            _ => None
+        }).flatten().collect()
+    }
+
+    /// Return every method listed in the Allow headers.
+    pub fn allowed_methods(&self) -> Vec<&Method> {
+        self.0.iter().filter_map(|h| match h {
+            Header::Allow(methods) => Some(methods.iter()),
+            _ => None
+        }).flatten().collect()
+    }
+
+    /// Return every event package listed in the Allow-Events headers.
+    pub fn allowed_events(&self) -> Vec<&String> {
+        self.0.iter().filter_map(|h| match h {
+            Header::AllowEvents(events) => Some(events.iter()),
+            _ => None
+        }).flatten().collect()
+    }
+
     /// Return XFS Sending Header if one is present.
     pub fn xfs_sending_message(&self) -> Option<Header> {
         for h in &self.0 {
@@ -130,53 +254,90 @@ impl IntoIterator for Headers {
     }
 }
 
+/// Generate a new Call-ID value for `host`. The local part is an
+/// MD5 hash of 16 bytes taken from the thread local CSPRNG.
+pub fn gen_call_id(host: &str) -> String {
+    format!("{:x}@{}", md5::compute(rand::random::<[u8; 16]>()), host)
+}
+
+/// Generate a new random value for the `tag` parameter
+/// of the From and To headers.
+pub fn gen_tag() -> String {
+    format!("{:x}", md5::compute(rand::random::<[u8; 16]>()))
+}
+
 /// Single SIP Header Representation.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Header {
     To(NamedHeader),
-    Contact(NamedHeader),
+    Contact(ContactHeader),
     From(NamedHeader),
     ReplyTo(NamedHeader),
+    ReferTo(NamedHeader),
+    ReferredBy(NamedHeader),
+    Replaces(Replaces),
     CSeq(u32, Method),
     MaxForwards(u32),
     Expires(u32),
-    Accept(Vec<Method>),
+    Accept(Vec<MediaRange>),
     ContentLength(u32),
     Allow(Vec<Method>),
+    AllowEvents(Vec<String>),
     UserAgent(String),
     CallId(String),
-    ContentType(ContentType),
+    ContentType(MediaType),
     ContentLanguage(Language),
-    ContentEncoding(ContentType),
-    AcceptLanguage(Language),
-    AcceptEncoding(ContentType),
-    AlertInfo(String),
+    ContentEncoding(Vec<String>),
+    AcceptLanguage(Vec<AcceptValue>),
+    AcceptEncoding(Vec<AcceptValue>),
+    AlertInfo(Vec<InfoValue>),
     ErrorInfo(String),
-    AuthenticationInfo(String),
+    Event(EventHeader),
+    AuthenticationInfo(auth::AuthenticationInfo),
     Authorization(auth::AuthHeader),
-    CallInfo(String),
-    InReplyTo(String),
-    ContentDisposition(String),
-    Date(String),
+    CallInfo(Vec<InfoValue>),
+    /// Call-IDs of the calls this one refers back to.
+    InReplyTo(Vec<String>),
+    ContentDisposition(ContentDisposition),
+    Date(SipDate),
     MinExpires(u32),
-    MimeVersion(f32),
+    MinSE(u32),
+    MimeVersion(String),
     Organization(String),
-    ProxyAuthenticate(String),
+    ProxyAuthenticate(auth::AuthChallenge),
     ProxyAuthorization(String),
     ProxyRequire(String),
-    Require(String),
-    RetryAfter(String),
-    Route(String),
+    Require(Vec<String>),
+    AcceptContact(Vec<CallerPreference>),
+    RejectContact(Vec<CallerPreference>),
+    RequestDisposition(Vec<String>),
+    RSeq(u32),
+    /// Response number, CSeq number and method of the
+    /// reliable provisional response being acknowledged.
+    RAck(u32, u32, Method),
+    RetryAfter(RetryAfter),
+    Route(Vec<NamedHeader>),
+    Path(Vec<NamedHeader>),
+    ServiceRoute(Vec<NamedHeader>),
+    HistoryInfo(Vec<NamedHeader>),
+    Diversion(Vec<NamedHeader>),
     Subject(String),
-    RecordRoute(String),
+    SubscriptionState(SubscriptionState),
+    RecordRoute(Vec<NamedHeader>),
+    Reason(Vec<Reason>),
+    PAssertedIdentity(Vec<NamedHeader>),
+    PPreferredIdentity(Vec<NamedHeader>),
     Server(String),
+    SessionExpires(SessionExpires),
     Supported(Vec<String>),
-    Timestamp(u32),
-    Unsupported(String),
-    Warning(String),
+    /// Timestamp value and the optional delay, both in seconds.
+    Timestamp(f64, Option<f64>),
+    Unsupported(Vec<String>),
+    Warning(Vec<Warning>),
     Via(via::ViaHeader),
-    Priority(String),
-    WwwAuthenticate(auth::AuthHeader),
+    Priority(Priority),
+    WwwAuthenticate(auth::AuthChallenge),
     XFsSendingMessage(String),
     Other(String, String),
 }
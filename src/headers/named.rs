@@ -1,33 +1,50 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use nom::{
     IResult,
     branch::alt,
     error::ParseError,
     combinator::{opt, map_res},
-    bytes::complete::take_while,
+    sequence::preceded,
+    bytes::complete::{take_while, take_while1},
     character::{
         *,
         complete::char
     }
 };
 
-use crate::{parse::*, uri::parse_uri, Uri};
+use crate::{error::parse_complete, parse::*, uri::parse_uri, Uri};
 
-use std::{collections::HashMap, fmt};
+use super::QValue;
+
+use std::{
+    fmt,
+    io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult},
+    str::FromStr,
+};
 
 /// Header Value for Named Headers,
-/// e.g. From, To, Contact
+/// e.g. From, To, Contact. Flag parameters
+/// such as `;lr` are stored with a value of None.
+/// Parameter names keep the case they were given
+/// in but are looked up case-insensitively.
+/// Uris carrying parameters or headers are always
+/// written inside angle brackets so they are not
+/// confused with the header parameters.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NamedHeader {
     pub display_name: Option<String>,
     pub uri: Uri,
-    pub params: HashMap<String, String>,
+    /// Parameters in the order they were received.
+    pub params: GenericParams,
 }
 
 impl NamedHeader {
     pub fn new(uri: Uri) -> NamedHeader {
         NamedHeader {
             display_name: None,
-            params: HashMap::new(),
+            params: vec![],
             uri,
         }
     }
@@ -36,34 +53,314 @@ impl NamedHeader {
         self.display_name = Some(name.into());
         self
     }
+
+    /// Add a `key=value` parameter, replacing any previous value.
+    pub fn param<K: Into<String>, V: Into<String>>(self, key: K, value: V) -> NamedHeader {
+        self.with_param(key, Some(value.into()))
+    }
+
+    /// Add a flag parameter without a value, e.g. `lr`.
+    pub fn flag<K: Into<String>>(self, key: K) -> NamedHeader {
+        self.with_param(key, None)
+    }
+
+    /// Retrieve the value of a parameter, the name is matched
+    /// case-insensitively. Flag parameters have no value.
+    pub fn get_param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Determine if a parameter is present, the name is
+    /// matched case-insensitively.
+    pub fn has_param(&self, key: &str) -> bool {
+        self.params.iter().any(|(k, _)| k.eq_ignore_ascii_case(key))
+    }
+
+    /// Set a parameter, replacing any previous value
+    /// whatever the case of its name.
+    pub fn with_param<K: Into<String>>(mut self, key: K, value: Option<String>) -> NamedHeader {
+        insert_param(&mut self.params, key.into(), value);
+        self
+    }
+
+    /// Retrieve the value of the `tag` parameter.
+    pub fn tag(&self) -> Option<&str> {
+        self.get_param("tag")
+    }
+
+    /// Retrieve the `expires` parameter of a Contact. A value
+    /// of `Some(0)` asks the registrar to remove the binding.
+    /// Returns None if the parameter is missing or not an integer.
+    pub fn expires(&self) -> Option<u32> {
+        self.get_param("expires").and_then(|value| value.trim().parse().ok())
+    }
+
+    /// Retrieve the `q` parameter of a Contact. Returns None if
+    /// the parameter is missing or not a value between 0 and 1.
+    pub fn q(&self) -> Option<f32> {
+        self.get_param("q")
+            .and_then(|value| value.trim().parse::<f32>().ok())
+            .filter(|q| (0.0..=1.0).contains(q))
+    }
+
+    /// Retrieve the instance id of a Contact (RFC 5626), the value of
+    /// the `+sip.instance` parameter without its angle brackets.
+    pub fn instance_id(&self) -> Option<&str> {
+        let value = self.get_param("+sip.instance")?;
+        Some(value.strip_prefix('<').and_then(|value| value.strip_suffix('>')).unwrap_or(value))
+    }
+
+    /// Retrieve the `index` parameter of a History-Info entry
+    /// (RFC 7044), e.g. `1.1.2` as `[1, 1, 2]`. Returns None if
+    /// the parameter is missing or not a dotted list of integers.
+    pub fn index(&self) -> Option<Vec<u32>> {
+        self.get_param("index")?
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect()
+    }
+
+    /// Retrieve the `reason` parameter of a Diversion entry,
+    /// e.g. `unconditional` or `user-busy`.
+    pub fn reason(&self) -> Option<&str> {
+        self.get_param("reason")
+    }
+
+    /// Retrieve the `counter` parameter of a Diversion entry.
+    /// Returns None if the parameter is missing or not an integer.
+    pub fn counter(&self) -> Option<u32> {
+        self.get_param("counter").and_then(|value| value.trim().parse().ok())
+    }
+
+    /// Set the `expires` parameter, replacing any previous value.
+    pub fn with_expires(self, expires: u32) -> NamedHeader {
+        self.with_param("expires", Some(expires.to_string()))
+    }
+
+    /// Set the `q` parameter of a Contact, replacing any previous
+    /// value. The value is written with at most three decimals,
+    /// values outside of 0 to 1 are rejected.
+    pub fn with_q(self, q: f32) -> IoResult<NamedHeader> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(IoError::new(IoErrorKind::InvalidInput, "q must be between 0 and 1"));
+        }
+        let q = QValue::new((q * 1000.0).round() as u16).unwrap_or_default();
+        Ok(self.with_param("q", Some(q.to_string())))
+    }
+
+    /// Set the `tag` parameter, replacing any previous value.
+    pub fn with_tag<S: Into<String>>(self, tag: S) -> NamedHeader {
+        self.with_param("tag", Some(tag.into()))
+    }
+
+    /// The display name with any RFC 2047 encoded words decoded.
+    pub fn decoded_display_name(&self) -> Option<String> {
+        self.display_name.as_deref().map(decode_encoded_words)
+    }
+}
+
+/// Insert a parameter, an existing entry whose name only
+/// differs in case is replaced where it stands.
+pub(crate) fn insert_param(params: &mut GenericParams, key: String, value: Option<String>) {
+    match params.iter_mut().find(|(k, _)| k.eq_ignore_ascii_case(&key)) {
+        Some(param) => *param = (key, value),
+        None => params.push((key, value)),
+    }
 }
 
 impl fmt::Display for NamedHeader {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(name) = &self.display_name {
-            if name.contains(' ') {
-                write!(f, "\"{}\" <{}>", name, self.uri)?;
-            } else if name.is_empty() {
-                write!(f, "\"\" <{}>", self.uri)?;
-            } else {
+            if !name.is_empty() && name.bytes().all(is_token_char) {
                 write!(f, "{} <{}>", name, self.uri)?;
+            } else {
+                write_quoted_string(name, f)?;
+                write!(f, " <{}>", self.uri)?;
             }
+        } else if !self.uri.parameters.is_empty() || !self.uri.headers.is_empty() {
+            write!(f, "<{}>", self.uri)?;
         } else {
             write!(f, "{}", self.uri)?;
         }
-        for (key, value) in (&self.params).iter() {
-            write!(f, ";{}={}", key, value)?;
+        write_generic_params(&self.params, f)
+    }
+}
+
+impl FromStr for NamedHeader {
+    type Err = crate::ParseError;
+
+    /// Parse a header value such as `Bob <sip:bob@biloxi.com>;tag=a6c85cf`.
+    /// As with `Header::from_str` trailing whitespace is ignored, anything
+    /// else following the value is an error.
+    fn from_str(s: &str) -> Result<NamedHeader, crate::ParseError> {
+        let input = s.trim_end().as_bytes();
+        parse_complete(input, parse_named_header(input))
+    }
+}
+
+/// Write a parameter value, quoting it if it contains
+/// characters that are not allowed in a token.
+pub fn write_param_value(value: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    if !value.is_empty() && value.bytes().all(is_token_char) {
+        write!(f, "{}", value)
+    } else {
+        write_quoted_string(value, f)
+    }
+}
+
+/// Write `value` as a quoted string, escaping `"` and `\`.
+pub fn write_quoted_string(value: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    write!(f, "\"")
+}
+
+/// Decode the RFC 2047 encoded words in `input`, such as
+/// `=?UTF-8?B?0JjQstCw0L0=?=`. The B and Q encodings of UTF-8,
+/// US-ASCII and ISO-8859-1 text are supported, whitespace between
+/// two encoded words is dropped and anything else is kept as is.
+pub fn decode_encoded_words(input: &str) -> String {
+    let mut out = String::new();
+    let mut rest = input;
+    let mut after_word = false;
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        match decode_encoded_word(candidate) {
+            Some((decoded, length)) => {
+                if !after_word || !before.trim().is_empty() {
+                    out.push_str(before);
+                }
+                out.push_str(&decoded);
+                rest = &candidate[length..];
+                after_word = true;
+            },
+            None => {
+                out.push_str(before);
+                out.push_str("=?");
+                rest = &candidate[2..];
+                after_word = false;
+            },
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Decode a single `=?charset?encoding?text?=` word at the start
+/// of `input`, returning the text and the length of the word.
+fn decode_encoded_word(input: &str) -> Option<(String, usize)> {
+    let mut parts = input.get(2..)?.splitn(3, '?');
+    let charset = parts.next()?;
+    let encoding = parts.next()?;
+    let remainder = parts.next()?;
+    let text = &remainder[..remainder.find("?=")?];
+    if text.contains(char::is_whitespace) {
+        return None;
+    }
+    let bytes = match encoding {
+        "B" | "b" => decode_base64(text)?,
+        "Q" | "q" => decode_q(text)?,
+        _ => return None,
+    };
+    let charset = charset.split('*').next()?.to_ascii_lowercase();
+    let decoded = match charset.as_str() {
+        "utf-8" | "us-ascii" => String::from_utf8(bytes).ok()?,
+        "iso-8859-1" => bytes.into_iter().map(char::from).collect(),
+        _ => return None,
+    };
+    Some((decoded, input.len() - remainder.len() + text.len() + 2))
+}
+
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    let mut out = vec![];
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in input.bytes().take_while(|c| *c != b'=') {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
         }
-        Ok(())
     }
+    Some(out)
 }
 
-/// Parse a single NamedHeader param value.
-pub fn parse_named_field_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (String, String), E> {
+fn decode_q(input: &str) -> Option<Vec<u8>> {
+    let mut out = vec![];
+    let mut bytes = input.bytes();
+    while let Some(c) = bytes.next() {
+        match c {
+            b'_' => out.push(b' '),
+            b'=' => {
+                let hex = [bytes.next()?, bytes.next()?];
+                out.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            },
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+/// Header parameters in the order they were received.
+pub type GenericParams = Vec<(String, Option<String>)>;
+
+/// Write `;key=value` parameters in order, quoting values as needed.
+pub fn write_generic_params(params: &[(String, Option<String>)], f: &mut fmt::Formatter) -> fmt::Result {
+    for (key, value) in params {
+        write!(f, ";{}", key)?;
+        if let Some(value) = value {
+            write!(f, "=")?;
+            write_param_value(value, f)?;
+        }
+    }
+    Ok(())
+}
+
+/// Parse `;key=value` parameters in the order they appear,
+/// whitespace before each `;` is ignored.
+pub fn parse_generic_params<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], GenericParams, E> {
+    let mut input = input;
+    let mut params = vec![];
+    loop {
+        let (rest, _) = take_while(is_space)(input)?;
+        match parse_named_field_param::<E>(rest) {
+            Ok((rest, param)) => {
+                params.push(param);
+                input = rest;
+            },
+            Err(_) => return Ok((input, params)),
+        }
+    }
+}
+
+/// Parse a single NamedHeader param value. The value
+/// can either be a token or a quoted string, flag
+/// parameters have no value.
+pub fn parse_named_field_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (String, Option<String>), E> {
     let (input, _) = char(';')(input)?;
-    let (input, key) = map_res(take_while(is_alphabetic), slice_to_string::<E>)(input)?;
-    let (input, _) = char('=')(input)?;
-    let (input, value) = map_res(take_while(is_alphanumeric), slice_to_string::<E>)(input)?;
+    let (input, key) = map_res(take_while(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, value) = opt(preceded(char('='), alt((
+        parse_quoted_string::<E>,
+        map_res(take_while(is_token_char), slice_to_string::<E>)
+    ))))(input)?;
     Ok((input, (key, value)))
 }
 
@@ -74,31 +371,55 @@ pub fn parse_name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [
     )
 }
 
-/// Parse a stream of text that is not quoted. This will stop
-/// at the first ' ' char the input contains.
+/// Parse a display name that is not quoted, one or more tokens
+/// separated by whitespace. The name must be followed by the `<`
+/// opening the uri, which is left in the input, anything else is
+/// rejected so that a bare uri is never mistaken for a name.
 pub fn parse_unquoted_string<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
-    let (input, string_data) = map_res(take_while(is_alphabetic), slice_to_string::<E>)(input)?;
-    let (input, _) = char(' ')(input)?;
-    Ok((input, string_data))
-}
-
-/// Parse a single NamedHeader value.
-pub fn parse_named_field_value<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (Option<String>, Uri), E> {
-    let (input, name) = opt(parse_name)(input)?;
-    let (input, _) = opt(take_while(is_space))(input)?;
-    let (input, _) = opt(char('<'))(input)?;
-    let (input, value) = parse_uri(input)?;
-    let (input, _) = opt(char('>'))(input)?;
-    Ok((input, (name, value)))
+    let (mut input, word) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let mut words = vec![word];
+    loop {
+        let (rest, _) = take_while(is_space)(input)?;
+        if rest.starts_with(b"<") {
+            return Ok((rest, words.join(" ")));
+        }
+        let (rest, word) = map_res(take_while1(is_token_char), slice_to_string::<E>)(rest)?;
+        words.push(word);
+        input = rest;
+    }
 }
 
 /// Parse as many valid named field params as the input contains.
-pub fn parse_named_field_params<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], HashMap<String, String>, E> {
-    let mut map = HashMap::new();
+pub fn parse_named_field_params<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], GenericParams, E> {
+    let mut params = vec![];
     let mut input = input;
     while let Ok((data, (key, value))) = parse_named_field_param::<E>(input) {
-        map.insert(key, value);
+        insert_param(&mut params, key, value);
         input = data;
     }
-    Ok((input, map))
+    Ok((input, params))
+}
+
+/// Parse a single NamedHeader value along with its params.
+/// When the uri is not enclosed in angle brackets any
+/// parameters following it belong to the header.
+pub fn parse_named_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], NamedHeader, E> {
+    let (input, display_name) = opt(parse_name)(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, bracket) = opt(char('<'))(input)?;
+    let (input, mut uri) = parse_uri(input)?;
+    let mut params = vec![];
+    let input = if bracket.is_some() {
+        char('>')(input)?.0
+    } else {
+        for param in uri.parameters.drain(..) {
+            insert_param(&mut params, param.key(), param.value());
+        }
+        input
+    };
+    let (input, header_params) = parse_named_field_params(input)?;
+    for (key, value) in header_params {
+        insert_param(&mut params, key, value);
+    }
+    Ok((input, NamedHeader { display_name, uri, params }))
 }
@@ -1,33 +1,35 @@
 use nom::character::*;
 use nom::{
     IResult,
-    error::ParseError,
+    branch::alt,
+    error::{ParseError, VerboseError},
     combinator::{
         map, opt,
-        map_res,
+        map_res, recognize,
     },
     sequence::{
-        pair
+        pair, preceded, tuple
     },
     multi::{
-        separated_list0
+        separated_list0, separated_list1
     },
     character::complete::{
         char
     },
     bytes::complete::{
-        tag_no_case, take_while,
+        tag_no_case, take_while, take_while1,
         tag, take_until
     }
 };
-use super::{content::*, language::*, named::*, *};
+use super::{accept::*, caller_prefs::parse_caller_preference, contact::*, content::*, info::parse_info_value, language::*, named::*, reason::parse_reason, warning::parse_warning, *};
 use crate::{
     core::{parse_method, parse_transport, parse_version},
+    error::parse_complete,
     parse::*,
     uri::parse_uri,
 };
 
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 pub fn parse_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header> {
     let (input, _) = opt(tag("\r\n"))(input)?;
@@ -36,11 +38,33 @@ pub fn parse_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a
     Ok((input, header))
 }
 
+impl FromStr for Header {
+    type Err = crate::ParseError;
+
+    /// Parse a single header line such as `Max-Forwards: 70`. Trailing
+    /// whitespace, including a final CRLF, is ignored, anything else
+    /// following the header value is an error.
+    fn from_str(s: &str) -> Result<Header, crate::ParseError> {
+        let line = format!("{}\r\n", s.trim_end());
+        let input = line.as_bytes();
+        let result = parse_header::<VerboseError<&[u8]>>(input)
+            .and_then(|(rest, header)| {
+                // parse_via_header leaves the line's CRLF in place.
+                let (rest, _) = opt(tag("\r\n"))(rest)?;
+                Ok((rest, header))
+            })
+            .map_err(|err| err.map(|err| VerboseError::from_error_kind(err.input, err.code)));
+        parse_complete(input, result)
+    }
+}
+
 named!(pub _parse_header<Header>, alt!(
+    parse_accept_contact_header |
     parse_accept_encoding_header |
     parse_accept_header |
     parse_accept_language_header |
     parse_alert_info_header |
+    parse_allow_events_header |
     parse_allow_header |
     parse_authentication_info_header |
     parse_authorization_header |
@@ -55,24 +79,42 @@ named!(pub _parse_header<Header>, alt!(
     parse_cseq_header |
     parse_date_header |
     parse_error_info_header |
+    parse_event_header |
     parse_expires_header |
     parse_from_header |
     parse_in_reply_to_header |
     parse_max_forwards_header |
     parse_mime_version_header |
     parse_min_expires_header |
+    parse_min_se_header |
     parse_organization_header |
     parse_priority_header |
     parse_proxy_authenticate_header |
     parse_proxy_authorization_header |
     parse_proxy_require_header |
     parse_record_route_header |
+    parse_reason_header |
     parse_reply_to_header |
+    parse_refer_to_header |
+    parse_referred_by_header |
+    parse_reject_contact_header |
+    parse_replaces_header |
+    parse_request_disposition_header |
     parse_require_header |
     parse_retry_after_header |
+    parse_rseq_header |
+    parse_rack_header |
     parse_route_header |
+    parse_path_header |
+    parse_service_route_header |
+    parse_history_info_header |
+    parse_diversion_header |
+    parse_p_asserted_identity_header |
+    parse_p_preferred_identity_header |
     parse_server_header |
+    parse_session_expires_header |
     parse_subject_header |
+    parse_subscription_state_header |
     parse_supported_header |
     parse_timestamp_header |
     parse_to_header |
@@ -88,7 +130,7 @@ named!(pub _parse_header<Header>, alt!(
 macro_rules! impl_u32_parser {
     ($name:tt, $tag:tt, $variant: ident) => {
         pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
-            let (input, _) = tag($tag)(input)?;
+            let (input, _) = tag_no_case($tag)(input)?;
             let (input, _) = opt(take_while(is_space))(input)?;
             let (input, _) = char(':')(input)?;
             let (input, _) = opt(take_while(is_space))(input)?;
@@ -96,21 +138,19 @@ macro_rules! impl_u32_parser {
             let (input, _) = tag("\r\n")(input)?;
             Ok((input, Header::$variant(value)))
         }
-    }
-}
-macro_rules! impl_f32_parser {
-    ($name:tt, $tag:tt, $variant: ident) => {
+    };
+    ($name:tt, $tag:tt, $compact:tt, $variant: ident) => {
         pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
-            let (input, _) = tag_no_case($tag)(input)?;
+            let (input, _) = alt((tag_no_case($tag), tag_no_case($compact)))(input)?;
             let (input, _) = opt(take_while(is_space))(input)?;
             let (input, _) = char(':')(input)?;
             let (input, _) = opt(take_while(is_space))(input)?;
-            let (input, value) = map_res(take_while(|item| is_digit(item) || item == b'.'), parse_f32)(input)?;
+            let (input, value) = map_res(take_while(is_digit), parse_u32)(input)?;
+            let (input, _) = tag("\r\n")(input)?;
             Ok((input, Header::$variant(value)))
         }
-    }
+    };
 }
-
 macro_rules! impl_string_parser {
     ($name:tt, $tag:tt, $variant: ident) => {
         pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
@@ -122,21 +162,63 @@ macro_rules! impl_string_parser {
             let (input, _) = tag("\r\n")(input)?;
             Ok((input, Header::$variant(value)))
         }
-    }
+    };
+    ($name:tt, $tag:tt, $compact:tt, $variant: ident) => {
+        pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+            let (input, _) = alt((tag_no_case($tag), tag_no_case($compact)))(input)?;
+            let (input, _) = opt(take_while(is_space))(input)?;
+            let (input, _) = char(':')(input)?;
+            let (input, _) = opt(take_while(is_space))(input)?;
+            let (input, value) = map_res(take_until("\r"), slice_to_string::<E>)(input)?;
+            let (input, _) = tag("\r\n")(input)?;
+            Ok((input, Header::$variant(value)))
+        }
+    };
+}
+
+/// Parse a Call-ID header, `word [ "@" word ]`, in
+/// either its full or compact `i` form.
+pub fn parse_callid_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = alt((tag_no_case::<_, _, E>("Call-ID"), tag_no_case("i")))(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = parse_call_id(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::CallId(value)))
 }
 
 macro_rules! impl_array_parser {
     ($name:tt, $tag:tt, $variant:ident, $func:ident) => {
         pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
             let (input, _) = tag_no_case($tag)(input)?;
-            let (input, _) = opt(take_while(is_space))(input)?;
-            let (input, _) = char(':')(input)?;
-            let (input, _) = opt(take_while(is_space))(input)?;
-            let (input, data) = separated_list0(pair(char(','), opt(char(' '))), $func)(input)?;
-            let (input, _) = tag("\r\n")(input)?;
-            Ok((input, Header::$variant(data)))
+            parse_array_value(input, $func).map(|(input, data)| (input, Header::$variant(data)))
         }
-    }
+    };
+    ($name:tt, $tag:tt, $compact:tt, $variant:ident, $func:ident) => {
+        pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+            let (input, _) = alt((tag_no_case($tag), tag_no_case($compact)))(input)?;
+            parse_array_value(input, $func).map(|(input, data)| (input, Header::$variant(data)))
+        }
+    };
+}
+
+/// Parse the `: a, b, c\r\n` remainder of a comma separated list header.
+fn parse_array_value<'a, T, E, F>(input: &'a [u8], func: F) -> IResult<&'a [u8], Vec<T>, E>
+where
+    E: ParseError<&'a [u8]>,
+    F: Fn(&'a [u8]) -> IResult<&'a [u8], T, E>,
+{
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, data) = separated_list0(
+        tuple((take_while(is_space), char(','), take_while(is_space))),
+        func
+    )(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, data))
 }
 
 macro_rules! impl_named_parser {
@@ -146,27 +228,167 @@ macro_rules! impl_named_parser {
             let (input, _) = opt(take_while(is_space))(input)?;
             let (input, _) = char(':')(input)?;
             let (input, _) = opt(take_while(is_space))(input)?;
-            let (input, out) = parse_named_field_value(input)?;
-            let (input, params) = parse_named_field_params(input)?;
+            let (input, value) = parse_named_header(input)?;
             let (input, _) = tag("\r\n")(input)?;
-            Ok((input, Header::$variant(NamedHeader { display_name: out.0, uri: out.1, params: params })))
+            Ok((input, Header::$variant(value)))
         }
-    }
+    };
+    ($name:tt, $tag:tt, $compact:tt, $variant:ident) => {
+        pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+            let (input, _) = alt((tag_no_case($tag), tag_no_case($compact)))(input)?;
+            let (input, _) = opt(take_while(is_space))(input)?;
+            let (input, _) = char(':')(input)?;
+            let (input, _) = opt(take_while(is_space))(input)?;
+            let (input, value) = parse_named_header(input)?;
+            let (input, _) = tag("\r\n")(input)?;
+            Ok((input, Header::$variant(value)))
+        }
+    };
+}
+
+/// Parse a Contact header, either in its full or compact `m` form.
+pub fn parse_contact_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = alt((tag_no_case("Contact"), tag_no_case("m")))(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = parse_contact_value(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::Contact(value)))
 }
 
-macro_rules! impl_type_parser {
+macro_rules! impl_named_list_parser {
     ($name:tt, $tag:tt, $variant:ident) => {
         pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
             let (input, _) = tag_no_case($tag)(input)?;
             let (input, _) = opt(take_while(is_space))(input)?;
             let (input, _) = char(':')(input)?;
             let (input, _) = opt(take_while(is_space))(input)?;
-            let (input, ty) = parse_content_type::<E>(input)?;
-            Ok((input, Header::$variant(ty)))
+            let (input, values) = separated_list1(
+                tuple((take_while(is_space), char(','), take_while(is_space))),
+                parse_named_header
+            )(input)?;
+            let (input, _) = tag("\r\n")(input)?;
+            Ok((input, Header::$variant(values)))
         }
     }
 }
 
+/// Parse a MIME-Version header, e.g. `MIME-Version: 1.0`.
+pub fn parse_mime_version_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("MIME-Version")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, version) = map_res(
+        recognize(tuple((take_while1(is_digit), char('.'), take_while1(is_digit)))),
+        slice_to_string::<E>
+    )(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::MimeVersion(version)))
+}
+
+/// Parse a Date header, the value must be an RFC 1123 date in GMT.
+pub fn parse_date_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("Date")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, date) = date::parse_sip_date::<E>(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::Date(date)))
+}
+
+/// Parse a Retry-After header, with an optional comment and parameters.
+pub fn parse_retry_after_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("Retry-After")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = retry_after::parse_retry_after::<E>(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::RetryAfter(value)))
+}
+
+pub fn parse_priority_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("Priority")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = priority::parse_priority::<E>(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::Priority(value)))
+}
+
+/// Parse a Session-Expires header, either in its full or compact `x` form.
+pub fn parse_session_expires_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = alt((tag_no_case("Session-Expires"), tag_no_case("x")))(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = session::parse_session_expires::<E>(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::SessionExpires(value)))
+}
+
+/// Parse an Event header, either in its full or compact `o` form.
+pub fn parse_event_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = alt((tag_no_case("Event"), tag_no_case("o")))(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = event::parse_event::<E>(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::Event(value)))
+}
+
+/// Parse a Subscription-State header.
+pub fn parse_subscription_state_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("Subscription-State")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = event::parse_subscription_state::<E>(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::SubscriptionState(value)))
+}
+
+/// Parse a Replaces header.
+pub fn parse_replaces_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("Replaces")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = replaces::parse_replaces::<E>(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::Replaces(value)))
+}
+
+/// Parse a Content-Type header, either in its full or compact `c` form.
+pub fn parse_content_type_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = alt((tag_no_case("Content-Type"), tag_no_case("c")))(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, ty) = parse_media_type::<E>(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::ContentType(ty)))
+}
+
+pub fn parse_content_disposition_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("Content-Disposition")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, disposition) = parse_content_disposition::<E>(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::ContentDisposition(disposition)))
+}
+
 macro_rules! impl_lang_parser {
     ($name:tt, $tag:tt, $variant:ident) => {
         pub fn $name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
@@ -185,101 +407,116 @@ macro_rules! impl_lang_parser {
 
 impl_u32_parser!(parse_expires_header, "Expires", Expires);
 impl_u32_parser!(parse_min_expires_header, "Min-Expires", MinExpires);
-impl_u32_parser!(parse_content_length_header, "Content-Length", ContentLength);
+impl_u32_parser!(parse_min_se_header, "Min-SE", MinSE);
+impl_u32_parser!(parse_content_length_header, "Content-Length", "l", ContentLength);
 impl_u32_parser!(parse_max_forwards_header, "Max-Forwards", MaxForwards);
-impl_f32_parser!(parse_mime_version_header, "MIME-Version", MimeVersion);
+impl_u32_parser!(parse_rseq_header, "RSeq", RSeq);
 impl_string_parser!(parse_useragent_header, "User-Agent", UserAgent);
-impl_string_parser!(parse_callid_header, "Call-ID", CallId);
-impl_string_parser!(parse_alert_info_header, "Alert-Info", AlertInfo);
+impl_array_parser!(parse_alert_info_header, "Alert-Info", AlertInfo, parse_info_value);
 impl_string_parser!(parse_error_info_header, "Error-Info", ErrorInfo);
-impl_string_parser!(
-    parse_authentication_info_header,
-    "Authentication-Info",
-    AuthenticationInfo
-);
-impl_string_parser!(parse_call_info_header, "Call-Info", CallInfo);
-impl_string_parser!(parse_in_reply_to_header, "In-Reply-To", InReplyTo);
-impl_string_parser!(
-    parse_content_disposition_header,
-    "Content-Disposition",
-    ContentDisposition
-);
-impl_string_parser!(parse_date_header, "Date", Date);
+impl_array_parser!(parse_call_info_header, "Call-Info", CallInfo, parse_info_value);
+impl_array_parser!(parse_in_reply_to_header, "In-Reply-To", InReplyTo, parse_call_id);
 impl_string_parser!(parse_organization_header, "Organization", Organization);
-impl_string_parser!(
-    parse_proxy_authenticate_header,
-    "Proxy-Authenticate",
-    ProxyAuthenticate
-);
 impl_string_parser!(
     parse_proxy_authorization_header,
     "Proxy-Authorization",
     ProxyAuthorization
 );
 impl_string_parser!(parse_proxy_require_header, "Proxy-Require", ProxyRequire);
-impl_string_parser!(parse_require_header, "Require", Require);
-impl_string_parser!(parse_retry_after_header, "Retry-After", RetryAfter);
-impl_string_parser!(parse_route_header, "Route", Route);
-impl_string_parser!(parse_subject_header, "Subject", Subject);
-impl_string_parser!(parse_record_route_header, "Record-Route", RecordRoute);
+impl_string_parser!(parse_subject_header, "Subject", "s", Subject);
 impl_string_parser!(parse_server_header, "Server", Server);
-impl_string_parser!(parse_unsupported_header, "Unsupported", Unsupported);
-impl_string_parser!(parse_warning_header, "Warning", Warning);
+impl_array_parser!(parse_warning_header, "Warning", Warning, parse_warning);
+impl_array_parser!(parse_reason_header, "Reason", Reason, parse_reason);
 impl_string_parser!(
     parse_xfs_sending_message_header,
     "X-FS-Sending-Message",
     XFsSendingMessage
 );
-impl_string_parser!(parse_priority_header, "Priority", Priority);
-impl_u32_parser!(parse_timestamp_header, "Timestamp", Timestamp);
-impl_array_parser!(parse_accept_header, "Accept", Accept, parse_method);
+impl_array_parser!(parse_accept_header, "Accept", Accept, parse_media_range);
+impl_array_parser!(parse_accept_encoding_header, "Accept-Encoding", AcceptEncoding, parse_accept_value);
+impl_array_parser!(parse_accept_language_header, "Accept-Language", AcceptLanguage, parse_accept_value);
 impl_array_parser!(parse_allow_header, "Allow", Allow, parse_method);
-impl_array_parser!(parse_supported_header, "Supported", Supported, parse_string);
-impl_named_parser!(parse_to_header, "To", To);
-impl_named_parser!(parse_from_header, "From", From);
-impl_named_parser!(parse_contact_header, "Contact", Contact);
+impl_array_parser!(parse_allow_events_header, "Allow-Events", "u", AllowEvents, parse_token);
+impl_array_parser!(parse_supported_header, "Supported", "k", Supported, parse_token);
+impl_array_parser!(parse_content_encoding_header, "Content-Encoding", "e", ContentEncoding, parse_token);
+impl_array_parser!(parse_require_header, "Require", Require, parse_token);
+impl_array_parser!(parse_accept_contact_header, "Accept-Contact", "a", AcceptContact, parse_caller_preference);
+impl_array_parser!(parse_reject_contact_header, "Reject-Contact", "j", RejectContact, parse_caller_preference);
+impl_array_parser!(parse_request_disposition_header, "Request-Disposition", "d", RequestDisposition, parse_token);
+impl_array_parser!(parse_unsupported_header, "Unsupported", Unsupported, parse_token);
+impl_named_parser!(parse_to_header, "To", "t", To);
+impl_named_parser!(parse_from_header, "From", "f", From);
 impl_named_parser!(parse_reply_to_header, "Reply-To", ReplyTo);
-impl_type_parser!(parse_content_type_header, "Content-Type", ContentType);
-impl_type_parser!(
-    parse_content_encoding_header,
-    "Content-Encoding",
-    ContentEncoding
-);
-impl_type_parser!(
-    parse_accept_encoding_header,
-    "Accept-Encoding",
-    AcceptEncoding
-);
+impl_named_parser!(parse_refer_to_header, "Refer-To", "r", ReferTo);
+impl_named_parser!(parse_referred_by_header, "Referred-By", "b", ReferredBy);
+impl_named_list_parser!(parse_route_header, "Route", Route);
+impl_named_list_parser!(parse_path_header, "Path", Path);
+impl_named_list_parser!(parse_service_route_header, "Service-Route", ServiceRoute);
+impl_named_list_parser!(parse_history_info_header, "History-Info", HistoryInfo);
+impl_named_list_parser!(parse_diversion_header, "Diversion", Diversion);
+impl_named_list_parser!(parse_record_route_header, "Record-Route", RecordRoute);
+impl_named_list_parser!(parse_p_asserted_identity_header, "P-Asserted-Identity", PAssertedIdentity);
+impl_named_list_parser!(parse_p_preferred_identity_header, "P-Preferred-Identity", PPreferredIdentity);
 impl_lang_parser!(
     parse_content_language_header,
     "Content-Language",
     ContentLanguage
 );
-impl_lang_parser!(
-    parse_accept_language_header,
-    "Accept-Language",
-    AcceptLanguage
-);
 
 fn parse_auth_header_vars<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], HashMap<String, String>, E> {
-    let mut map = HashMap::new();
-    let mut data = input;
-    while let Ok((remains, (key, value))) = parse_key_value_pair::<E>(data) {
-        map.insert(key, value);
-        data = remains;
+    let (input, params) = parse_auth_params::<E>(input)?;
+    Ok((input, params.into_iter().collect()))
+}
+
+/// Parse a comma separated list of auth parameters,
+/// whitespace around the commas is ignored.
+pub fn parse_auth_params<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Vec<(String, String)>, E> {
+    let (mut input, first) = parse_auth_param::<E>(input)?;
+    let mut params = vec![first];
+    while let Ok((data, param)) = preceded(
+        tuple((take_while(is_space), char(','), take_while(is_space))),
+        parse_auth_param::<E>
+    )(input) {
+        params.push(param);
+        input = data;
     }
-    Ok((data, map))
+    let (input, _) = take_while(is_space)(input)?;
+    Ok((input, params))
 }
 
+/// Parse a header that has no dedicated parser, the name keeps its
+/// original casing and the value is stored as received.
 pub fn parse_other_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
     let (input, _) = opt(tag("\r\n"))(input)?;
-    let (input, key) = map_res(take_while(|item| is_alphanumeric(item) || item == b'-'), slice_to_string::<E>)(input)?;
+    let (input, key) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
     let (input, _) = char(':')(input)?;
-    let (input, value) = map_res(take_until("\r"), slice_to_string::<E>)(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, value) = map_res(take_until("\r\n"), slice_to_string_nullable)(input)?;
     let (input, _) = tag("\r\n")(input)?;
     Ok((input, Header::Other(key, value)))
 }
 
+/// Parse a Timestamp header, the delay following the timestamp is optional.
+pub fn parse_timestamp_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("Timestamp")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, timestamp) = parse_timestamp_value(input)?;
+    let (input, delay) = opt(preceded(take_while1(is_space), parse_timestamp_value))(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::Timestamp(timestamp, delay)))
+}
+
+fn parse_timestamp_value<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], f64, E> {
+    map_res(
+        recognize(pair(take_while1(is_digit), opt(pair(char('.'), take_while(is_digit))))),
+        parse_f64
+    )(input)
+}
+
 pub fn parse_cseq_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, 
 E> {
     let (input, _) = opt(tag("\r\n"))(input)?;
@@ -294,17 +531,55 @@ E> {
     Ok((input, Header::CSeq(value, method)))
 }
 
+pub fn parse_rack_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = tag_no_case("RAck")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, rseq) = map_res(take_while1(is_digit), parse_u32)(input)?;
+    let (input, _) = take_while1(is_space)(input)?;
+    let (input, cseq) = map_res(take_while1(is_digit), parse_u32)(input)?;
+    let (input, _) = take_while1(is_space)(input)?;
+    let (input, method) = parse_method(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::RAck(rseq, cseq, method)))
+}
+
 pub fn parse_via_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
-    let (input, _) = tag_no_case("Via")(input)?;
+    let (input, _) = parse_via_name(input)?;
+    let (input, value) = parse_via_value(input)?;
+    Ok((input, Header::Via(value)))
+}
+
+/// Parse a Via header line that may carry several comma separated
+/// values, each value becomes its own `Header::Via` entry.
+pub fn parse_via_headers<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Vec<Header>, E> {
+    let (input, _) = opt(tag("\r\n"))(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = parse_via_name(input)?;
+    let (input, values) = separated_list1(
+        tuple((take_while(is_space), char(','), take_while(is_space))),
+        parse_via_value
+    )(input)?;
+    Ok((input, values.into_iter().map(Header::Via).collect()))
+}
+
+fn parse_via_name<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (), E> {
+    let (input, _) = alt((tag_no_case("Via"), tag_no_case("v")))(input)?;
     let (input, _) = opt(take_while(is_space))(input)?;
     let (input, _) = char(':')(input)?;
     let (input, _) = opt(take_while(is_space))(input)?;
+    Ok((input, ()))
+}
+
+/// Parse a single Via value, e.g. `SIP/2.0/UDP host;branch=z9hG4bK`.
+pub fn parse_via_value<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], via::ViaHeader, E> {
     let (input, version) = parse_version(input)?;
     let (input, _) = char('/')(input)?;
     let (input, transport) = parse_transport(input)?;
     let (input, _) = opt(take_while(is_space))(input)?;
     let (input, uri) = parse_uri(input)?;
-    Ok((input, Header::Via(via::ViaHeader { version, transport, uri })))
+    Ok((input, via::ViaHeader { version, transport, uri }))
 }
 
 pub fn parse_www_authenticate_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
@@ -313,12 +588,99 @@ pub fn parse_www_authenticate_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8
     let (input, _) = opt(take_while(is_space))(input)?;
     let (input, _) = char(':')(input)?;
     let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, challenge) = parse_auth_challenge(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::WwwAuthenticate(challenge)))
+}
+
+pub fn parse_proxy_authenticate_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = opt(tag("\r\n"))(input)?;
+    let (input, _) = tag_no_case("Proxy-Authenticate")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, challenge) = parse_auth_challenge(input)?;
+    let (input, _) = tag("\r\n")(input)?;
+    Ok((input, Header::ProxyAuthenticate(challenge)))
+}
+
+/// Parse a digest challenge. Parameters may appear in any order,
+/// separated by commas with optional whitespace around them.
+pub fn parse_auth_challenge<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], auth::AuthChallenge, E> {
     let (input, schema) = parse_auth_schema::<E>(input)?;
-    let (input, _) = char(' ')(input)?;
-    let (input, res) = parse_auth_header_vars(input)?;
-    let (input, _) = opt(char(' '))(input)?;
+    let (input, _) = take_while1(is_space)(input)?;
+    let (input, params) = parse_auth_params::<E>(input)?;
+
+    let mut challenge = auth::AuthChallenge::new("", "");
+    challenge.schema = schema;
+    let (mut realm, mut nonce) = (None, None);
+    for (key, value) in params {
+        match key.to_ascii_lowercase().as_str() {
+            "realm" => realm = Some(value),
+            "nonce" => nonce = Some(value),
+            "opaque" => challenge.opaque = Some(value),
+            "algorithm" => challenge.algorithm = Some(value),
+            "qop" => challenge.qop = value
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect(),
+            "stale" => challenge.stale = value.eq_ignore_ascii_case("true"),
+            _ => challenge.params.push((key, value)),
+        }
+    }
+    match (realm, nonce) {
+        (Some(realm), Some(nonce)) => {
+            challenge.realm = realm;
+            challenge.nonce = nonce;
+            Ok((input, challenge))
+        },
+        _ => Err(nom::Err::Error(E::from_error_kind(input, nom::error::ErrorKind::Verify))),
+    }
+}
+
+/// Parse an Authentication-Info header, e.g. `Authentication-Info: nextnonce="47364c23"`.
+pub fn parse_authentication_info_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
+    let (input, _) = opt(tag("\r\n"))(input)?;
+    let (input, _) = tag_no_case("Authentication-Info")(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, _) = char(':')(input)?;
+    let (input, _) = opt(take_while(is_space))(input)?;
+    let (input, info) = parse_authentication_info(input)?;
     let (input, _) = tag("\r\n")(input)?;
-    Ok((input, Header::WwwAuthenticate(auth::AuthHeader(schema, res))))
+    Ok((input, Header::AuthenticationInfo(info)))
+}
+
+/// Parse the value of an Authentication-Info header, a comma
+/// separated mix of quoted and unquoted parameters.
+pub fn parse_authentication_info<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], auth::AuthenticationInfo, E> {
+    let (input, params) = parse_auth_params::<E>(input)?;
+    let mut info = auth::AuthenticationInfo::new();
+    for (key, value) in params {
+        match key.to_ascii_lowercase().as_str() {
+            "nextnonce" => info.nextnonce = Some(value),
+            "qop" => info.qop = Some(value),
+            "rspauth" => info.rspauth = Some(value),
+            "cnonce" => info.cnonce = Some(value),
+            "nc" => info.nc = Some(value),
+            _ => info.params.push((key, value)),
+        }
+    }
+    Ok((input, info))
+}
+
+/// Parse a single `key=value` auth parameter where
+/// the value is either a token or a quoted string.
+pub fn parse_auth_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (String, String), E> {
+    let (input, key) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, _) = char('=')(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (input, value) = alt((
+        parse_quoted_string::<E>,
+        map_res(take_while1(is_token_char), slice_to_string::<E>)
+    ))(input)?;
+    Ok((input, (key, value)))
 }
 
 pub fn parse_authorization_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Header, E> {
@@ -328,9 +690,8 @@ pub fn parse_authorization_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8])
     let (input, _) = char(':')(input)?;
     let (input, _) = opt(take_while(is_space))(input)?;
     let (input, schema) = parse_auth_schema(input)?;
-    let (input, _) = char(' ')(input)?;
+    let (input, _) = take_while1(is_space)(input)?;
     let (input, res) = parse_auth_header_vars(input)?;
-    let (input, _) = opt(char(' '))(input)?;
     let (input, _) = tag("\r\n")(input)?;
     Ok((input, Header::Authorization(auth::AuthHeader(schema, res))))
 }
@@ -0,0 +1,46 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    combinator::map,
+    error::ParseError
+};
+
+use std::fmt;
+
+use crate::parse::parse_token;
+
+/// Value of the Priority header.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Priority {
+    Emergency,
+    Urgent,
+    Normal,
+    NonUrgent,
+    Extension(String),
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Priority::Emergency => write!(f, "emergency"),
+            Priority::Urgent => write!(f, "urgent"),
+            Priority::Normal => write!(f, "normal"),
+            Priority::NonUrgent => write!(f, "non-urgent"),
+            Priority::Extension(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Parse a Priority value, the known priorities are matched
+/// case insensitively and anything else is an extension.
+pub fn parse_priority<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Priority, E> {
+    map(parse_token, |token: String| match token.to_ascii_lowercase().as_str() {
+        "emergency" => Priority::Emergency,
+        "urgent" => Priority::Urgent,
+        "normal" => Priority::Normal,
+        "non-urgent" => Priority::NonUrgent,
+        _ => Priority::Extension(token),
+    })(input)
+}
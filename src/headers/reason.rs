@@ -0,0 +1,76 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::take_while1,
+    combinator::map_res,
+    error::{ErrorKind, ParseError}
+};
+
+use std::fmt;
+
+use crate::parse::{is_token_char, slice_to_string};
+
+use super::named::{parse_generic_params, write_generic_params, write_quoted_string, GenericParams};
+
+/// Single value of the Reason header (RFC 3326), e.g.
+/// `Q.850;cause=16;text="Normal call clearing"`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Reason {
+    /// Protocol the cause belongs to, usually `SIP` or `Q.850`.
+    pub protocol: String,
+    pub cause: u16,
+    /// Reason text without the surrounding quotes.
+    pub text: Option<String>,
+    /// Any other parameters in the order they were received.
+    pub params: GenericParams,
+}
+
+impl Reason {
+    pub fn new<S: Into<String>>(protocol: S, cause: u16) -> Reason {
+        Reason { protocol: protocol.into(), cause, text: None, params: vec![] }
+    }
+
+    /// Set the reason text.
+    pub fn text<S: Into<String>>(mut self, text: S) -> Reason {
+        self.text = Some(text.into());
+        self
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{};cause={}", self.protocol, self.cause)?;
+        if let Some(text) = &self.text {
+            write!(f, ";text=")?;
+            write_quoted_string(text, f)?;
+        }
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Parse a single Reason value, the `cause` parameter is required.
+pub fn parse_reason<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Reason, E> {
+    let (rest, protocol) = map_res(take_while1(is_token_char), slice_to_string::<E>)(input)?;
+    let (rest, params) = parse_generic_params(rest)?;
+    let mut cause = None;
+    let mut reason = Reason::new(protocol, 0);
+    for (key, value) in params {
+        match (key.to_ascii_lowercase().as_str(), value) {
+            ("cause", Some(value)) => match value.parse() {
+                Ok(value) => cause = Some(value),
+                Err(_) => return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Digit))),
+            },
+            ("text", Some(value)) => reason.text = Some(value),
+            (_, value) => reason.params.push((key, value)),
+        }
+    }
+    match cause {
+        Some(cause) => {
+            reason.cause = cause;
+            Ok((rest, reason))
+        },
+        None => Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify))),
+    }
+}
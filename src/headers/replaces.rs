@@ -0,0 +1,84 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    error::{ErrorKind, ParseError}
+};
+
+use std::fmt;
+
+use crate::parse::parse_call_id;
+
+use super::named::{parse_generic_params, write_generic_params, GenericParams};
+
+/// Value of the Replaces header (RFC 3891), e.g.
+/// `12345@192.0.2.1;from-tag=abc;to-tag=def`. The tags and the
+/// `early-only` flag are kept with the other parameters so they
+/// are written back in the order they were received.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Replaces {
+    pub call_id: String,
+    pub params: GenericParams,
+}
+
+impl Replaces {
+    pub fn new<C: Into<String>, F: Into<String>, T: Into<String>>(call_id: C, from_tag: F, to_tag: T) -> Replaces {
+        Replaces {
+            call_id: call_id.into(),
+            params: vec![
+                ("from-tag".into(), Some(from_tag.into())),
+                ("to-tag".into(), Some(to_tag.into())),
+            ],
+        }
+    }
+
+    /// Set the `early-only` flag.
+    pub fn early_only(mut self) -> Replaces {
+        if !self.is_early_only() {
+            self.params.push(("early-only".into(), None));
+        }
+        self
+    }
+
+    /// Retrieve the `from-tag` parameter.
+    pub fn from_tag(&self) -> Option<&str> {
+        self.get_param("from-tag")
+    }
+
+    /// Retrieve the `to-tag` parameter.
+    pub fn to_tag(&self) -> Option<&str> {
+        self.get_param("to-tag")
+    }
+
+    /// Determine if the `early-only` flag is present.
+    pub fn is_early_only(&self) -> bool {
+        self.params.iter().any(|(key, value)| key.eq_ignore_ascii_case("early-only") && value.is_none())
+    }
+
+    fn get_param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| value.as_deref())
+    }
+}
+
+impl fmt::Display for Replaces {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.call_id)?;
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Parse the value of a Replaces header. The `from-tag`
+/// and `to-tag` parameters are required.
+pub fn parse_replaces<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Replaces, E> {
+    let (rest, call_id) = parse_call_id(input)?;
+    let (rest, params) = parse_generic_params(rest)?;
+    let replaces = Replaces { call_id, params };
+    match (replaces.from_tag(), replaces.to_tag()) {
+        (Some(_), Some(_)) => Ok((rest, replaces)),
+        _ => Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify))),
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::{take_while, take_while1},
+    character::{complete::char, is_digit, is_space},
+    combinator::{map_res, opt},
+    error::{ErrorKind, ParseError}
+};
+
+use std::fmt;
+
+use crate::parse::{parse_u32, slice_to_string_nullable};
+
+use super::named::{parse_named_field_param, write_generic_params};
+
+/// Value of the Retry-After header, e.g.
+/// `120 (I'm in a meeting);duration=3600`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RetryAfter {
+    /// Seconds to wait before retrying.
+    pub delay: u32,
+    /// Comment without the surrounding parentheses.
+    pub comment: Option<String>,
+    /// Value of the `duration` parameter in seconds.
+    pub duration: Option<u32>,
+    /// Any other parameters in the order they were received.
+    pub params: Vec<(String, Option<String>)>,
+}
+
+impl RetryAfter {
+    pub fn new(delay: u32) -> RetryAfter {
+        RetryAfter { delay, comment: None, duration: None, params: vec![] }
+    }
+
+    /// Set the comment.
+    pub fn comment<S: Into<String>>(mut self, comment: S) -> RetryAfter {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Set the `duration` parameter.
+    pub fn duration(mut self, duration: u32) -> RetryAfter {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+impl From<u32> for RetryAfter {
+    fn from(delay: u32) -> RetryAfter {
+        RetryAfter::new(delay)
+    }
+}
+
+impl fmt::Display for RetryAfter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.delay)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " ({})", comment)?;
+        }
+        if let Some(duration) = self.duration {
+            write!(f, ";duration={}", duration)?;
+        }
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Parse a parenthesised comment, nested parentheses are kept
+/// in the returned text while the outer ones are removed.
+pub fn parse_comment<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
+    let (body, _) = char('(')(input)?;
+    let mut depth = 0;
+    for (index, c) in body.iter().enumerate() {
+        match c {
+            b'(' => depth += 1,
+            b')' if depth == 0 => {
+                let comment = slice_to_string_nullable(&body[..index])
+                    .map_err(|_| nom::Err::Error(E::from_error_kind(body, ErrorKind::Verify)))?;
+                return Ok((&body[index + 1..], comment));
+            },
+            b')' => depth -= 1,
+            _ => {},
+        }
+    }
+    Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Char)))
+}
+
+/// Parse the value of a Retry-After header.
+pub fn parse_retry_after<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], RetryAfter, E> {
+    let (input, delay) = map_res(take_while1(is_digit), parse_u32)(input)?;
+    let (input, _) = take_while(is_space)(input)?;
+    let (mut input, comment) = opt(parse_comment)(input)?;
+    let mut retry = RetryAfter { delay, comment, duration: None, params: vec![] };
+    loop {
+        let (rest, _) = take_while(is_space)(input)?;
+        let (rest, (key, value)) = match parse_named_field_param::<E>(rest) {
+            Ok(param) => param,
+            Err(_) => break,
+        };
+        if key.eq_ignore_ascii_case("duration") {
+            let value = value.unwrap_or_default();
+            retry.duration = Some(parse_u32(value.as_bytes())
+                .map_err(|_| nom::Err::Error(E::from_error_kind(input, ErrorKind::Digit)))?);
+        } else {
+            retry.params.push((key, value));
+        }
+        input = rest;
+    }
+    Ok((input, retry))
+}
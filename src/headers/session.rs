@@ -0,0 +1,91 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::{take_while, take_while1},
+    character::{is_digit, is_space},
+    combinator::map_res,
+    error::{ErrorKind, ParseError}
+};
+
+use std::fmt;
+
+use crate::parse::parse_u32;
+
+use super::named::{parse_named_field_param, write_generic_params};
+
+/// Value of the Session-Expires `refresher` parameter.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Refresher {
+    Uac,
+    Uas,
+}
+
+impl fmt::Display for Refresher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refresher::Uac => write!(f, "uac"),
+            Refresher::Uas => write!(f, "uas"),
+        }
+    }
+}
+
+/// Value of the Session-Expires header (RFC 4028),
+/// e.g. `1800;refresher=uac`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionExpires {
+    /// Session interval in seconds.
+    pub delta: u32,
+    pub refresher: Option<Refresher>,
+    /// Any other parameters in the order they were received.
+    pub params: Vec<(String, Option<String>)>,
+}
+
+impl SessionExpires {
+    pub fn new(delta: u32) -> SessionExpires {
+        SessionExpires { delta, refresher: None, params: vec![] }
+    }
+
+    /// Set the `refresher` parameter.
+    pub fn refresher(mut self, refresher: Refresher) -> SessionExpires {
+        self.refresher = Some(refresher);
+        self
+    }
+}
+
+impl fmt::Display for SessionExpires {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.delta)?;
+        if let Some(refresher) = self.refresher {
+            write!(f, ";refresher={}", refresher)?;
+        }
+        write_generic_params(&self.params, f)
+    }
+}
+
+/// Parse the value of a Session-Expires header. A `refresher`
+/// parameter other than `uac` or `uas` is an error.
+pub fn parse_session_expires<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], SessionExpires, E> {
+    let (mut input, delta) = map_res(take_while1(is_digit), parse_u32)(input)?;
+    let mut value = SessionExpires::new(delta);
+    loop {
+        let (rest, _) = take_while(is_space)(input)?;
+        let (rest, (key, param)) = match parse_named_field_param::<E>(rest) {
+            Ok(param) => param,
+            Err(_) => break,
+        };
+        if key.eq_ignore_ascii_case("refresher") {
+            value.refresher = match param.as_ref().map(|p| p.to_ascii_lowercase()).as_deref() {
+                Some("uac") => Some(Refresher::Uac),
+                Some("uas") => Some(Refresher::Uas),
+                _ => return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify))),
+            };
+        } else {
+            value.params.push((key, param));
+        }
+        input = rest;
+    }
+    Ok((input, value))
+}
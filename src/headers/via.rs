@@ -1,9 +1,21 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use crate::*;
 
-use std::fmt;
+use std::{fmt, net::IpAddr};
+
+/// Magic cookie that RFC 3261 branch parameters start with.
+pub const BRANCH_MAGIC_COOKIE: &str = "z9hG4bK";
+
+/// Generate a new RFC 3261 branch parameter, the magic cookie
+/// followed by an MD5 hash of 16 bytes from the thread local CSPRNG.
+pub fn gen_branch() -> String {
+    format!("{}{:x}", BRANCH_MAGIC_COOKIE, md5::compute(rand::random::<[u8; 16]>()))
+}
 
 /// Value used in the Via Header.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ViaHeader {
     pub version: Version,
     pub transport: Transport,
@@ -18,6 +30,78 @@ impl ViaHeader {
             version: Version::default(),
         }
     }
+
+    /// Set the `branch` parameter, replacing any existing one.
+    pub fn with_branch<S: Into<String>>(mut self, branch: S) -> ViaHeader {
+        self.uri.parameters.retain(|param| !matches!(param, UriParam::Branch(_)));
+        self.uri.parameters.push(UriParam::Branch(branch.into()));
+        self
+    }
+
+    /// Retrieve the value of the `branch` parameter.
+    pub fn branch(&self) -> Option<&str> {
+        self.uri.parameters.iter().find_map(|param| match param {
+            UriParam::Branch(branch) => Some(branch.as_str()),
+            _ => None
+        })
+    }
+
+    /// Retrieve the value of the `received` parameter.
+    pub fn received(&self) -> Option<&Domain> {
+        self.uri.parameters.iter().find_map(|param| match param {
+            UriParam::Received(domain) => Some(domain),
+            _ => None
+        })
+    }
+
+    /// Retrieve the `rport` parameter. `Some(None)` is
+    /// returned when rport is present without a value.
+    pub fn rport(&self) -> Option<Option<u16>> {
+        self.uri.parameters.iter().find_map(|param| match param {
+            UriParam::RPort(port) => Some(*port),
+            _ => None
+        })
+    }
+
+    /// Record the source address of a received request as a proxy
+    /// must (RFC 3261 section 18.2.1, RFC 3581). `received` is set
+    /// when `src` differs from the sent-by host or the client asked
+    /// for rport, a bare `rport` is then given the source `port`.
+    pub fn set_received(&mut self, src: IpAddr, port: Option<u16>) {
+        let sent_by_matches = match (&self.uri.host, src) {
+            (Domain::Ipv4(host, _), IpAddr::V4(src)) => *host == src,
+            (Domain::Ipv6(host, _), IpAddr::V6(src)) => *host == src,
+            _ => false,
+        };
+        let rport_requested = self.rport() == Some(None);
+        if !sent_by_matches || rport_requested {
+            let received = match src {
+                IpAddr::V4(addr) => Domain::Ipv4(addr, None),
+                IpAddr::V6(addr) => Domain::Ipv6(addr, None),
+            };
+            match self.uri.parameters.iter_mut().find(|param| matches!(param, UriParam::Received(_))) {
+                Some(param) => *param = UriParam::Received(received),
+                None => self.uri.parameters.push(UriParam::Received(received)),
+            }
+        }
+        if let Some(port) = port.filter(|_| rport_requested) {
+            for param in self.uri.parameters.iter_mut() {
+                if let UriParam::RPort(None) = param {
+                    *param = UriParam::RPort(Some(port));
+                }
+            }
+        }
+    }
+
+    /// Retrieve the value of the `maddr` parameter.
+    pub fn maddr(&self) -> Option<&Domain> {
+        self.uri.maddr()
+    }
+
+    /// Retrieve the value of the `ttl` parameter.
+    pub fn ttl(&self) -> Option<u8> {
+        self.uri.ttl()
+    }
 }
 
 impl fmt::Display for ViaHeader {
@@ -0,0 +1,51 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::{take_while1, take_while_m_n},
+    character::{complete::char, is_digit},
+    combinator::map_res,
+    error::ParseError
+};
+
+use std::fmt;
+
+use crate::parse::{parse_quoted_string, parse_u32, slice_to_string};
+
+use super::named::write_quoted_string;
+
+/// Single value of the Warning header, e.g.
+/// `370 devnull "Choose a bigger pipe"`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Warning {
+    /// Three digit warn-code.
+    pub code: u16,
+    /// Host or pseudonym of the agent adding the warning.
+    pub agent: String,
+    /// Warning text without the surrounding quotes.
+    pub text: String,
+}
+
+impl Warning {
+    pub fn new<A: Into<String>, T: Into<String>>(code: u16, agent: A, text: T) -> Warning {
+        Warning { code, agent: agent.into(), text: text.into() }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:03} {} ", self.code, self.agent)?;
+        write_quoted_string(&self.text, f)
+    }
+}
+
+/// Parse a single Warning value.
+pub fn parse_warning<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Warning, E> {
+    let (input, code) = map_res(take_while_m_n(3, 3, is_digit), parse_u32)(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, agent) = map_res(take_while1(|c| c != b' ' && c != b',' && c != b'\r'), slice_to_string::<E>)(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, text) = parse_quoted_string(input)?;
+    Ok((input, Warning { code: code as u16, agent, text }))
+}
@@ -1,69 +1,173 @@
 use std::fmt;
 
 use super::*;
+use crate::core::view::expand_compact;
+
+impl Header {
+    /// The header name as written on the wire, using the
+    /// exact casing of the RFC that defines the header.
+    pub fn name(&self) -> &str {
+        match self {
+            Header::To(_) => "To",
+            Header::From(_) => "From",
+            Header::Contact(_) => "Contact",
+            Header::ReplyTo(_) => "Reply-To",
+            Header::ReferTo(_) => "Refer-To",
+            Header::ReferredBy(_) => "Referred-By",
+            Header::Replaces(_) => "Replaces",
+            Header::CSeq(_, _) => "CSeq",
+            Header::MaxForwards(_) => "Max-Forwards",
+            Header::Expires(_) => "Expires",
+            Header::Accept(_) => "Accept",
+            Header::Allow(_) => "Allow",
+            Header::AllowEvents(_) => "Allow-Events",
+            Header::ContentEncoding(_) => "Content-Encoding",
+            Header::ContentLength(_) => "Content-Length",
+            Header::ContentType(_) => "Content-Type",
+            Header::UserAgent(_) => "User-Agent",
+            Header::CallId(_) => "Call-ID",
+            Header::ContentLanguage(_) => "Content-Language",
+            Header::AcceptLanguage(_) => "Accept-Language",
+            Header::AcceptEncoding(_) => "Accept-Encoding",
+            Header::AlertInfo(_) => "Alert-Info",
+            Header::Event(_) => "Event",
+            Header::ErrorInfo(_) => "Error-Info",
+            Header::AuthenticationInfo(_) => "Authentication-Info",
+            Header::Authorization(_) => "Authorization",
+            Header::CallInfo(_) => "Call-Info",
+            Header::InReplyTo(_) => "In-Reply-To",
+            Header::ContentDisposition(_) => "Content-Disposition",
+            Header::Date(_) => "Date",
+            Header::MinExpires(_) => "Min-Expires",
+            Header::MinSE(_) => "Min-SE",
+            Header::MimeVersion(_) => "MIME-Version",
+            Header::Organization(_) => "Organization",
+            Header::ProxyAuthenticate(_) => "Proxy-Authenticate",
+            Header::ProxyAuthorization(_) => "Proxy-Authorization",
+            Header::ProxyRequire(_) => "Proxy-Require",
+            Header::Require(_) => "Require",
+            Header::AcceptContact(_) => "Accept-Contact",
+            Header::RejectContact(_) => "Reject-Contact",
+            Header::RequestDisposition(_) => "Request-Disposition",
+            Header::RSeq(_) => "RSeq",
+            Header::RAck(_, _, _) => "RAck",
+            Header::RetryAfter(_) => "Retry-After",
+            Header::Route(_) => "Route",
+            Header::Path(_) => "Path",
+            Header::ServiceRoute(_) => "Service-Route",
+            Header::HistoryInfo(_) => "History-Info",
+            Header::Diversion(_) => "Diversion",
+            Header::Subject(_) => "Subject",
+            Header::SubscriptionState(_) => "Subscription-State",
+            Header::RecordRoute(_) => "Record-Route",
+            Header::Reason(_) => "Reason",
+            Header::PAssertedIdentity(_) => "P-Asserted-Identity",
+            Header::PPreferredIdentity(_) => "P-Preferred-Identity",
+            Header::Server(_) => "Server",
+            Header::SessionExpires(_) => "Session-Expires",
+            Header::Supported(_) => "Supported",
+            Header::Timestamp(_, _) => "Timestamp",
+            Header::Unsupported(_) => "Unsupported",
+            Header::Warning(_) => "Warning",
+            Header::Via(_) => "Via",
+            Header::Priority(_) => "Priority",
+            Header::WwwAuthenticate(_) => "WWW-Authenticate",
+            Header::XFsSendingMessage(_) => "X-FS-Sending-Message",
+            Header::Other(key, _) => key,
+        }
+    }
+
+    /// Determine if this header is called `name`, ignoring case
+    /// and expanding compact header names.
+    pub fn is(&self, name: &str) -> bool {
+        self.name().eq_ignore_ascii_case(expand_compact(name))
+    }
+}
 
 impl fmt::Display for Header {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = self.name();
         match self {
-            Header::To(value) => write_simple_field("To", value, f),
-            Header::From(value) => write_simple_field("From", value, f),
-            Header::Contact(value) => write_simple_field("Contact", value, f),
-            Header::ReplyTo(value) => write_simple_field("Reply-To", value, f),
-            Header::CSeq(num, method) => write!(f, "CSeq: {} {}", num, method),
-            Header::MaxForwards(num) => write!(f, "Max-Forwards: {}", num),
-            Header::Expires(num) => write!(f, "Expires: {}", num),
-            Header::Accept(methods) => write_method_array_header("Accept", f, methods),
-            Header::Allow(methods) => write_method_array_header("Allow", f, methods),
-            Header::ContentEncoding(ty) => write_simple_field("Content-Encoding", ty, f),
-            Header::ContentLength(len) => write_simple_field("Content-Length", len, f),
-            Header::ContentType(ty) => write_simple_field("Content-Type", ty, f),
-            Header::UserAgent(agent) => write_simple_field("User-Agent", agent, f),
-            Header::CallId(call_id) => write_simple_field("Call-ID", call_id, f),
-            Header::ContentLanguage(lang) => write_simple_field("Content-Language", lang, f),
-            Header::AcceptLanguage(lang) => write_simple_field("Accept-Language", lang, f),
-            Header::AcceptEncoding(ty) => write_simple_field("Accept-Encoding", ty, f),
-            Header::AlertInfo(data) => write_simple_field("Alert-Info", data, f),
-            Header::ErrorInfo(data) => write_simple_field("Error-Info", data, f),
-            Header::AuthenticationInfo(data) => write_simple_field("Authentication-Info", data, f),
-            Header::Authorization(data) => write_auth_header("Authorization", data, f),
-            Header::CallInfo(data) => write_simple_field("Call-Info", data, f),
-            Header::InReplyTo(data) => write_simple_field("In-Reply-To", data, f),
-            Header::ContentDisposition(data) => write_simple_field("Content-Disposition", data, f),
-            Header::Date(string) => write_simple_field("Date", string, f),
-            Header::MinExpires(exp) => write_simple_field("Min-Expires", exp, f),
-            Header::MimeVersion(exp) => write_simple_field("MIME-Version", exp, f),
-            Header::Organization(org) => write_simple_field("Organization", org, f),
-            Header::ProxyAuthenticate(data) => write_simple_field("Proxy-Authenticate", data, f),
-            Header::ProxyAuthorization(data) => write_simple_field("Proxy-Authorization", data, f),
-            Header::ProxyRequire(data) => write_simple_field("Proxy-Require", data, f),
-            Header::Require(data) => write_simple_field("Require", data, f),
-            Header::RetryAfter(data) => write_simple_field("Retry-After", data, f),
-            Header::Route(data) => write_simple_field("Route", data, f),
-            Header::Subject(data) => write_simple_field("Subject", data, f),
-            Header::RecordRoute(data) => write_simple_field("Record-Route", data, f),
-            Header::Server(data) => write_simple_field("Server", data, f),
-            Header::Supported(data) => write_string_array_header("Supported", f, data),
-            Header::Timestamp(data) => write_simple_field("Timestamp", data, f),
-            Header::Unsupported(data) => write_simple_field("Unsupported", data, f),
-            Header::Warning(data) => write_simple_field("Warning", data, f),
+            Header::To(value) => write_simple_field(name, value, f),
+            Header::From(value) => write_simple_field(name, value, f),
+            Header::Contact(value) => write_simple_field(name, value, f),
+            Header::ReplyTo(value) => write_simple_field(name, value, f),
+            Header::ReferTo(value) => write_simple_field(name, value, f),
+            Header::ReferredBy(value) => write_simple_field(name, value, f),
+            Header::Replaces(value) => write_simple_field(name, value, f),
+            Header::CSeq(num, method) => write!(f, "{}: {} {}", name, num, method),
+            Header::MaxForwards(num) => write!(f, "{}: {}", name, num),
+            Header::Expires(num) => write!(f, "{}: {}", name, num),
+            Header::Accept(ranges) => write_media_range_list_header(name, f, ranges),
+            Header::Allow(methods) => write_method_list_header(name, f, methods),
+            Header::AllowEvents(data) => write_string_list_header(name, f, data),
+            Header::ContentEncoding(codings) => write_string_list_header(name, f, codings),
+            Header::ContentLength(len) => write_simple_field(name, len, f),
+            Header::ContentType(ty) => write_simple_field(name, ty, f),
+            Header::UserAgent(agent) => write_simple_field(name, agent, f),
+            Header::CallId(call_id) => write_simple_field(name, call_id, f),
+            Header::ContentLanguage(lang) => write_simple_field(name, lang, f),
+            Header::AcceptLanguage(values) => write_accept_list_header(name, f, values),
+            Header::AcceptEncoding(values) => write_accept_list_header(name, f, values),
+            Header::AlertInfo(data) => write_info_list_header(name, f, data),
+            Header::Event(data) => write_simple_field(name, data, f),
+            Header::ErrorInfo(data) => write_simple_field(name, data, f),
+            Header::AuthenticationInfo(data) => write_simple_field(name, data, f),
+            Header::Authorization(data) => write_auth_header(name, data, f),
+            Header::CallInfo(data) => write_info_list_header(name, f, data),
+            Header::InReplyTo(data) => write_string_list_header(name, f, data),
+            Header::ContentDisposition(data) => write_simple_field(name, data, f),
+            Header::Date(string) => write_simple_field(name, string, f),
+            Header::MinExpires(exp) => write_simple_field(name, exp, f),
+            Header::MinSE(exp) => write_simple_field(name, exp, f),
+            Header::MimeVersion(exp) => write_simple_field(name, exp, f),
+            Header::Organization(org) => write_simple_field(name, org, f),
+            Header::ProxyAuthenticate(data) => write_auth_header(name, data, f),
+            Header::ProxyAuthorization(data) => write_simple_field(name, data, f),
+            Header::ProxyRequire(data) => write_simple_field(name, data, f),
+            Header::Require(data) => write_string_list_header(name, f, data),
+            Header::AcceptContact(data) => write_caller_preference_list_header(name, f, data),
+            Header::RejectContact(data) => write_caller_preference_list_header(name, f, data),
+            Header::RequestDisposition(data) => write_string_list_header(name, f, data),
+            Header::RSeq(num) => write!(f, "{}: {}", name, num),
+            Header::RAck(rseq, cseq, method) => write!(f, "{}: {} {} {}", name, rseq, cseq, method),
+            Header::RetryAfter(data) => write_simple_field(name, data, f),
+            Header::Route(data) => write_named_array_header(name, f, data),
+            Header::Path(data) => write_named_array_header(name, f, data),
+            Header::ServiceRoute(data) => write_named_array_header(name, f, data),
+            Header::HistoryInfo(data) => write_named_array_header(name, f, data),
+            Header::Diversion(data) => write_named_array_header(name, f, data),
+            Header::Subject(data) => write_simple_field(name, data, f),
+            Header::SubscriptionState(data) => write_simple_field(name, data, f),
+            Header::RecordRoute(data) => write_named_array_header(name, f, data),
+            Header::Reason(data) => write_reason_list_header(name, f, data),
+            Header::PAssertedIdentity(data) => write_named_array_header(name, f, data),
+            Header::PPreferredIdentity(data) => write_named_array_header(name, f, data),
+            Header::Server(data) => write_simple_field(name, data, f),
+            Header::SessionExpires(data) => write_simple_field(name, data, f),
+            Header::Supported(data) => write_string_list_header(name, f, data),
+            Header::Timestamp(timestamp, Some(delay)) => write!(f, "{}: {} {}", name, timestamp, delay),
+            Header::Timestamp(timestamp, None) => write_simple_field(name, timestamp, f),
+            Header::Unsupported(data) => write_string_list_header(name, f, data),
+            Header::Warning(data) => write_warning_list_header(name, f, data),
             Header::Via(data) => write!(f, "{}", data),
-            Header::Priority(data) => write_simple_field("Priority", data, f),
-            Header::WwwAuthenticate(data) => write_auth_header("WWW-Authenticate", data, f),
-            Header::XFsSendingMessage(data) => write_simple_field("X-FS-Sending-Message", data, f),
-            Header::Other(key, value) => write!(f, "{}: {}", key, value),
+            Header::Priority(data) => write_simple_field(name, data, f),
+            Header::WwwAuthenticate(data) => write_auth_header(name, data, f),
+            Header::XFsSendingMessage(data) => write_simple_field(name, data, f),
+            Header::Other(_, value) => write!(f, "{}: {}", name, value),
         }
     }
 }
 
 macro_rules! write_array_header {
-    ($name:ident, $item:ident) => {
+    ($name:ident, $item:ident, $separator:expr) => {
         fn $name(name: &str, f: &mut fmt::Formatter, v: &[$item]) -> fmt::Result {
             write!(f, "{}: ", name)?;
             for (index, item) in v.iter().enumerate() {
                 if index == 0 {
                     write!(f, "{}", item)?;
                 } else {
-                    write!(f, ",{}", item)?;
+                    write!(f, "{}{}", $separator, item)?;
                 }
             }
             Ok(())
@@ -71,8 +175,27 @@ macro_rules! write_array_header {
     };
 }
 
-write_array_header!(write_method_array_header, Method);
-write_array_header!(write_string_array_header, String);
+write_array_header!(write_method_list_header, Method, ", ");
+write_array_header!(write_string_list_header, String, ", ");
+write_array_header!(write_warning_list_header, Warning, ", ");
+write_array_header!(write_reason_list_header, Reason, ", ");
+write_array_header!(write_media_range_list_header, MediaRange, ", ");
+write_array_header!(write_accept_list_header, AcceptValue, ", ");
+write_array_header!(write_info_list_header, InfoValue, ", ");
+write_array_header!(write_caller_preference_list_header, CallerPreference, ", ");
+
+fn write_named_array_header(name: &str, f: &mut fmt::Formatter, v: &[NamedHeader]) -> fmt::Result {
+    write!(f, "{}: ", name)?;
+    for (index, item) in v.iter().enumerate() {
+        if index == 0 {
+            write!(f, "{}", item)?;
+        } else {
+            write!(f, ", {}", item)?;
+        }
+    }
+    Ok(())
+}
+
 fn write_simple_field<D: fmt::Display>(
     header: &str,
     data: D,
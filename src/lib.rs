@@ -53,6 +53,7 @@
 
 #[macro_use]
 extern crate nom;
+#[cfg(feature = "serde")]
 extern crate serde;
 
 #[macro_use]
@@ -60,31 +61,45 @@ mod macros;
 
 mod client;
 mod core;
+mod error;
 pub mod headers;
+pub mod multipart;
 mod parse;
 mod request;
 mod response;
+mod route;
+pub mod sdp;
 pub mod uri;
 
 pub use crate::{
     client::{
         SoftPhone, MessageHelper, MessageWriter,
-        InviteHelper, RegistrationManager,
+        InviteHelper, RegistrationManager, Dialog,
         HeaderWriteConfig
     },
-    request::RequestGenerator,
+    error::{MessageError, ParseError, TooManyHops, ValidationError, try_parse_message},
+    request::{RequestBuilder, RequestGenerator},
     response::ResponseGenerator,
+    route::{RouteDecision, route_request},
     core::{
-        Transport, Method, Version,
-        SipMessage, parse_message, parse_version,
-        parse_response, parse_request
+        Transport, Method, Version, parse_method,
+        SipMessage, StatusCode, parse_message, parse_version, parse_transport,
+        parse_response, parse_request,
+        RequestLine, parse_request_line,
+        StatusLine, parse_status_line,
+        MessageFramer, TransactionId,
+        MessageView, HeaderView, HeaderViews, parse_message_view
     },
     headers::{
-        ContentType,
+        ContentType, ContentDisposition, Handling, MediaType, MediaRange, AcceptValue, QValue, SipDate, RetryAfter, Warning, Reason,
+        SessionExpires, Refresher, Priority, InfoValue, CallerPreference,
+        EventHeader, SubscriptionState, SubscriptionStatus, Replaces,
         Language,
-        Header, Headers, NamedHeader,
-        AuthHeader, AuthContext, parse_header,
-        AuthSchema, via::ViaHeader
+        Header, Headers, NamedHeader, ContactHeader, decode_encoded_words,
+        AuthChallenge, AuthHeader, AuthContext, AuthenticationInfo, parse_header,
+        AuthSchema, via::{ViaHeader, gen_branch}, gen_call_id, gen_tag
     },
-    uri::{Domain, UriParam, Uri, UriAuth, UriSchema, parse_uri}
+    multipart::{BodyPart, MultipartBody, parse_multipart},
+    sdp::{SessionDescription, SessionDescriptionBuilder, parse_sdp},
+    uri::{Domain, UriParam, UserParam, Uri, UriAuth, UriSchema, parse_uri}
 };
@@ -17,13 +17,16 @@ macro_rules! domain {
     };
 }
 
-/// Generate a URI domain from an ip address.
+/// Generate a URI domain from an ip address. Eight segments
+/// generate an Ipv6 domain.
 /// ```rust
 /// # #[macro_use]
 /// # extern crate libsip;
 /// # fn main() {
 /// let domain = ip_domain!(192, 168, 0, 1);
 /// let domain = ip_domain!(192, 168, 0, 1, 5060);
+/// let domain = ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+/// let domain = ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1, 5060);
 /// # }
 /// ```
 #[macro_export]
@@ -35,6 +38,14 @@ macro_rules! ip_domain {
     ($a:tt, $b:tt, $c:tt, $d:tt, $port:tt) => {
         libsip::Domain::Ipv4(::std::net::Ipv4Addr::new($a, $b, $c, $d), Some($port))
     };
+
+    ($a:tt, $b:tt, $c:tt, $d:tt, $e:tt, $f:tt, $g:tt, $h:tt) => {
+        libsip::Domain::Ipv6(::std::net::Ipv6Addr::new($a, $b, $c, $d, $e, $f, $g, $h), None)
+    };
+
+    ($a:tt, $b:tt, $c:tt, $d:tt, $e:tt, $f:tt, $g:tt, $h:tt, $port:tt) => {
+        libsip::Domain::Ipv6(::std::net::Ipv6Addr::new($a, $b, $c, $d, $e, $f, $g, $h), Some($port))
+    };
 }
 
 /// Generate a URI authentication from credentials.
@@ -71,14 +82,14 @@ macro_rules! named_header {
         libsip::NamedHeader {
             display_name: None,
             uri: $u,
-            params: ::std::collections::HashMap::new(),
+            params: ::std::vec::Vec::new(),
         }
     };
     ($u:tt, $name:tt) => {
         libsip::NamedHeader {
             display_name: Some($name.into()),
             uri: $u,
-            params: ::std::collections::HashMap::new()
+            params: ::std::vec::Vec::new()
         }
     };
 }
@@ -89,7 +100,7 @@ macro_rules! __named_header {
         crate::NamedHeader {
             display_name: None,
             uri: $u,
-            params: ::std::collections::HashMap::new(),
+            params: ::std::vec::Vec::new(),
         }
     };
 }
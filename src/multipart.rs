@@ -0,0 +1,139 @@
+//! Multipart message bodies (RFC 2046), as used to carry SDP
+//! alongside other payloads such as ISUP or XML.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    error::{ErrorKind, ParseError}
+};
+
+use crate::{
+    core::message::parse_headers,
+    headers::{ContentDisposition, Header, Headers, MediaType},
+};
+
+/// Single part of a multipart body.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BodyPart {
+    pub headers: Headers,
+    pub body: Vec<u8>,
+}
+
+impl BodyPart {
+    /// Create a part holding `body` with no headers.
+    pub fn new(body: Vec<u8>) -> BodyPart {
+        BodyPart { headers: Headers::new(), body }
+    }
+
+    /// Add a header to this part.
+    pub fn with_header(mut self, header: Header) -> BodyPart {
+        self.headers.push(header);
+        self
+    }
+
+    /// The Content-Type of this part if one is present.
+    pub fn content_type(&self) -> Option<&MediaType> {
+        self.headers.iter().find_map(|h| match h {
+            Header::ContentType(ty) => Some(ty),
+            _ => None,
+        })
+    }
+
+    /// The Content-Disposition of this part if one is present.
+    pub fn content_disposition(&self) -> Option<&ContentDisposition> {
+        self.headers.iter().find_map(|h| match h {
+            Header::ContentDisposition(disposition) => Some(disposition),
+            _ => None,
+        })
+    }
+}
+
+/// Body of a message whose Content-Type is `multipart/*`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultipartBody {
+    pub boundary: String,
+    pub parts: Vec<BodyPart>,
+}
+
+impl MultipartBody {
+    /// Create an empty body delimited by `boundary`.
+    pub fn new<S: Into<String>>(boundary: S) -> MultipartBody {
+        MultipartBody { boundary: boundary.into(), parts: vec![] }
+    }
+
+    /// Add a part to the body.
+    pub fn with_part(mut self, part: BodyPart) -> MultipartBody {
+        self.parts.push(part);
+        self
+    }
+
+    /// Render the body, each part is preceded by a boundary
+    /// delimiter and the last one is followed by `--boundary--`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = vec![];
+        for part in &self.parts {
+            out.extend_from_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            for header in part.headers.iter() {
+                out.extend_from_slice(format!("{}\r\n", header).as_bytes());
+            }
+            out.extend_from_slice(b"\r\n");
+            out.extend_from_slice(&part.body);
+            out.extend_from_slice(b"\r\n");
+        }
+        out.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        out
+    }
+}
+
+fn find(input: &[u8], needle: &[u8]) -> Option<usize> {
+    input.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Parse a multipart body delimited by `boundary`, the value of the
+/// Content-Type `boundary` parameter. Any preamble before the first
+/// delimiter is skipped, the input following the closing delimiter
+/// is returned as the remainder.
+pub fn parse_multipart<'a, E: ParseError<&'a [u8]>>(input: &'a [u8], boundary: &str) -> IResult<&'a [u8], MultipartBody, E> {
+    let delimiter = format!("\r\n--{}", boundary).into_bytes();
+    let mut rest = if input.starts_with(&delimiter[2..]) {
+        &input[delimiter.len() - 2..]
+    } else {
+        let start = find(input, &delimiter).ok_or_else(|| nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify)))?;
+        &input[start + delimiter.len()..]
+    };
+    let mut body = MultipartBody::new(boundary);
+    loop {
+        if rest.starts_with(b"--") {
+            rest = &rest[2..];
+            if rest.starts_with(b"\r\n") {
+                rest = &rest[2..];
+            }
+            return Ok((rest, body));
+        }
+        while let Some(b' ') | Some(b'\t') = rest.first() {
+            rest = &rest[1..];
+        }
+        if !rest.starts_with(b"\r\n") {
+            return Err(nom::Err::Error(E::from_error_kind(rest, ErrorKind::CrLf)));
+        }
+        let content = &rest[2..];
+        let end = find(content, &delimiter).ok_or_else(|| nom::Err::Error(E::from_error_kind(content, ErrorKind::Eof)))?;
+        let (data, headers) = if content.starts_with(b"\r\n") {
+            (&content[..end], Headers::new())
+        } else {
+            parse_headers::<E>(&content[..end])?
+        };
+        let payload = if data.starts_with(b"\r\n") {
+            &data[2..]
+        } else if data.is_empty() {
+            data
+        } else {
+            return Err(nom::Err::Error(E::from_error_kind(data, ErrorKind::CrLf)));
+        };
+        body.parts.push(BodyPart { headers, body: payload.to_vec() });
+        rest = &content[end + delimiter.len()..];
+    }
+}
@@ -8,10 +8,11 @@ use nom::{
     bytes::{
         complete::{
             take_while,
-            take_until
+            take_while1
         }
     },
-    combinator::map_res,
+    combinator::{map_res, opt, recognize},
+    sequence::pair,
     error::ParseError,
     error::ErrorKind
 };
@@ -21,7 +22,7 @@ use std::{
         Error as IoError,
         ErrorKind as IoErrorKind
     },
-    net::Ipv4Addr,
+    net::{Ipv4Addr, Ipv6Addr},
 };
 
 /// Parse input as a string using `String::from_utf8`.
@@ -39,6 +40,79 @@ pub fn slice_to_string_nullable(slice: &[u8]) -> Result<String, IoError> {
         .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse utf8 string"))?)
 }
 
+/// Decode the percent encoded octets of a uri component. A `%` that
+/// is not followed by two hex digits results in a `nom::Err::Failure`
+/// so that the surrounding parsers do not silently backtrack.
+pub fn percent_decode<'a, E: ParseError<&'a [u8]>>(slice: &'a [u8]) -> Result<String, nom::Err<E>> {
+    let mut out = Vec::with_capacity(slice.len());
+    let mut index = 0;
+    while index < slice.len() {
+        if slice[index] == b'%' {
+            let escape = slice.get(index + 1..index + 3)
+                .and_then(|hex| ::std::str::from_utf8(hex).ok())
+                .filter(|hex| hex.bytes().all(is_hex_digit))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            match escape {
+                Some(byte) => out.push(byte),
+                None => return Err(nom::Err::Failure(E::from_error_kind(&slice[index..], ErrorKind::Escaped))),
+            }
+            index += 3;
+        } else {
+            out.push(slice[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(out).map_err(|_| nom::Err::Failure(E::from_error_kind(slice, ErrorKind::IsNot)))
+}
+
+/// Percent encode every byte of `value` that is not allowed by `allowed`.
+pub fn percent_encode(value: &str, allowed: fn(u8) -> bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if allowed(byte) && byte != b'%' {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+/// Characters allowed in a SIP `token`.
+pub fn is_token_char(item: u8) -> bool {
+    is_alphanumeric(item) || b"-.!%*_+`'~".contains(&item)
+}
+
+/// Characters allowed in a SIP `word`, as used by the Call-ID header.
+pub fn is_word_char(item: u8) -> bool {
+    is_token_char(item) || b"()<>:\\\"/[]?{}".contains(&item)
+}
+
+/// Uri `unreserved` characters.
+pub fn is_unreserved(item: u8) -> bool {
+    is_alphanumeric(item) || b"-_.!~*'()".contains(&item)
+}
+
+/// Characters allowed unescaped in the user part of a uri.
+pub fn is_user_char(item: u8) -> bool {
+    is_unreserved(item) || b"&=+$,;?/".contains(&item)
+}
+
+/// Characters allowed unescaped in the password part of a uri.
+pub fn is_password_char(item: u8) -> bool {
+    is_unreserved(item) || b"&=+$,".contains(&item)
+}
+
+/// Characters allowed unescaped in uri parameter names and values.
+pub fn is_param_char(item: u8) -> bool {
+    is_unreserved(item) || b"[]/:&+$".contains(&item)
+}
+
+/// Characters allowed unescaped in uri header names and values.
+pub fn is_header_char(item: u8) -> bool {
+    is_unreserved(item) || b"[]/?:+$".contains(&item)
+}
+
 /// Parse unsigned 16 bit integer using `Parse::parse`.
 pub fn parse_u16<'a, E: ParseError<&'a [u8]>>(slice: &'a [u8]) -> Result<u16, E> {
     Ok(::std::str::from_utf8(slice)
@@ -75,12 +149,25 @@ pub fn parse_u32(slice: &[u8]) -> Result<u32, IoError> {
         .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse u32 integer"))?)
 }
 
-/// Parse input as an f32 using `Parse::parse`.
-pub fn parse_f32(slice: &[u8]) -> Result<f32, IoError> {
-    Ok(::std::str::from_utf8(slice)
-        .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse utf8 f32"))?
+/// Parse unsigned 64 bit integer using `Parse::parse`.
+pub fn parse_u64(slice: &[u8]) -> Result<u64, IoError> {
+    ::std::str::from_utf8(slice)
+        .map_err(|_| {
+            IoError::new(
+                IoErrorKind::InvalidInput,
+                "Failed to parse utf8 u64 integer",
+            )
+        })?
         .parse()
-        .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse f32"))?)
+        .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse u64 integer"))
+}
+
+/// Parse input as an f64 using `Parse::parse`.
+pub fn parse_f64(slice: &[u8]) -> Result<f64, IoError> {
+    ::std::str::from_utf8(slice)
+        .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse utf8 f64"))?
+        .parse()
+        .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse f64"))
 }
 
 /// Parse Input as a vector of bytes.
@@ -99,10 +186,37 @@ pub fn parse_ip_address<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult
   Ok((input, Ipv4Addr::new(byte1, byte2, byte3, byte4)))
 }
 
+/// Parse an IPv6 reference, the address enclosed in '[' & ']'.
+pub fn parse_ipv6_reference<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Ipv6Addr, E> {
+    let (input, _) = parse_char('[')(input)?;
+    let (input, addr) = map_res(take_while1(|item| is_hex_digit(item) || item == b':' || item == b'.'), parse_ipv6)(input)?;
+    let (input, _) = parse_char(']')(input)?;
+    Ok((input, addr))
+}
+
+/// Parse input as an IPv6 address using `Parse::parse`.
+pub fn parse_ipv6(slice: &[u8]) -> Result<Ipv6Addr, IoError> {
+    ::std::str::from_utf8(slice)
+        .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse utf8 IPv6 address"))?
+        .parse()
+        .map_err(|_| IoError::new(IoErrorKind::InvalidInput, "Failed to parse IPv6 address"))
+}
+
 pub fn parse_string<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
     map_res(take_while(is_alphanumeric), slice_to_string::<E>)(input)
 }
 
+/// Parse a SIP `token`, e.g. an option tag such as `100rel`.
+pub fn parse_token<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
+    map_res(take_while1(is_token_char), slice_to_string::<E>)(input)
+}
+
+/// Parse a Call-ID value, `word [ "@" word ]`.
+pub fn parse_call_id<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
+    let (input, value) = recognize(pair(take_while1(is_word_char), opt(pair(parse_char('@'), take_while1(is_word_char)))))(input)?;
+    Ok((input, slice_to_string::<E>(value).map_err(nom::Err::Error)?))
+}
+
 pub fn parse_possibly_quoted_string<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
     alt::<_, _, E, _>((
         parse_string::<E>,
@@ -110,9 +224,26 @@ pub fn parse_possibly_quoted_string<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]
     ))(input)
 }
 
+/// Parse a quoted string. Backslash escapes such as `\"` and `\\`
+/// are replaced by the character they escape.
 pub fn parse_quoted_string<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
-    let (input, _) = parse_char('\"')(input)?;
-    let (input, out) = map_res(take_until("\""), slice_to_string_nullable)(input)?;
-    let (input, _) = parse_char('\"')(input)?;
-    Ok((input, out))
+    let (mut input, _) = parse_char('\"')(input)?;
+    let mut out = vec![];
+    loop {
+        match input {
+            [b'"', rest @ ..] => {
+                let out = String::from_utf8(out).map_err(|_| nom::Err::Error(E::from_error_kind(input, ErrorKind::IsNot)))?;
+                return Ok((rest, out));
+            },
+            [b'\\', c, rest @ ..] if *c != b'\r' && *c != b'\n' => {
+                out.push(*c);
+                input = rest;
+            },
+            [c, rest @ ..] => {
+                out.push(*c);
+                input = rest;
+            },
+            [] => return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::TakeUntil))),
+        }
+    }
 }
\ No newline at end of file
@@ -99,3 +99,142 @@ impl RequestGenerator {
         }
     }
 }
+
+/// Sip Request Builder which checks that the headers
+/// every request must carry are present. The mandatory
+/// headers are written first in the order recommended
+/// by RFC 3261 and Content-Length is set from the body.
+/// Max-Forwards defaults to 70 when it is not set.
+pub struct RequestBuilder {
+    method: Method,
+    uri: Uri,
+    version: Version,
+    vias: Vec<ViaHeader>,
+    max_forwards: Option<u32>,
+    to: Option<NamedHeader>,
+    from: Option<NamedHeader>,
+    call_id: Option<String>,
+    cseq: Option<u32>,
+    headers: Headers,
+    body: Vec<u8>,
+}
+
+impl RequestBuilder {
+    /// Create a new instance for `method` sent to `uri`.
+    pub fn new(method: Method, uri: Uri) -> RequestBuilder {
+        RequestBuilder {
+            method,
+            uri,
+            version: Version::default(),
+            vias: vec![],
+            max_forwards: None,
+            to: None,
+            from: None,
+            call_id: None,
+            cseq: None,
+            headers: Headers::new(),
+            body: vec![],
+        }
+    }
+
+    /// Set the From header.
+    pub fn from(mut self, from: NamedHeader) -> RequestBuilder {
+        self.from = Some(from);
+        self
+    }
+
+    /// Set the To header.
+    pub fn to(mut self, to: NamedHeader) -> RequestBuilder {
+        self.to = Some(to);
+        self
+    }
+
+    /// Set the Call-ID header.
+    pub fn call_id<S: Into<String>>(mut self, call_id: S) -> RequestBuilder {
+        self.call_id = Some(call_id.into());
+        self
+    }
+
+    /// Set the CSeq sequence number, the CSeq
+    /// method is always the request method.
+    pub fn cseq(mut self, cseq: u32) -> RequestBuilder {
+        self.cseq = Some(cseq);
+        self
+    }
+
+    /// Add a Via header below any that are already present.
+    pub fn via(mut self, via: ViaHeader) -> RequestBuilder {
+        self.vias.push(via);
+        self
+    }
+
+    /// Set the Max-Forwards header, replacing the default of 70.
+    pub fn max_forwards(mut self, max_forwards: u8) -> RequestBuilder {
+        self.max_forwards = Some(max_forwards.into());
+        self
+    }
+
+    /// Add a header. Mandatory headers replace the values
+    /// set by their dedicated methods, Content-Length is
+    /// ignored as it is always derived from the body.
+    pub fn header(mut self, header: Header) -> RequestBuilder {
+        match header {
+            Header::Via(via) => self.vias.push(via),
+            Header::MaxForwards(max_forwards) => self.max_forwards = Some(max_forwards),
+            Header::To(to) => self.to = Some(to),
+            Header::From(from) => self.from = Some(from),
+            Header::CallId(call_id) => self.call_id = Some(call_id),
+            Header::CSeq(cseq, _) => self.cseq = Some(cseq),
+            Header::ContentLength(_) => {},
+            header => self.headers.push(header),
+        }
+        self
+    }
+
+    /// Set the sip request body. This completely replaces
+    /// the current request body.
+    pub fn body(mut self, body: Vec<u8>) -> RequestBuilder {
+        self.body = body;
+        self
+    }
+
+    /// Build the sip request, failing with the name of
+    /// the first mandatory header that is missing.
+    pub fn build(self) -> IoResult<SipMessage> {
+        if self.vias.is_empty() {
+            return Err(missing_header("Via"));
+        }
+        let max_forwards = self.max_forwards.unwrap_or_else(|| DEFAULT_MAX_FORWARDS.into());
+        let to = self.to.ok_or_else(|| missing_header("To"))?;
+        let from = self.from.ok_or_else(|| missing_header("From"))?;
+        let call_id = self.call_id.ok_or_else(|| missing_header("Call-ID"))?;
+        let cseq = self.cseq.ok_or_else(|| missing_header("CSeq"))?;
+
+        let mut headers = Headers::new();
+        headers.extend(self.vias.into_iter().map(Header::Via).collect());
+        headers.push(Header::MaxForwards(max_forwards));
+        headers.push(Header::To(to));
+        headers.push(Header::From(from));
+        headers.push(Header::CallId(call_id));
+        headers.push(Header::CSeq(cseq, self.method.clone()));
+        headers.extend(self.headers.0);
+        headers.push(Header::ContentLength(self.body.len() as u32));
+        Ok(SipMessage::Request {
+            method: self.method,
+            uri: self.uri,
+            version: self.version,
+            headers,
+            body: self.body,
+        })
+    }
+}
+
+/// Max-Forwards value recommended by RFC 3261 section 8.1.1.6.
+pub(crate) const DEFAULT_MAX_FORWARDS: u8 = 70;
+
+fn missing_header(name: &str) -> IoError {
+    IoError::new(
+        IoErrorKind::InvalidInput,
+        format!("RequestBuilder requires a {} header", name),
+    )
+}
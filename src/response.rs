@@ -13,6 +13,7 @@ use std::io::{
 #[derive(Default)]
 pub struct ResponseGenerator {
     code: Option<u32>,
+    reason: Option<String>,
     version: Version,
     headers: Headers,
     body: Vec<u8>,
@@ -24,6 +25,7 @@ impl ResponseGenerator {
     pub fn new() -> ResponseGenerator {
         ResponseGenerator {
             code: None,
+            reason: None,
             version: Version::default(),
             headers: Headers::new(),
             body: vec![],
@@ -36,6 +38,13 @@ impl ResponseGenerator {
         self
     }
 
+    /// Set the reason phrase. When not set the canonical
+    /// reason phrase of the status code is used.
+    pub fn reason<S: Into<String>>(mut self, reason: S) -> ResponseGenerator {
+        self.reason = Some(reason.into());
+        self
+    }
+
     /// Add multiple headers to the response header list.
     /// This use's Vec::extend so that the current items
     /// in the header list are kept.
@@ -70,9 +79,16 @@ impl ResponseGenerator {
     /// Create the Sip response.
     pub fn build(self) -> IoResult<SipMessage> {
         if let Some(code) = self.code {
+            let reason = self.reason.unwrap_or_else(|| {
+                StatusCode::from_u16(code as u16)
+                    .canonical_reason()
+                    .unwrap_or_default()
+                    .to_string()
+            });
             let res = SipMessage::Response {
                 code,
                 version: self.version,
+                reason,
                 headers: self.headers,
                 body: self.body,
             };
@@ -0,0 +1,70 @@
+use crate::*;
+
+use std::io::{
+    Error as IoError,
+    ErrorKind as IoErrorKind,
+    Result as IoResult
+};
+
+/// Where a proxy should send a request, as decided by `route_request`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RouteDecision {
+    /// There is no Route header, the request is sent
+    /// towards its Request-URI.
+    RequestUri(Uri),
+    /// The top Route is a loose router (`;lr`). The request is
+    /// sent to it unchanged, that hop removes its own Route value.
+    LooseRoute(Uri),
+    /// The top Route is a strict router. It was moved into the
+    /// Request-URI and the old Request-URI appended to the Route
+    /// headers, the request is sent to the new Request-URI.
+    StrictRoute(Uri),
+}
+
+impl RouteDecision {
+    /// The uri the request should be forwarded to.
+    pub fn next_hop(&self) -> &Uri {
+        match self {
+            RouteDecision::RequestUri(uri) => uri,
+            RouteDecision::LooseRoute(uri) => uri,
+            RouteDecision::StrictRoute(uri) => uri,
+        }
+    }
+}
+
+/// Choose the next hop of a request following RFC 3261 section 16.6
+/// steps 6 and 7. The Route headers are only rewritten when the top
+/// Route is a strict router. Routes addressing the proxy itself must
+/// already have been removed as described in section 16.4.
+pub fn route_request(message: &mut SipMessage) -> IoResult<RouteDecision> {
+    let (uri, headers) = match message {
+        SipMessage::Request { uri, headers, .. } => (uri, headers),
+        SipMessage::Response { .. } => {
+            return Err(IoError::new(IoErrorKind::InvalidInput, "Only requests can be routed"))
+        },
+    };
+    let first = match headers.routes().first() {
+        Some(first) => first.uri.clone(),
+        None => return Ok(RouteDecision::RequestUri(uri.clone())),
+    };
+    if first.lr() {
+        return Ok(RouteDecision::LooseRoute(first));
+    }
+    if let Some(index) = headers.0.iter().position(|h| matches!(h, Header::Route(routes) if !routes.is_empty())) {
+        if let Header::Route(routes) = &mut headers.0[index] {
+            routes.remove(0);
+            if routes.is_empty() {
+                headers.0.remove(index);
+            }
+        }
+    }
+    let request_uri = NamedHeader::new(std::mem::replace(uri, first.strip_for_request_uri()));
+    match headers.0.iter_mut().rev().find_map(|h| match h {
+        Header::Route(routes) => Some(routes),
+        _ => None
+    }) {
+        Some(routes) => routes.push(request_uri),
+        None => headers.push(Header::Route(vec![request_uri])),
+    }
+    Ok(RouteDecision::StrictRoute(uri.clone()))
+}
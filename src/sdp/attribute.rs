@@ -0,0 +1,108 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::{take_while, take_while1},
+    character::complete::char,
+    combinator::{map_res, opt},
+    error::ParseError
+};
+
+use std::fmt;
+
+use crate::parse::{slice_to_string, slice_to_string_nullable};
+
+/// SDP `a=` line, either a property `a=name` or a value `a=name:value`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Attribute {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+impl Attribute {
+    /// Create a property attribute without a value, e.g. `sendrecv`.
+    pub fn property<S: Into<String>>(name: S) -> Attribute {
+        Attribute { name: name.into(), value: None }
+    }
+
+    /// Create a value attribute, e.g. `ptime:20`.
+    pub fn value<N: Into<String>, V: Into<String>>(name: N, value: V) -> Attribute {
+        Attribute { name: name.into(), value: Some(value.into()) }
+    }
+
+    /// Interpret this attribute as an `rtpmap`.
+    pub fn rtpmap(&self) -> Option<RtpMap> {
+        if self.name != "rtpmap" {
+            return None;
+        }
+        let value = self.value.as_ref()?;
+        let mut parts = value.splitn(2, ' ');
+        let payload_type = parts.next()?.parse().ok()?;
+        let mut encoding = parts.next()?.trim().splitn(3, '/');
+        Some(RtpMap {
+            payload_type,
+            encoding_name: encoding.next()?.into(),
+            clock_rate: encoding.next()?.parse().ok()?,
+            encoding_params: encoding.next().map(Into::into),
+        })
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}:{}", self.name, value),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// Payload type mapping carried by an `a=rtpmap` attribute,
+/// e.g. `rtpmap:97 opus/48000/2`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RtpMap {
+    pub payload_type: u8,
+    pub encoding_name: String,
+    pub clock_rate: u32,
+    pub encoding_params: Option<String>,
+}
+
+impl RtpMap {
+    pub fn new<S: Into<String>>(payload_type: u8, encoding_name: S, clock_rate: u32) -> RtpMap {
+        RtpMap { payload_type, encoding_name: encoding_name.into(), clock_rate, encoding_params: None }
+    }
+
+    /// Set the encoding parameters, the channel count for audio.
+    pub fn params<S: Into<String>>(mut self, params: S) -> RtpMap {
+        self.encoding_params = Some(params.into());
+        self
+    }
+}
+
+impl fmt::Display for RtpMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}/{}", self.payload_type, self.encoding_name, self.clock_rate)?;
+        if let Some(params) = &self.encoding_params {
+            write!(f, "/{}", params)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<RtpMap> for Attribute {
+    fn from(map: RtpMap) -> Attribute {
+        Attribute::value("rtpmap", map.to_string())
+    }
+}
+
+/// Parse the value of an `a=` line.
+pub fn parse_attribute<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Attribute, E> {
+    let (input, name) = map_res(take_while1(|c| c != b':'), slice_to_string::<E>)(input)?;
+    let (input, value) = opt(|input: &'a [u8]| {
+        let (input, _) = char(':')(input)?;
+        map_res(take_while(|_| true), slice_to_string_nullable)(input)
+    })(input)?;
+    Ok((input, Attribute { name, value }))
+}
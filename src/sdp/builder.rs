@@ -0,0 +1,93 @@
+use std::io::{
+    Error as IoError,
+    ErrorKind as IoErrorKind,
+    Result as IoResult
+};
+
+use super::{Attribute, Connection, MediaDescription, Origin, SessionDescription, Timing};
+
+/// SDP session description builder. When build is called the
+/// struct is consumed and produces a `SessionDescription`.
+/// Calling the `origin` method before `build` is required.
+/// The session name defaults to `-` and the timing to `t=0 0`.
+#[derive(Default)]
+pub struct SessionDescriptionBuilder {
+    origin: Option<Origin>,
+    session_name: Option<String>,
+    connection: Option<Connection>,
+    timing: Vec<Timing>,
+    attributes: Vec<Attribute>,
+    media: Vec<MediaDescription>,
+}
+
+impl SessionDescriptionBuilder {
+    /// Create a new instance.
+    pub fn new() -> SessionDescriptionBuilder {
+        SessionDescriptionBuilder::default()
+    }
+
+    /// Set the origin of the session.
+    pub fn origin(mut self, origin: Origin) -> SessionDescriptionBuilder {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Set the session name.
+    pub fn session_name<S: Into<String>>(mut self, name: S) -> SessionDescriptionBuilder {
+        self.session_name = Some(name.into());
+        self
+    }
+
+    /// Set the session level connection.
+    pub fn connection(mut self, connection: Connection) -> SessionDescriptionBuilder {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Add a `t=` line.
+    pub fn timing(mut self, timing: Timing) -> SessionDescriptionBuilder {
+        self.timing.push(timing);
+        self
+    }
+
+    /// Add a session level attribute.
+    pub fn attribute(mut self, attribute: Attribute) -> SessionDescriptionBuilder {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Add a media description.
+    pub fn media(mut self, media: MediaDescription) -> SessionDescriptionBuilder {
+        self.media.push(media);
+        self
+    }
+
+    /// Build the session description. Every media description needs
+    /// a connection unless a session level connection was set.
+    pub fn build(self) -> IoResult<SessionDescription> {
+        let origin = self.origin.ok_or_else(|| {
+            IoError::new(IoErrorKind::InvalidInput, "`origin` method call required")
+        })?;
+        if self.connection.is_none() && self.media.iter().any(|media| media.connection.is_none()) {
+            return Err(IoError::new(
+                IoErrorKind::InvalidInput,
+                "`connection` is required at session level or in every media description",
+            ));
+        }
+        let timing = if self.timing.is_empty() {
+            vec![Timing::default()]
+        } else {
+            self.timing
+        };
+        Ok(SessionDescription {
+            version: 0,
+            origin,
+            session_name: self.session_name.unwrap_or_else(|| "-".into()),
+            connection: self.connection,
+            timing,
+            attributes: self.attributes,
+            media: self.media,
+            other: vec![],
+        })
+    }
+}
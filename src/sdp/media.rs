@@ -0,0 +1,149 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::take_while1,
+    character::{complete::char, is_digit},
+    combinator::{map_res, opt},
+    error::ParseError,
+    multi::many0,
+    sequence::preceded
+};
+
+use std::{
+    fmt,
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+};
+
+use crate::parse::parse_u32;
+
+use super::{
+    attribute::{Attribute, RtpMap},
+    origin::{parse_sdp_field, Connection},
+};
+
+/// SDP media description, an `m=` line together with
+/// the lines that follow it up to the next `m=` line.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MediaDescription {
+    pub media: String,
+    pub port: u16,
+    pub port_count: Option<u16>,
+    pub protocol: String,
+    pub formats: Vec<String>,
+    pub connection: Option<Connection>,
+    pub attributes: Vec<Attribute>,
+    /// Lines without a dedicated field, e.g. `i=` or `b=`.
+    pub other: Vec<(char, String)>,
+}
+
+impl MediaDescription {
+    /// Create a new media description without any formats.
+    pub fn new<M: Into<String>, P: Into<String>>(media: M, port: u16, protocol: P) -> MediaDescription {
+        MediaDescription {
+            media: media.into(),
+            port,
+            port_count: None,
+            protocol: protocol.into(),
+            formats: vec![],
+            connection: None,
+            attributes: vec![],
+            other: vec![],
+        }
+    }
+
+    /// Add a payload format to the `m=` line.
+    pub fn with_format<S: Into<String>>(mut self, format: S) -> MediaDescription {
+        self.formats.push(format.into());
+        self
+    }
+
+    /// Add an `rtpmap` attribute, also adding its payload
+    /// type to the `m=` line formats when it is missing.
+    pub fn with_rtpmap(mut self, map: RtpMap) -> MediaDescription {
+        let payload_type = map.payload_type.to_string();
+        if !self.formats.contains(&payload_type) {
+            self.formats.push(payload_type);
+        }
+        self.attributes.push(map.into());
+        self
+    }
+
+    /// Add an attribute.
+    pub fn with_attribute(mut self, attribute: Attribute) -> MediaDescription {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Set the media level connection.
+    pub fn with_connection(mut self, connection: Connection) -> MediaDescription {
+        self.connection = Some(connection);
+        self
+    }
+
+    /// Return the first attribute called `name`.
+    pub fn attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.name == name)
+    }
+
+    /// Return every `rtpmap` attribute of this media description.
+    pub fn rtpmaps(&self) -> Vec<RtpMap> {
+        self.attributes.iter().filter_map(Attribute::rtpmap).collect()
+    }
+}
+
+impl fmt::Display for MediaDescription {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "m={} {}", self.media, self.port)?;
+        if let Some(count) = self.port_count {
+            write!(f, "/{}", count)?;
+        }
+        write!(f, " {}", self.protocol)?;
+        for format in &self.formats {
+            write!(f, " {}", format)?;
+        }
+        write!(f, "\r\n")?;
+        super::write_other(f, &self.other, |ty| ty == 'i')?;
+        if let Some(connection) = &self.connection {
+            write!(f, "c={}\r\n", connection)?;
+        }
+        super::write_other(f, &self.other, |ty| ty != 'i')?;
+        for attribute in &self.attributes {
+            write!(f, "a={}\r\n", attribute)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_port<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], u16, E> {
+    map_res(take_while1(is_digit), |slice| {
+        let port = parse_u32(slice)?;
+        if port > u32::from(u16::MAX) {
+            Err(IoError::new(IoErrorKind::InvalidInput, "Port number out of range"))
+        } else {
+            Ok(port as u16)
+        }
+    })(input)
+}
+
+/// Parse the value of an `m=` line.
+pub fn parse_media<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], MediaDescription, E> {
+    let (input, media) = parse_sdp_field(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, port) = parse_port(input)?;
+    let (input, port_count) = opt(preceded(char('/'), parse_port))(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, protocol) = parse_sdp_field(input)?;
+    let (input, formats) = many0(preceded(char(' '), parse_sdp_field))(input)?;
+    Ok((input, MediaDescription {
+        media,
+        port,
+        port_count,
+        protocol,
+        formats,
+        connection: None,
+        attributes: vec![],
+        other: vec![],
+    }))
+}
@@ -0,0 +1,176 @@
+//! Session Description Protocol, as carried in the body of
+//! INVITE requests and their responses (RFC 4566).
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{tag, take_till, take_while1, take_while_m_n},
+    character::{complete::char, is_alphabetic, is_digit},
+    combinator::{all_consuming, map_res, opt},
+    error::{ErrorKind, ParseError}
+};
+
+use std::fmt;
+
+use crate::parse::{parse_u32, slice_to_string_nullable};
+
+pub mod origin;
+pub use self::origin::{parse_connection, parse_origin, parse_timing, Connection, Origin, Timing};
+
+pub mod attribute;
+pub use self::attribute::{parse_attribute, Attribute, RtpMap};
+
+pub mod media;
+pub use self::media::{parse_media, MediaDescription};
+
+pub mod builder;
+pub use self::builder::SessionDescriptionBuilder;
+
+/// Parsed SDP session description.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionDescription {
+    pub version: u32,
+    pub origin: Origin,
+    pub session_name: String,
+    pub connection: Option<Connection>,
+    pub timing: Vec<Timing>,
+    pub attributes: Vec<Attribute>,
+    pub media: Vec<MediaDescription>,
+    /// Session level lines without a dedicated field, e.g. `i=` or `b=`.
+    pub other: Vec<(char, String)>,
+}
+
+impl SessionDescription {
+    /// Render the session description for use as a message body.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+
+    /// Return the first session level attribute called `name`.
+    pub fn attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.name == name)
+    }
+
+    /// The connection to use for `media`, the media level `c=`
+    /// line takes precedence over the session level one.
+    pub fn connection_for<'a>(&'a self, media: &'a MediaDescription) -> Option<&'a Connection> {
+        media.connection.as_ref().or(self.connection.as_ref())
+    }
+}
+
+impl fmt::Display for SessionDescription {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v={}\r\n", self.version)?;
+        write!(f, "o={}\r\n", self.origin)?;
+        write!(f, "s={}\r\n", self.session_name)?;
+        write_other(f, &self.other, |ty| "iuep".contains(ty))?;
+        if let Some(connection) = &self.connection {
+            write!(f, "c={}\r\n", connection)?;
+        }
+        write_other(f, &self.other, |ty| ty == 'b')?;
+        for timing in &self.timing {
+            write!(f, "t={}\r\n", timing)?;
+        }
+        write_other(f, &self.other, |ty| !"iuepb".contains(ty))?;
+        for attribute in &self.attributes {
+            write!(f, "a={}\r\n", attribute)?;
+        }
+        for media in &self.media {
+            write!(f, "{}", media)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the lines in `other` whose type matches `filter`.
+fn write_other<F: Fn(char) -> bool>(f: &mut fmt::Formatter, other: &[(char, String)], filter: F) -> fmt::Result {
+    for (ty, value) in other.iter().filter(|(ty, _)| filter(*ty)) {
+        write!(f, "{}={}\r\n", ty, value)?;
+    }
+    Ok(())
+}
+
+/// Parse a single `<type>=<value>` line, the line ending
+/// may be CRLF or a bare LF and is optional on the last line.
+pub fn parse_sdp_line<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (char, &'a [u8]), E> {
+    let (input, ty) = take_while_m_n(1, 1, is_alphabetic)(input)?;
+    let (input, _) = char('=')(input)?;
+    let (input, value) = take_till(|c| c == b'\r' || c == b'\n')(input)?;
+    let (input, _) = opt(alt((tag("\r\n"), tag("\n"))))(input)?;
+    Ok((input, (ty[0] as char, value)))
+}
+
+fn parse_typed_line<'a, E: ParseError<&'a [u8]>>(input: &'a [u8], expected: char) -> IResult<&'a [u8], &'a [u8], E> {
+    let (rest, (ty, value)) = parse_sdp_line(input)?;
+    if ty == expected {
+        Ok((rest, value))
+    } else {
+        Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify)))
+    }
+}
+
+/// Parse an SDP session description. Parsing stops at the first
+/// line that is not an SDP line or that starts a new session.
+pub fn parse_sdp<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], SessionDescription, E> {
+    let (input, value) = parse_typed_line(input, 'v')?;
+    let (_, version) = all_consuming(map_res(take_while1(is_digit), parse_u32))(value)?;
+    let (input, value) = parse_typed_line(input, 'o')?;
+    let (_, origin) = all_consuming(parse_origin)(value)?;
+    let (mut input, value) = parse_typed_line(input, 's')?;
+    let session_name = slice_to_string_nullable(value)
+        .map_err(|_| nom::Err::Error(E::from_error_kind(value, ErrorKind::Verify)))?;
+
+    let mut session = SessionDescription {
+        version,
+        origin,
+        session_name,
+        connection: None,
+        timing: vec![],
+        attributes: vec![],
+        media: vec![],
+        other: vec![],
+    };
+    while let Ok((rest, (ty, value))) = parse_sdp_line::<E>(input) {
+        match ty {
+            'v' | 'o' | 's' => break,
+            'm' => {
+                let (_, media) = all_consuming(parse_media)(value)?;
+                session.media.push(media);
+            },
+            'c' => {
+                let (_, connection) = all_consuming(parse_connection)(value)?;
+                match session.media.last_mut() {
+                    Some(media) => media.connection = Some(connection),
+                    None => session.connection = Some(connection),
+                }
+            },
+            'a' => {
+                let (_, attribute) = all_consuming(parse_attribute)(value)?;
+                match session.media.last_mut() {
+                    Some(media) => media.attributes.push(attribute),
+                    None => session.attributes.push(attribute),
+                }
+            },
+            't' if session.media.is_empty() => {
+                let (_, timing) = all_consuming(parse_timing)(value)?;
+                session.timing.push(timing);
+            },
+            _ => {
+                let value = slice_to_string_nullable(value)
+                    .map_err(|_| nom::Err::Error(E::from_error_kind(value, ErrorKind::Verify)))?;
+                match session.media.last_mut() {
+                    Some(media) => media.other.push((ty, value)),
+                    None => session.other.push((ty, value)),
+                }
+            },
+        }
+        input = rest;
+    }
+    if session.timing.is_empty() {
+        return Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Verify)));
+    }
+    Ok((input, session))
+}
@@ -0,0 +1,134 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use nom::{
+    IResult,
+    bytes::complete::take_while1,
+    character::{complete::char, is_digit},
+    combinator::map_res,
+    error::ParseError
+};
+
+use std::fmt;
+
+use crate::parse::{parse_u64, slice_to_string};
+
+/// SDP `o=` line, identifying the originator of the session.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Origin {
+    pub username: String,
+    pub session_id: u64,
+    pub session_version: u64,
+    pub network_type: String,
+    pub address_type: String,
+    pub address: String,
+}
+
+impl Origin {
+    /// Create a new `IN IP4` origin for `address`.
+    pub fn new<U: Into<String>, A: Into<String>>(username: U, session_id: u64, session_version: u64, address: A) -> Origin {
+        Origin {
+            username: username.into(),
+            session_id,
+            session_version,
+            network_type: "IN".into(),
+            address_type: "IP4".into(),
+            address: address.into(),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.username, self.session_id, self.session_version,
+            self.network_type, self.address_type, self.address
+        )
+    }
+}
+
+/// SDP `c=` line, the address media should be sent to.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Connection {
+    pub network_type: String,
+    pub address_type: String,
+    pub address: String,
+}
+
+impl Connection {
+    /// Create a new `IN IP4` connection for `address`.
+    pub fn new<A: Into<String>>(address: A) -> Connection {
+        Connection {
+            network_type: "IN".into(),
+            address_type: "IP4".into(),
+            address: address.into(),
+        }
+    }
+
+    /// The connection address without any multicast ttl or address count.
+    pub fn host(&self) -> &str {
+        self.address.split('/').next().unwrap_or("")
+    }
+}
+
+impl fmt::Display for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.network_type, self.address_type, self.address)
+    }
+}
+
+/// SDP `t=` line, the start and stop times of the session.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Timing {
+    pub start: u64,
+    pub stop: u64,
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.start, self.stop)
+    }
+}
+
+/// Parse a single space separated SDP field.
+pub fn parse_sdp_field<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
+    map_res(take_while1(|c| c != b' '), slice_to_string::<E>)(input)
+}
+
+fn parse_sdp_u64<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], u64, E> {
+    map_res(take_while1(is_digit), parse_u64)(input)
+}
+
+/// Parse the value of an `o=` line.
+pub fn parse_origin<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Origin, E> {
+    let (input, username) = parse_sdp_field(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, session_id) = parse_sdp_u64(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, session_version) = parse_sdp_u64(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, Connection { network_type, address_type, address }) = parse_connection(input)?;
+    Ok((input, Origin { username, session_id, session_version, network_type, address_type, address }))
+}
+
+/// Parse the value of a `c=` line.
+pub fn parse_connection<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Connection, E> {
+    let (input, network_type) = parse_sdp_field(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, address_type) = parse_sdp_field(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, address) = parse_sdp_field(input)?;
+    Ok((input, Connection { network_type, address_type, address }))
+}
+
+/// Parse the value of a `t=` line.
+pub fn parse_timing<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Timing, E> {
+    let (input, start) = parse_sdp_u64(input)?;
+    let (input, _) = char(' ')(input)?;
+    let (input, stop) = parse_sdp_u64(input)?;
+    Ok((input, Timing { start, stop }))
+}
@@ -1,19 +1,21 @@
-use crate::parse::slice_to_string;
+use crate::parse::{is_password_char, is_user_char, percent_decode, percent_encode};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use nom::{
     IResult,
     error::ParseError,
     character::complete::char,
-    character::is_alphanumeric,
-    bytes::complete::take_while,
-    combinator::{ map_res, opt}
+    bytes::complete::{take_while, take_while1},
+    combinator::opt,
+    sequence::preceded
 };
 
 use std::fmt;
 
 /// URI Credentials
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UriAuth {
     username: String,
     password: Option<String>,
@@ -37,25 +39,31 @@ impl UriAuth {
 
 impl fmt::Display for UriAuth {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", percent_encode(&self.username, is_user_char))?;
         if let Some(pass) = &self.password {
-            write!(f, "{}:{}", &self.username, pass)?;
-        } else {
-            write!(f, "{}", &self.username)?;
+            write!(f, ":{}", percent_encode(pass, is_password_char))?;
         }
         Ok(())
     }
 }
 
-/// Parse the username/password of a uri.
+/// Parse the username/password of a uri. Percent encoded
+/// octets are decoded once the trailing '@' has been found.
 pub fn parse_uriauth<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], UriAuth, E> {
-    let (input, username) = map_res(take_while(is_alphanumeric), slice_to_string::<E>)(input)?;
-    let (input, password) = opt(parse_password::<E>)(input)?;
+    let (input, username) = take_while1(|item| is_user_char(item) || item == b'%')(input)?;
+    let (input, password) = opt(preceded(char(':'), take_while(|item| is_password_char(item) || item == b'%')))(input)?;
     let (input, _) = char('@')(input)?;
+    let username = percent_decode::<E>(username)?;
+    let password = match password {
+        Some(pass) => Some(percent_decode::<E>(pass)?),
+        None => None
+    };
     Ok((input, UriAuth { username, password }))
  }
 
-/// Currently this will only accept alphanumeric characters.
+/// Parse the password of a uri including the leading ':'.
 pub fn parse_password<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], String, E> {
     let (input, _) = char(':')(input)?;
-    Ok(map_res(take_while(is_alphanumeric), slice_to_string::<E>)(input)?)
+    let (input, pass) = take_while(|item| is_password_char(item) || item == b'%')(input)?;
+    Ok((input, percent_decode::<E>(pass)?))
 }
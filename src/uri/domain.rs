@@ -1,21 +1,38 @@
 use nom::character::{
     is_alphanumeric,
-    is_digit
+    is_digit,
+    is_hex_digit
 };
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use std::{fmt, net::Ipv4Addr};
+use std::{fmt, net::{Ipv4Addr, Ipv6Addr}};
 
-use crate::parse::{parse_ip_address, parse_u16, slice_to_string};
+use crate::parse::{parse_ip_address, parse_ipv6_reference, parse_u16, slice_to_string};
 
-/// Domain address for a URI. Currently Only Ipv4 and
-/// domains are implemented.
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// Domain address for a URI. Ipv6 addresses are written
+/// enclosed in brackets, zone ids are not supported.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Domain {
     Ipv4(Ipv4Addr, Option<u16>),
+    Ipv6(Ipv6Addr, Option<u16>),
     Domain(String, Option<u16>),
 }
 
+impl Domain {
+    /// Compare two domains the way RFC 3261 compares uri hosts,
+    /// host names are compared case-insensitively.
+    pub fn matches(&self, other: &Domain) -> bool {
+        match (self, other) {
+            (Domain::Domain(a, a_port), Domain::Domain(b, b_port)) => {
+                a.eq_ignore_ascii_case(b) && a_port == b_port
+            },
+            (a, b) => a == b,
+        }
+    }
+}
+
 impl fmt::Display for Domain {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -26,6 +43,13 @@ impl fmt::Display for Domain {
                     write!(f, "{}", addr)
                 }
             },
+            Domain::Ipv6(addr, port) => {
+                if let Some(port) = port {
+                    write!(f, "[{}]:{}", addr, port)
+                } else {
+                    write!(f, "[{}]", addr)
+                }
+            },
             Domain::Domain(domain, port) => {
                 if let Some(port) = port {
                     write!(f, "{}:{}", domain, port)
@@ -41,13 +65,21 @@ use nom::{
     IResult,
     branch::alt,
     character::complete::char,
-    bytes::complete::take_while,
-    combinator::{opt, map_res},
+    bytes::complete::{take_while, take_while1},
+    combinator::{opt, map_res, recognize},
+    sequence::pair,
     error::ParseError
 };
 
 pub fn parse_domain<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Domain, E> {
-  alt((parse_ip_domain::<E>, parse_domain_domain::<E>))(input)
+  alt((parse_ipv6_domain::<E>, parse_ip_domain::<E>, parse_domain_domain::<E>))(input)
+}
+
+pub fn parse_ipv6_domain<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Domain, E> {
+    let (input, addr) = parse_ipv6_reference::<E>(input)?;
+    let (input, _) = opt::<_, _, E, _>(char::<_, E>(':'))(input)?;
+    let (input, port) = opt(map_res::<_, _, _, _, E, _, _>(take_while::<_, _, E>(is_digit), parse_u16::<E>))(input)?;
+    Ok((input, Domain::Ipv6(addr, port)))
 }
 
 pub fn parse_ip_domain<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Domain, E> {
@@ -63,4 +95,16 @@ pub fn parse_domain_domain<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IRes
     let (input, _) = opt::<_, _, E, _>(char::<_, E>(':'))(input)?;
     let (input, port) = opt::<_, _, E, _>(map_res::<_, _, _, _, E, _, _>(take_while::<_, _, E>(is_digit), parse_u16::<E>))(input)?;
     Ok((input, Domain::Domain(domain, port)))
-}
\ No newline at end of file
+}
+
+/// Parse the subscriber number of a tel uri. Both global (`+` prefixed)
+/// and local numbers are accepted, visual separators are kept as is.
+pub fn parse_tel_number<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Domain, E> {
+    let (input, number) = map_res::<_, _, _, _, E, _, _>(
+        recognize(pair(opt(char::<_, E>('+')), take_while1::<_, _, E>(is_tel_char))), slice_to_string::<E>)(input)?;
+    Ok((input, Domain::Domain(number, None)))
+}
+
+fn is_tel_char(item: u8) -> bool {
+    is_hex_digit(item) || b"*#-.()".contains(&item)
+}
@@ -0,0 +1,37 @@
+use crate::parse::{is_header_char, percent_decode};
+
+use nom::{
+    IResult,
+    error::ParseError,
+    character::complete::char,
+    bytes::complete::take_while
+};
+
+/// Parse the headers embedded in a uri, including the leading '?'.
+/// Header names and values are percent decoded.
+pub fn parse_uri_headers<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Vec<(String, String)>, E> {
+    let (mut input, _) = char('?')(input)?;
+    let mut headers = vec![];
+    loop {
+        let (data, header) = parse_uri_header::<E>(input)?;
+        headers.push(header);
+        input = data;
+        match char::<_, E>('&')(input) {
+            Ok((data, _)) => input = data,
+            Err(_) => break,
+        }
+    }
+    Ok((input, headers))
+}
+
+/// Parse a single `name=value` uri header.
+pub fn parse_uri_header<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], (String, String), E> {
+    let (input, key) = take_while(is_escaped_header_char)(input)?;
+    let (input, _) = char('=')(input)?;
+    let (input, value) = take_while(is_escaped_header_char)(input)?;
+    Ok((input, (percent_decode::<E>(key)?, percent_decode::<E>(value)?)))
+}
+
+fn is_escaped_header_char(item: u8) -> bool {
+    is_header_char(item) || item == b'%'
+}
@@ -1,5 +1,8 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::core::Transport;
+
 use std::{
     fmt,
     io::Result as IoResult,
@@ -18,21 +21,28 @@ pub mod schema;
 pub use self::schema::{parse_schema, UriSchema};
 
 pub mod domain;
-pub use self::domain::{parse_domain, Domain};
+pub use self::domain::{parse_domain, parse_tel_number, Domain};
 
 pub mod params;
-pub use self::params::{parse_param, parse_params, UriParam};
+pub use self::params::{parse_param, parse_params, UriParam, UserParam};
 
 pub mod auth;
 pub use self::auth::{parse_uriauth, UriAuth};
 
+pub mod headers;
+pub use self::headers::parse_uri_headers;
+
+use crate::{error::parse_complete, parse::{is_header_char, percent_encode}};
+
 /// Universal Rescource Identifier for libsip.
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Uri {
     pub schema: Option<UriSchema>,
     pub host: Domain,
     pub auth: Option<UriAuth>,
     pub parameters: Vec<UriParam>,
+    pub headers: Vec<(String, String)>,
 }
 
 impl Uri {
@@ -42,6 +52,7 @@ impl Uri {
             host,
             auth: None,
             parameters: vec![],
+            headers: vec![],
         }
     }
 
@@ -52,6 +63,7 @@ impl Uri {
             host,
             auth: None,
             parameters: vec![],
+            headers: vec![],
         }
     }
 
@@ -65,6 +77,12 @@ impl Uri {
         Uri::new(UriSchema::Sips, host)
     }
 
+    /// Create a new Uri With schema set to `Schema::Tel`.
+    /// `number` is the subscriber number e.g. `+15551234567`.
+    pub fn tel<S: Into<String>>(number: S) -> Uri {
+        Uri::new(UriSchema::Tel, Domain::Domain(number.into(), None))
+    }
+
     /// Add a `UriAuth` section to this Uri.
     pub fn auth(mut self, auth: UriAuth) -> Uri {
         self.auth = Some(auth);
@@ -90,6 +108,12 @@ impl Uri {
         self
     }
 
+    /// Add a new header to the uri header list.
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Uri {
+        self.headers.push((key.into(), value.into()));
+        self
+    }
+
     /// Remove the Schema if there is any.
     pub fn schemaless(mut self) -> Uri {
         self.schema = None;
@@ -107,6 +131,115 @@ impl Uri {
         format!("{}", self.host)
     }
 
+    /// Retrieve the value of the `transport` parameter if present.
+    pub fn transport(&self) -> Option<Transport> {
+        self.parameters.iter().find_map(|param| match param {
+            UriParam::Transport(transport) => Some(*transport),
+            _ => None
+        })
+    }
+
+    /// Retrieve the value of the `maddr` parameter if present.
+    pub fn maddr(&self) -> Option<&Domain> {
+        self.parameters.iter().find_map(|param| match param {
+            UriParam::Maddr(domain) => Some(domain),
+            _ => None
+        })
+    }
+
+    /// Retrieve the value of the `ttl` parameter if present.
+    pub fn ttl(&self) -> Option<u8> {
+        self.parameters.iter().find_map(|param| match param {
+            UriParam::Ttl(ttl) => Some(*ttl),
+            _ => None
+        })
+    }
+
+    /// Retrieve the value of the `user` parameter if present.
+    pub fn user_param(&self) -> Option<UserParam> {
+        self.parameters.iter().find_map(|param| match param {
+            UriParam::User(user) => Some(*user),
+            _ => None
+        })
+    }
+
+    /// Determine if the `lr` parameter is present.
+    pub fn lr(&self) -> bool {
+        self.parameters.iter().any(|param| param == &UriParam::Lr)
+    }
+
+    /// Split the host into the domain without a port and the port
+    /// to connect to. Without an explicit port the default of the
+    /// transport is used, `sips` uris default to 5061 as they are
+    /// always sent over TLS, or 443 when using websockets.
+    pub fn host_port(&self) -> (Domain, u16) {
+        let (domain, port) = match &self.host {
+            Domain::Ipv4(addr, port) => (Domain::Ipv4(*addr, None), *port),
+            Domain::Ipv6(addr, port) => (Domain::Ipv6(*addr, None), *port),
+            Domain::Domain(name, port) => (Domain::Domain(name.clone(), None), *port),
+        };
+        let default = match (self.schema, self.transport()) {
+            (Some(UriSchema::Sips), Some(Transport::Ws)) | (Some(UriSchema::Sips), Some(Transport::Wss)) => 443,
+            (Some(UriSchema::Sips), _) => Transport::Tls.default_port(),
+            (_, transport) => transport.unwrap_or_default().default_port(),
+        };
+        (domain, port.unwrap_or(default))
+    }
+
+    /// Copy of this uri usable as a Request-URI. RFC 3261 section
+    /// 19.1.1 does not allow the `method` parameter or uri headers
+    /// there, so both are removed. Other parameters are kept.
+    pub fn strip_for_request_uri(&self) -> Uri {
+        let mut uri = self.clone();
+        uri.parameters.retain(|param| !param.key().eq_ignore_ascii_case("method"));
+        uri.headers.clear();
+        uri
+    }
+
+    /// Compare two uris using the rules of RFC 3261 section 19.1.4.
+    ///
+    /// The schema and host are compared case-insensitively while the
+    /// user and password are case-sensitive. The `user`, `ttl`, `method`,
+    /// `maddr` and `transport` parameters must be present in both or neither,
+    /// any other parameter is only compared when present in both uris.
+    /// Parameter order is ignored. As the RFC requires a component that is
+    /// omitted never matches the same component explicitly set to it's
+    /// default value, e.g. `sip:host` does not match `sip:host;transport=udp`.
+    pub fn matches(&self, other: &Uri) -> bool {
+        if self.schema != other.schema || self.auth != other.auth || !self.host.matches(&other.host) {
+            return false;
+        }
+        let find = |uri: &Uri, key: &str| {
+            uri.parameters
+                .iter()
+                .find(|param| param.key().eq_ignore_ascii_case(key))
+                .map(|param| param.value().map(|value| value.to_lowercase()))
+        };
+        for param in self.parameters.iter().chain(other.parameters.iter()) {
+            let key = param.key();
+            match (find(self, &key), find(other, &key)) {
+                (Some(a), Some(b)) if a != b => return false,
+                (None, Some(_)) | (Some(_), None)
+                    if ["user", "ttl", "method", "maddr", "transport"].iter().any(|item| key.eq_ignore_ascii_case(item)) => {
+                    return false;
+                },
+                _ => {},
+            }
+        }
+        let find_header = |uri: &Uri, key: &str| {
+            uri.headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, value)| value.clone())
+        };
+        for (key, _) in self.headers.iter().chain(other.headers.iter()) {
+            if find_header(self, key) != find_header(other, key) {
+                return false;
+            }
+        }
+        true
+    }
+
     /// Retrieve a formatted string containing host and parameters.
     /// This can be used in the Via header.
     pub fn host_and_params(&self) -> IoResult<String> {
@@ -131,30 +264,37 @@ impl fmt::Display for Uri {
         for param in &self.parameters {
             write!(f, "{}", param)?;
         }
+        for (index, (key, value)) in self.headers.iter().enumerate() {
+            let delimiter = if index == 0 { '?' } else { '&' };
+            write!(
+                f, "{}{}={}", delimiter, percent_encode(key, is_header_char), percent_encode(value, is_header_char)
+            )?;
+        }
         Ok(())
     }
 }
 
 pub fn parse_uri<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Uri, E> {
     let (input, schema) = opt(pair(parse_schema::<E>, char(':')))(input)?;
+    let schema = schema.map(|item| item.0);
+    if let Some(UriSchema::Tel) = schema {
+        let (input, host) = parse_tel_number::<E>(input)?;
+        let (input, parameters) = parse_params::<E>(input)?;
+        return Ok((input, Uri { schema, host, parameters, auth: None, headers: vec![] }));
+    }
     let (input, auth) = opt(parse_uriauth::<E>)(input)?;
     let (input, host) = parse_domain::<E>(input)?;
     let (input, parameters) = parse_params::<E>(input)?;
-    Ok((input, Uri { schema: schema.map(|item|item.0), host, parameters, auth}))
+    let (input, headers) = opt(parse_uri_headers::<E>)(input)?;
+    Ok((input, Uri { schema, host, parameters, auth, headers: headers.unwrap_or_default() }))
 }
 
 impl FromStr for Uri {
-    type Err = nom::Err<nom::error::ErrorKind>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(parse_uri(s.as_bytes()).map_err(map_error)?.1)
-    }
-}
+    type Err = crate::ParseError;
 
-fn map_error(n: nom::Err<(&[u8], nom::error::ErrorKind)>) -> nom::Err<nom::error::ErrorKind> {
-    match n {
-        nom::Err::Error((_, a)) => nom::Err::Error(a),
-        nom::Err::Failure((_, b)) => nom::Err::Failure(b),
-        nom::Err::Incomplete(a) => nom::Err::Incomplete(a),
+    /// The whole string must be a uri, surrounding
+    /// whitespace is not removed.
+    fn from_str(s: &str) -> Result<Uri, crate::ParseError> {
+        parse_complete(s.as_bytes(), parse_uri(s.as_bytes()))
     }
 }
@@ -1,40 +1,94 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::{
     core::{parse_transport, Transport},
+    parse::{is_param_char, parse_u16, parse_u8, percent_decode, percent_encode},
     uri::{parse_domain, Domain},
 };
 
 use nom::{
     IResult,
-    character::{is_alphabetic, is_alphanumeric},
     error::ParseError,
     bytes::complete::{ take_while, tag},
-    combinator::map,
     branch::alt
 };
 
-/// Uri Parameters.
-///
-/// TODO: Expand this enum. Similar to `libsip::Header`
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// Value of the `user` uri parameter.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UserParam {
+    Phone,
+    Ip,
+}
+
+impl fmt::Display for UserParam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserParam::Phone => write!(f, "phone"),
+            UserParam::Ip => write!(f, "ip"),
+        }
+    }
+}
+
+/// Uri Parameters. Parameters without a dedicated
+/// variant are kept in `UriParam::Other`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UriParam {
     Transport(Transport),
     Branch(String),
     Received(Domain),
-    RPort,
+    RPort(Option<u16>),
+    Lr,
+    Maddr(Domain),
+    Ttl(u8),
+    User(UserParam),
     Other(String, Option<String>)
 }
 
 impl UriParam {
+    /// The parameter name, as written before the '='.
+    pub fn key(&self) -> String {
+        match self {
+            UriParam::Transport(_) => "transport".into(),
+            UriParam::Branch(_) => "branch".into(),
+            UriParam::Received(_) => "received".into(),
+            UriParam::RPort(_) => "rport".into(),
+            UriParam::Lr => "lr".into(),
+            UriParam::Maddr(_) => "maddr".into(),
+            UriParam::Ttl(_) => "ttl".into(),
+            UriParam::User(_) => "user".into(),
+            UriParam::Other(key, _) => key.clone(),
+        }
+    }
+
+    /// The parameter value, None for flag parameters.
+    pub fn value(&self) -> Option<String> {
+        match self {
+            UriParam::Transport(transport) => Some(transport.param_value().into()),
+            UriParam::Branch(branch) => Some(branch.clone()),
+            UriParam::Received(domain) => Some(format!("{}", domain)),
+            UriParam::RPort(port) => port.map(|port| format!("{}", port)),
+            UriParam::Lr => None,
+            UriParam::Maddr(domain) => Some(format!("{}", domain)),
+            UriParam::Ttl(ttl) => Some(format!("{}", ttl)),
+            UriParam::User(user) => Some(format!("{}", user)),
+            UriParam::Other(_, value) => value.clone(),
+        }
+    }
+
     /// Create `UriParam` from a key value pair.
     pub fn from_key<'a, E: ParseError<&'a [u8]>>(
         key: &'a [u8],
         value: &'a [u8],
     ) -> Result<UriParam, nom::Err<E>> {
         match key {
-            b"transport" => Ok(UriParam::Transport(parse_transport::<E>(&value)?.1)),
+            _ if key.eq_ignore_ascii_case(b"transport") => match parse_transport::<E>(value) {
+                Ok((&[], transport)) => Ok(UriParam::Transport(transport)),
+                _ => Ok(UriParam::Other(percent_decode::<E>(key)?, Some(percent_decode::<E>(value)?))),
+            },
             b"branch" => Ok(UriParam::Branch(
                 String::from_utf8(value.to_vec()).expect("Utf-8 Error"),
             )),
@@ -43,9 +97,20 @@ impl UriParam {
                 //data.push(b' ');
                 Ok(UriParam::Received(parse_domain::<E>(&value)?.1))
             },
+            b"rport" => match parse_u16::<E>(value) {
+                Ok(port) => Ok(UriParam::RPort(Some(port))),
+                Err(_) => Ok(UriParam::Other(percent_decode::<E>(key)?, Some(percent_decode::<E>(value)?))),
+            },
+            b"maddr" => Ok(UriParam::Maddr(parse_domain::<E>(value)?.1)),
+            b"ttl" => match parse_u8(value) {
+                Ok(ttl) => Ok(UriParam::Ttl(ttl)),
+                Err(_) => Ok(UriParam::Other(percent_decode::<E>(key)?, Some(percent_decode::<E>(value)?))),
+            },
+            b"user" if value == b"phone" => Ok(UriParam::User(UserParam::Phone)),
+            b"user" if value == b"ip" => Ok(UriParam::User(UserParam::Ip)),
             _method => Ok(UriParam::Other(
-                String::from_utf8_lossy(key).to_string(),
-                Some(String::from_utf8_lossy(value).to_string())
+                percent_decode::<E>(key)?,
+                Some(percent_decode::<E>(value)?)
             )),
         }
     }
@@ -54,12 +119,19 @@ impl UriParam {
 impl fmt::Display for UriParam {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            UriParam::Transport(trans) => write!(f, ";transport={}", trans),
+            UriParam::Transport(trans) => write!(f, ";transport={}", trans.param_value()),
             UriParam::Branch(branch) => write!(f, ";branch={}", branch),
             UriParam::Received(branch) => write!(f, ";received={}", branch),
-            UriParam::RPort => write!(f, ";rport"),
-            UriParam::Other(key, Some(value)) => write!(f, ";{}={}", key, value),
-            UriParam::Other(key, None) => write!(f, ";{}", key)
+            UriParam::RPort(Some(port)) => write!(f, ";rport={}", port),
+            UriParam::RPort(None) => write!(f, ";rport"),
+            UriParam::Lr => write!(f, ";lr"),
+            UriParam::Maddr(domain) => write!(f, ";maddr={}", domain),
+            UriParam::Ttl(ttl) => write!(f, ";ttl={}", ttl),
+            UriParam::User(user) => write!(f, ";user={}", user),
+            UriParam::Other(key, Some(value)) => write!(
+                f, ";{}={}", percent_encode(key, is_param_char), percent_encode(value, is_param_char)
+            ),
+            UriParam::Other(key, None) => write!(f, ";{}", percent_encode(key, is_param_char))
         }
     }
 }
@@ -67,7 +139,6 @@ impl fmt::Display for UriParam {
 pub fn parse_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], UriParam, E> {
     alt::<_, _, E, _>(
         (
-            map(tag::<_, _, E>(";rport"), |_| UriParam::RPort),
             parse_named_param,
             parse_single_param
         )
@@ -77,27 +148,44 @@ pub fn parse_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a
 /// Parse a single named field param.
 pub fn parse_named_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], UriParam, E> {
     let (input, _) = tag(";")(input)?;
-    let (input, key) = take_while(is_alphabetic)(input)?;
+    let (input, key) = take_while(is_escaped_param_char)(input)?;
     let (input, _) = tag("=")(input)?;
-    let (input, value) = take_while(|item| is_alphanumeric(item) || b'.' == item)(input)?;
+    let (input, value) = take_while(is_escaped_param_char)(input)?;
     UriParam::from_key::<E>(key, value)
         .and_then(|item| Ok((input, item)))
 }
 
 pub fn parse_single_param<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], UriParam, E> {
     let (input, _) = tag(";")(input)?;
-    let (input, key) = take_while(is_alphabetic)(input)?;
-    Ok((input, UriParam::Other(String::from_utf8_lossy(key).into(), None)))
+    let (input, key) = take_while(is_escaped_param_char)(input)?;
+    match key {
+        b"lr" => return Ok((input, UriParam::Lr)),
+        b"rport" => return Ok((input, UriParam::RPort(None))),
+        _ => {},
+    }
+    Ok((input, UriParam::Other(percent_decode::<E>(key)?, None)))
+}
+
+fn is_escaped_param_char(item: u8) -> bool {
+    is_param_char(item) || item == b'%'
 }
 
-/// Parse multiple uri parameters.
+/// Parse multiple uri parameters. Stops at the first
+/// input that is not a parameter, parse failures
+/// such as malformed escapes are returned.
 pub fn parse_params<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Vec<UriParam>, E> {
     let mut results = vec![];
     let mut data = input;
 
-    while let Ok((remains, param)) = parse_param::<E>(&data) {
-        results.push(param);
-        data = remains;
+    loop {
+        match parse_param::<E>(&data) {
+            Ok((remains, param)) => {
+                results.push(param);
+                data = remains;
+            },
+            Err(nom::Err::Failure(e)) => return Err(nom::Err::Failure(e)),
+            Err(_) => break,
+        }
     }
     Ok((data, results))
 }
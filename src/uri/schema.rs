@@ -1,3 +1,4 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use std::fmt;
@@ -10,10 +11,12 @@ use nom::{
 };
 
 /// Sip URI Schema.
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UriSchema {
     Sip,
     Sips,
+    Tel,
 }
 
 impl fmt::Display for UriSchema {
@@ -21,14 +24,18 @@ impl fmt::Display for UriSchema {
         match self {
             UriSchema::Sip => write!(f, "sip"),
             UriSchema::Sips => write!(f, "sips"),
+            UriSchema::Tel => write!(f, "tel"),
         }
     }
 }
 
-/// Parse SIP URI schema. Only Accepts 'sip' and 'sips'.
+/// Parse SIP URI schema. Only Accepts 'sip', 'sips' and 'tel'.
+/// 'sips' has to be tried first otherwise 'sip' would
+/// match it's prefix.
 pub fn parse_schema<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], UriSchema, E> {
     alt::<_, _, E, _>((
+        map(tag_no_case::<_, _, E>("sips"), |_| UriSchema::Sips),
         map(tag_no_case::<_, _, E>("sip"), |_| UriSchema::Sip),
-        map(tag_no_case::<_, _, E>("sips"), |_| UriSchema::Sips)
+        map(tag_no_case::<_, _, E>("tel"), |_| UriSchema::Tel)
     ))(input)
 }
@@ -0,0 +1,155 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+Record-Route: <sip:proxy.atlanta.com;lr>\r\n\
+Record-Route: <sip:server10.biloxi.com;lr>\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:alice@pc33.atlanta.com>\r\n\
+Content-Length: 0\r\n\r\n";
+
+const OK: &[u8] = b"SIP/2.0 200 OK\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Record-Route: <sip:proxy.atlanta.com;lr>\r\n\
+Record-Route: <sip:server10.biloxi.com;lr>\r\n\
+To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:bob@192.0.2.4>\r\n\
+Content-Length: 0\r\n\r\n";
+
+fn parse(input: &[u8]) -> SipMessage {
+    parse_message::<VerboseError<&[u8]>>(input).unwrap().1
+}
+
+fn via() -> ViaHeader {
+    ViaHeader::new(Uri::new_schemaless(domain!("pc33.atlanta.com")), Transport::Udp).with_branch(gen_branch())
+}
+
+fn route_uris(message: &SipMessage) -> Vec<String> {
+    message.headers().routes().iter().map(|route| route.uri.to_string()).collect()
+}
+
+#[test]
+fn uac() {
+    let dialog = Dialog::uac(&parse(INVITE), &parse(OK)).unwrap();
+    assert_eq!("a84b4c76e66710@pc33.atlanta.com", dialog.call_id);
+    assert_eq!("1928301774", dialog.local_tag);
+    assert_eq!(Some("a6c85cf".to_string()), dialog.remote_tag);
+    assert_eq!("sip:alice@atlanta.com", dialog.local_uri.uri.to_string());
+    assert_eq!("sip:bob@biloxi.com", dialog.remote_uri.uri.to_string());
+    assert_eq!(None, dialog.remote_uri.tag());
+    assert_eq!("sip:bob@192.0.2.4", dialog.remote_target.to_string());
+    assert_eq!(314159, dialog.local_cseq);
+    assert_eq!(Some(314159), dialog.invite_cseq);
+    let routes: Vec<String> = dialog.route_set.iter().map(|route| route.uri.to_string()).collect();
+    assert_eq!(vec!["sip:server10.biloxi.com;lr", "sip:proxy.atlanta.com;lr"], routes);
+}
+
+#[test]
+fn uac_request() {
+    let mut dialog = Dialog::uac(&parse(INVITE), &parse(OK)).unwrap();
+    let bye = dialog.request(Method::Bye).via(via()).build().unwrap();
+    match &bye {
+        SipMessage::Request { method, uri, .. } => {
+            assert_eq!(&Method::Bye, method);
+            assert_eq!("sip:bob@192.0.2.4", uri.to_string());
+        },
+        SipMessage::Response { .. } => panic!("expected a request"),
+    }
+    assert_eq!(Some(Header::CSeq(314160, Method::Bye)), bye.headers().cseq());
+    assert_eq!(Some(Header::CallId("a84b4c76e66710@pc33.atlanta.com".into())), bye.headers().call_id());
+    match (bye.headers().from(), bye.headers().to()) {
+        (Some(Header::From(from)), Some(Header::To(to))) => {
+            assert_eq!(Some("1928301774"), from.tag());
+            assert_eq!(Some("a6c85cf"), to.tag());
+        },
+        _ => panic!("request is missing From or To"),
+    }
+    assert_eq!(vec!["sip:server10.biloxi.com;lr", "sip:proxy.atlanta.com;lr"], route_uris(&bye));
+
+    let info = dialog.request(Method::Info).via(via()).build().unwrap();
+    assert_eq!(Some(Header::CSeq(314161, Method::Info)), info.headers().cseq());
+    let ack = dialog.request(Method::Ack).via(via()).build().unwrap();
+    assert_eq!(Some(Header::CSeq(314159, Method::Ack)), ack.headers().cseq());
+
+    let reinvite = dialog.request(Method::Invite).via(via()).build().unwrap();
+    assert_eq!(Some(Header::CSeq(314162, Method::Invite)), reinvite.headers().cseq());
+    let ack = dialog.request(Method::Ack).via(via()).build().unwrap();
+    assert_eq!(Some(Header::CSeq(314162, Method::Ack)), ack.headers().cseq());
+}
+
+#[test]
+fn uas() {
+    let mut dialog = Dialog::uas(&parse(INVITE)).unwrap();
+    assert_eq!(Some("1928301774".to_string()), dialog.remote_tag);
+    assert!(!dialog.local_tag.is_empty());
+    assert_eq!("sip:bob@biloxi.com", dialog.local_uri.uri.to_string());
+    assert_eq!("sip:alice@pc33.atlanta.com", dialog.remote_target.to_string());
+    assert_eq!(Some(314159), dialog.remote_cseq);
+
+    let bye = dialog.request(Method::Bye).via(via()).build().unwrap();
+    assert_eq!(Some(Header::CSeq(1, Method::Bye)), bye.headers().cseq());
+    assert_eq!(vec!["sip:proxy.atlanta.com;lr", "sip:server10.biloxi.com;lr"], route_uris(&bye));
+}
+
+#[test]
+fn strict_route() {
+    let mut dialog = Dialog::uac(&parse(INVITE), &parse(OK)).unwrap();
+    dialog.route_set = vec![NamedHeader::new(Uri::sip(domain!("strict.biloxi.com")))];
+    let bye = dialog.request(Method::Bye).via(via()).build().unwrap();
+    match &bye {
+        SipMessage::Request { uri, .. } => assert_eq!("sip:strict.biloxi.com", uri.to_string()),
+        SipMessage::Response { .. } => panic!("expected a request"),
+    }
+    assert_eq!(vec!["sip:bob@192.0.2.4"], route_uris(&bye));
+}
+
+#[test]
+fn uac_requires_contact() {
+    let invite = parse(INVITE);
+    let response = invite.make_response(200, None);
+    assert!(Dialog::uac(&invite, &response).is_err());
+}
+
+#[test]
+fn prack() {
+    let invite = parse(INVITE);
+    let provisional = b"SIP/2.0 183 Session Progress\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:bob@192.0.2.4>\r\n\
+Require: 100rel\r\n\
+RSeq: 813520\r\n\
+Content-Length: 0\r\n\r\n";
+    let provisional = parse(provisional);
+    let mut dialog = Dialog::uac(&invite, &provisional).unwrap();
+    let prack = dialog.make_prack(&provisional).unwrap().via(via()).build().unwrap();
+    match &prack {
+        SipMessage::Request { method, uri, .. } => {
+            assert_eq!(&Method::PRack, method);
+            assert_eq!("sip:bob@192.0.2.4", uri.to_string());
+        },
+        SipMessage::Response { .. } => panic!("expected a request"),
+    }
+    assert_eq!(Some(Header::CSeq(314160, Method::PRack)), prack.headers().cseq());
+    assert!(prack.headers().iter().any(|h| h == &Header::RAck(813520, 314159, Method::Invite)));
+
+    let ringing = invite.make_response(180, None);
+    assert!(dialog.make_prack(&ringing).is_err());
+    assert_eq!(314160, dialog.local_cseq);
+
+    let ack = dialog.request(Method::Ack).via(via()).build().unwrap();
+    assert_eq!(Some(Header::CSeq(314159, Method::Ack)), ack.headers().cseq());
+    assert_eq!(314160, dialog.local_cseq);
+}
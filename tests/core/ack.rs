@@ -0,0 +1,97 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+Route: <sip:proxy.atlanta.com;lr>\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:alice@pc33.atlanta.com>\r\n\
+Content-Length: 0\r\n\r\n";
+
+const OK: &[u8] = b"SIP/2.0 200 OK\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Record-Route: <sip:server10.biloxi.com;lr>\r\n\
+Record-Route: <sip:proxy.atlanta.com;lr>\r\n\
+To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:bob@192.0.2.4>\r\n\
+Content-Length: 0\r\n\r\n";
+
+const BUSY: &[u8] = b"SIP/2.0 486 Busy Here\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Content-Length: 0\r\n\r\n";
+
+fn parse(input: &[u8]) -> SipMessage {
+    parse_message::<VerboseError<&[u8]>>(input).unwrap().1
+}
+
+fn request_uri(message: &SipMessage) -> String {
+    match message {
+        SipMessage::Request { uri, .. } => uri.to_string(),
+        SipMessage::Response { .. } => panic!("expected a request"),
+    }
+}
+
+#[test]
+fn ack_for_2xx() {
+    let invite = parse(INVITE);
+    let response = parse(OK);
+    let ack = invite.make_ack(&response).unwrap();
+    match &ack {
+        SipMessage::Request { method, .. } => assert_eq!(&Method::Ack, method),
+        SipMessage::Response { .. } => panic!("expected a request"),
+    }
+    assert_eq!("sip:bob@192.0.2.4", request_uri(&ack));
+    assert_eq!(Some(Header::CSeq(314159, Method::Ack)), ack.headers().cseq());
+    assert_eq!(response.headers().to(), ack.headers().to());
+    assert_eq!(invite.headers().from(), ack.headers().from());
+    assert_eq!(invite.headers().call_id(), ack.headers().call_id());
+
+    let vias = ack.headers().vias();
+    assert_eq!(1, vias.len());
+    assert_ne!(Some("z9hG4bK776asdhds"), vias[0].branch());
+    assert!(vias[0].branch().unwrap().starts_with("z9hG4bK"));
+
+    let routes: Vec<String> = ack.headers().routes().iter().map(|r| r.uri.to_string()).collect();
+    assert_eq!(vec!["sip:proxy.atlanta.com;lr", "sip:server10.biloxi.com;lr"], routes);
+}
+
+#[test]
+fn ack_for_non_2xx() {
+    let invite = parse(INVITE);
+    let response = parse(BUSY);
+    let ack = invite.make_ack(&response).unwrap();
+    assert_eq!("sip:bob@biloxi.com", request_uri(&ack));
+    assert_eq!(Some(Header::CSeq(314159, Method::Ack)), ack.headers().cseq());
+    assert_eq!(response.headers().to(), ack.headers().to());
+
+    let vias = ack.headers().vias();
+    assert_eq!(1, vias.len());
+    assert_eq!(Some("z9hG4bK776asdhds"), vias[0].branch());
+    assert_eq!(invite.headers().routes(), ack.headers().routes());
+}
+
+#[test]
+fn ack_requires_final_response() {
+    let invite = parse(INVITE);
+    let ringing = invite.make_response(180, None);
+    assert!(invite.make_ack(&ringing).is_err());
+}
+
+#[test]
+fn ack_for_2xx_requires_contact() {
+    let invite = parse(INVITE);
+    let response = invite.make_response(200, None);
+    assert!(invite.make_ack(&response).is_err());
+}
@@ -0,0 +1,78 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Via: SIP/2.0/UDP 192.0.2.1;branch=z9hG4bKnashds8\r\n\
+Max-Forwards: 70\r\n\
+Route: <sip:proxy.atlanta.com;lr>\r\n\
+To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:alice@pc33.atlanta.com>\r\n\
+Content-Type: application/sdp\r\n\
+Content-Length: 4\r\n\r\nv=0\n";
+
+#[test]
+fn make_cancel() {
+    let (_, invite) = parse_message::<VerboseError<&[u8]>>(INVITE).unwrap();
+    let cancel = invite.make_cancel().unwrap();
+    match (&invite, &cancel) {
+        (SipMessage::Request { uri: invite_uri, .. }, SipMessage::Request { method, uri, .. }) => {
+            assert_eq!(&Method::Cancel, method);
+            assert_eq!(invite_uri, uri);
+        },
+        _ => panic!("expected requests"),
+    }
+    assert!(cancel.body().is_empty());
+    assert_eq!(Some(Header::CSeq(314159, Method::Cancel)), cancel.headers().cseq());
+    assert_eq!(invite.headers().from(), cancel.headers().from());
+    assert_eq!(invite.headers().call_id(), cancel.headers().call_id());
+    assert_eq!(invite.headers().routes(), cancel.headers().routes());
+    assert_eq!(None, cancel.headers().contact());
+    assert_eq!(Some(Header::ContentLength(0)), cancel.headers().content_length());
+
+    match cancel.headers().to() {
+        Some(Header::To(to)) => {
+            assert_eq!(None, to.tag());
+            assert_eq!("sip:bob@biloxi.com", to.uri.to_string());
+        },
+        _ => panic!("CANCEL is missing its To header"),
+    }
+
+    let vias = cancel.headers().vias();
+    assert_eq!(1, vias.len());
+    assert_eq!(invite.headers().vias()[0].branch(), vias[0].branch());
+}
+
+#[test]
+fn make_cancel_from_response() {
+    let (_, invite) = parse_message::<VerboseError<&[u8]>>(INVITE).unwrap();
+    let ringing = invite.make_response(180, None);
+    assert_eq!(
+        std::io::ErrorKind::InvalidInput,
+        ringing.make_cancel().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn make_cancel_from_non_invite() {
+    let (_, invite) = parse_message::<VerboseError<&[u8]>>(INVITE).unwrap();
+    let ack = invite.make_ack(&invite.make_response(486, None)).unwrap();
+    assert_eq!(
+        std::io::ErrorKind::InvalidInput,
+        ack.make_cancel().unwrap_err().kind()
+    );
+}
+
+#[test]
+fn make_cancel_without_via() {
+    let (_, mut invite) = parse_message::<VerboseError<&[u8]>>(INVITE).unwrap();
+    invite.headers_mut().0.retain(|h| !matches!(h, Header::Via(_)));
+    assert_eq!(
+        std::io::ErrorKind::InvalidInput,
+        invite.make_cancel().unwrap_err().kind()
+    );
+}
@@ -0,0 +1,26 @@
+use libsip::*;
+
+#[test]
+fn canonical_reason() {
+    assert_eq!(Some("Busy Here"), StatusCode::from_u16(486).canonical_reason());
+    assert_eq!(Some("Alternative Service"), StatusCode::from_u16(380).canonical_reason());
+    assert_eq!(Some("Session Interval Too Small"), StatusCode::from_u16(422).canonical_reason());
+    assert_eq!(Some("Request Pending"), StatusCode::from_u16(491).canonical_reason());
+    assert_eq!(None, StatusCode::from_u16(499).canonical_reason());
+}
+
+#[test]
+fn classify() {
+    assert!(StatusCode::from_u16(180).is_provisional());
+    assert!(StatusCode::from_u16(200).is_success());
+    assert!(StatusCode::from_u16(302).is_redirect());
+    assert!(StatusCode::from_u16(486).is_request_failure());
+    assert!(StatusCode::from_u16(503).is_server_failure());
+    assert!(StatusCode::from_u16(603).is_global_failure());
+
+    let unknown = StatusCode::from_u16(499);
+    assert!(unknown.is_request_failure());
+    assert!(!unknown.is_success());
+    assert_eq!(499, unknown.as_u16());
+    assert_eq!("499".to_string(), format!("{}", unknown));
+}
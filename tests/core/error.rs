@@ -0,0 +1,30 @@
+use libsip::*;
+
+#[test]
+fn parse_error_offset() {
+    let err = try_parse_message(b"INVITE sip:bob@biloxi.com SIP/3\r\n\r\n").unwrap_err();
+    assert_eq!(31, err.offset);
+    assert_eq!("expected '.'", err.description);
+    assert_eq!("INVITE sip:bob@biloxi.com SIP/3", err.line());
+    assert_eq!(31, err.column());
+
+    let err = try_parse_message(b"SIP/2.0 200 OK\r\nContent-Length: 10\r\n\r\nabc").unwrap_err();
+    assert_eq!(38, err.offset);
+    assert_eq!("abc", err.line());
+    assert_eq!(0, err.column());
+
+    assert!(try_parse_message(b"SIP/2.0 200 OK\r\n\r\n").is_ok());
+}
+
+#[test]
+fn parse_error_display() {
+    let err = try_parse_message(b"SIP/2.0 2000 OK\r\nCSeq: 1 INVITE\r\n\r\n").unwrap_err();
+    assert_eq!(8, err.offset);
+    assert_eq!(
+        "unexpected input (predicate verification) at offset 8\nSIP/2.0 2000 OK\n        ^",
+        format!("{}", err)
+    );
+
+    let err = ParseError::new(b"MESSAGE sip:bob@biloxi.com SIP/2.0\r\nTo: Bob\r\n", 40, "expected '<'");
+    assert_eq!("expected '<' at offset 40\nTo: Bob\n    ^", format!("{}", err));
+}
@@ -0,0 +1,57 @@
+use libsip::*;
+
+const FIRST: &[u8] = b"MESSAGE sip:bob@example.com SIP/2.0\r\nContent-Length: 5\r\n\r\nhello";
+const SECOND: &[u8] = b"SIP/2.0 200 OK\r\nl: 3\r\n\r\nbye";
+
+#[test]
+fn push_complete_messages() {
+    let mut framer = MessageFramer::new();
+    let mut data = FIRST.to_vec();
+    data.extend_from_slice(SECOND);
+    let messages = framer.push(&data).unwrap();
+    assert_eq!(2, messages.len());
+    assert_eq!(b"hello", &messages[0].body()[..]);
+    assert_eq!(b"bye", &messages[1].body()[..]);
+    assert!(framer.buffered().is_empty());
+}
+
+#[test]
+fn push_split_messages() {
+    let mut data = FIRST.to_vec();
+    data.extend_from_slice(b"\r\n");
+    data.extend_from_slice(SECOND);
+    for split in 0..data.len() {
+        let mut framer = MessageFramer::new();
+        let mut messages = framer.push(&data[..split]).unwrap();
+        messages.extend(framer.push(&data[split..]).unwrap());
+        assert_eq!(2, messages.len(), "split at {}", split);
+        assert_eq!(b"hello", &messages[0].body()[..]);
+        assert_eq!(b"bye", &messages[1].body()[..]);
+    }
+}
+
+#[test]
+fn push_partial_body() {
+    let mut framer = MessageFramer::new();
+    assert!(framer.push(&FIRST[..FIRST.len() - 2]).unwrap().is_empty());
+    assert_eq!(FIRST.len() - 2, framer.buffered().len());
+    assert_eq!(1, framer.push(&FIRST[FIRST.len() - 2..]).unwrap().len());
+}
+
+#[test]
+fn push_too_large() {
+    let mut framer = MessageFramer::with_max_message_size(32);
+    assert!(framer.push(&FIRST[..30]).unwrap().is_empty());
+    assert!(framer.push(&FIRST[30..]).is_err());
+    assert!(framer.buffered().is_empty());
+
+    let mut framer = MessageFramer::with_max_message_size(16);
+    assert!(framer.push(&[b'A'; 17]).is_err());
+}
+
+#[test]
+fn push_invalid() {
+    let mut framer = MessageFramer::new();
+    assert!(framer.push(b"NOT SIP\r\n\r\n").is_err());
+    assert!(framer.buffered().is_empty());
+}
@@ -0,0 +1,53 @@
+use libsip::*;
+
+#[test]
+fn uri_from_str() {
+    let uri: Uri = "sip:alice@atlanta.com;transport=tcp".parse().unwrap();
+    assert_eq!(Uri::sip(domain!("atlanta.com")).auth(uri_auth!("alice")).parameter(UriParam::Transport(Transport::Tcp)), uri);
+
+    let err = "sip:alice@atlanta.com>".parse::<Uri>().unwrap_err();
+    assert_eq!(21, err.offset);
+    assert_eq!("unexpected trailing input", err.description);
+    // Whitespace is not part of a uri and is not trimmed.
+    assert!("sip:alice@atlanta.com ".parse::<Uri>().is_err());
+    assert!(" sip:alice@atlanta.com".parse::<Uri>().is_err());
+    assert!("".parse::<Uri>().is_err());
+}
+
+#[test]
+fn named_header_from_str() {
+    let header: NamedHeader = "Bob <sip:bob@biloxi.com>;tag=a6c85cf".parse().unwrap();
+    assert_eq!(Some("Bob"), header.display_name.as_deref());
+    assert_eq!("sip:bob@biloxi.com", header.uri.to_string());
+    assert_eq!(Some("a6c85cf"), header.tag());
+
+    assert!("Bob <sip:bob@biloxi.com> junk".parse::<NamedHeader>().is_err());
+    // Trailing whitespace is ignored, as for Header.
+    assert_eq!(NamedHeader::new(Uri::sip(domain!("biloxi.com")).auth(uri_auth!("bob"))), "<sip:bob@biloxi.com> \r\n".parse().unwrap());
+}
+
+#[test]
+fn header_from_str() {
+    assert_eq!(Header::MaxForwards(70), "Max-Forwards: 70".parse().unwrap());
+    assert_eq!(Header::MaxForwards(70), "Max-Forwards: 70\r\n".parse().unwrap());
+    assert_eq!(Header::CallId("a84b4c76e66710".into()), "i: a84b4c76e66710".parse().unwrap());
+
+    let via = ViaHeader::new(Uri::new_schemaless(domain!("pc33.atlanta.com")), Transport::Udp).with_branch("z9hG4bK776asdhds");
+    assert_eq!(Header::Via(via.clone()), "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds".parse().unwrap());
+    assert_eq!(Header::Via(via), "v: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n".parse().unwrap());
+    match "Via: SIP/2.0/UDP pc33.atlanta.com;rport;received=192.0.2.1".parse::<Header>() {
+        Ok(Header::Via(via)) => assert_eq!(None, via.branch()),
+        other => panic!("expected a Via header, got {:?}", other),
+    }
+    // Trailing whitespace is not part of the value.
+    assert_eq!(Header::Expires(60), "Expires: 60 \t".parse().unwrap());
+    assert_eq!(Header::Subject("Need more boxes".into()), "Subject: Need more boxes  ".parse().unwrap());
+
+    // Like parse_header, a value that does not fit the header is kept as is.
+    assert_eq!(Header::Other("Max-Forwards".into(), "70 hops".into()), "Max-Forwards: 70 hops".parse().unwrap());
+
+    let err = "Max-Forwards: 70\r\nExpires: 60".parse::<Header>().unwrap_err();
+    assert_eq!(18, err.offset);
+    assert_eq!("unexpected trailing input", err.description);
+    assert!("Max-Forwards 70".parse::<Header>().is_err());
+}
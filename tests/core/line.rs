@@ -0,0 +1,88 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+#[test]
+fn write_request_line() {
+    let uri = Uri::sip(domain!("biloxi.com")).auth(uri_auth!("bob"));
+    let line = RequestLine::new(Method::Invite, uri);
+    assert_eq!("INVITE sip:bob@biloxi.com SIP/2.0".to_string(), format!("{}", line));
+
+    let line = RequestLine::new(Method::Register, Uri::sip(domain!("registrar.biloxi.com")));
+    assert_eq!("REGISTER sip:registrar.biloxi.com SIP/2.0".to_string(), format!("{}", line));
+}
+
+#[test]
+fn read_request_line() {
+    let remains = vec![];
+    let uri = Uri::sip(domain!("biloxi.com")).auth(uri_auth!("bob"));
+    let line = RequestLine::new(Method::Invite, uri);
+    assert_eq!(
+        Ok((remains.as_ref(), line)),
+        parse_request_line::<VerboseError<&[u8]>>(b"INVITE sip:bob@biloxi.com SIP/2.0\r\n")
+    );
+
+    let line = RequestLine::new(Method::Register, Uri::sip(domain!("registrar.biloxi.com")));
+    assert_eq!(
+        Ok((remains.as_ref(), line)),
+        parse_request_line::<VerboseError<&[u8]>>(b"REGISTER sip:registrar.biloxi.com SIP/2.0\r\n")
+    );
+}
+
+#[test]
+fn read_request_line_version() {
+    let line = parse_request_line::<VerboseError<&[u8]>>(b"OPTIONS sip:biloxi.com SIP/3.1\r\n").unwrap().1;
+    assert_eq!(3, line.version.major);
+    assert_eq!(1, line.version.minor);
+
+    assert!(parse_request_line::<VerboseError<&[u8]>>(b"INVITE sip:bob@biloxi.com HTTP/1.1\r\n").is_err());
+    assert!(parse_request_line::<VerboseError<&[u8]>>(b"INVITE sip:bob@biloxi.com SIP/2\r\n").is_err());
+}
+
+#[test]
+fn write_status_line() {
+    assert_eq!("SIP/2.0 200 OK".to_string(), format!("{}", StatusLine::new(200, "OK")));
+    assert_eq!("SIP/2.0 180 ".to_string(), format!("{}", StatusLine::new(180, "")));
+    assert_eq!("SIP/2.0 503 Service Unavailable".to_string(), format!("{}", StatusLine::new(503, "Service Unavailable")));
+}
+
+#[test]
+fn read_status_line() {
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), StatusLine::new(200, "OK"))),
+        parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 200 OK\r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), StatusLine::new(486, "Busy Here"))),
+        parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 486 Busy Here\r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), StatusLine::new(503, "Service Unavailable"))),
+        parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 503 Service Unavailable\r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), StatusLine::new(480, "Temporärer Fehler"))),
+        parse_status_line::<VerboseError<&[u8]>>("SIP/2.0 480 Temporärer Fehler\r\n".as_bytes())
+    );
+}
+
+#[test]
+fn read_status_line_empty_reason() {
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), StatusLine::new(180, ""))),
+        parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 180 \r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), StatusLine::new(180, ""))),
+        parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 180\r\n")
+    );
+}
+
+#[test]
+fn read_status_line_code_range() {
+    assert!(parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 099 Nope\r\n").is_err());
+    assert!(parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 700 Nope\r\n").is_err());
+    assert!(parse_status_line::<VerboseError<&[u8]>>(b"SIP/2.0 2000 OK\r\n").is_err());
+}
@@ -0,0 +1,43 @@
+use libsip::*;
+
+fn request(max_forwards: Option<u32>) -> SipMessage {
+    let mut builder = RequestGenerator::new()
+        .method(Method::Options)
+        .uri(Uri::sip(domain!("biloxi.com")));
+    if let Some(value) = max_forwards {
+        builder = builder.header(Header::MaxForwards(value));
+    }
+    builder.build().unwrap()
+}
+
+#[test]
+fn decrement() {
+    let mut message = request(Some(2));
+    assert_eq!(Ok(1), message.decrement_max_forwards());
+    assert_eq!(Some(&Header::MaxForwards(1)), message.header("Max-Forwards"));
+    assert_eq!(Ok(0), message.decrement_max_forwards());
+    assert_eq!(Some(&Header::MaxForwards(0)), message.header("Max-Forwards"));
+}
+
+#[test]
+fn reaches_zero() {
+    let mut message = request(Some(0));
+    assert_eq!(Err(TooManyHops), message.decrement_max_forwards());
+    assert_eq!(Some(&Header::MaxForwards(0)), message.header("Max-Forwards"));
+    let response = message.make_response(483, None);
+    assert_eq!(Some("Too Many Hops"), response.reason());
+}
+
+#[test]
+fn missing_header() {
+    let mut message = request(None);
+    assert_eq!(Ok(69), message.decrement_max_forwards());
+    assert_eq!(vec![&Header::MaxForwards(69)], message.headers_named("Max-Forwards").collect::<Vec<_>>());
+}
+
+#[test]
+fn above_range() {
+    let mut message = request(Some(1000));
+    assert_eq!(Ok(255), message.decrement_max_forwards());
+    assert_eq!(Some(&Header::MaxForwards(255)), message.header("Max-Forwards"));
+}
@@ -22,7 +22,7 @@ fn read_complex() {
     let remains = vec![];
     let uri = Uri::sip(domain!("example.com"))
                .auth(uri_auth!("user"))
-               .parameter(UriParam::RPort)
+               .parameter(UriParam::RPort(None))
                .parameter(UriParam::Other("new".into(), None))
                .parameter(UriParam::Other("Some".into(), Some("Param".into())))
                .parameter(UriParam::Other("Other".into(), None));
@@ -38,3 +38,215 @@ fn read_complex() {
         parse_message::<VerboseError<&[u8]>>(b"REGISTER sip:user@example.com;rport;new;Some=Param;Other SIP/2.0\r\nExpires: 10\r\nContent-Length: 5\r\n\r\n66666")
     );
 }
+
+#[test]
+fn update_content_length() {
+    let uri = Uri::sip(domain!("example.com"));
+    let mut req = RequestGenerator::new()
+        .uri(uri)
+        .method(Method::Message)
+        .headers(vec![Header::ContentLength(2), Header::Expires(10)])
+        .body(b"Hello World!".to_vec())
+        .build()
+        .unwrap();
+    req.update_content_length();
+    assert_eq!(
+        &Headers(vec![Header::ContentLength(12), Header::Expires(10)]),
+        req.headers()
+    );
+
+    let mut res = ResponseGenerator::new()
+        .code(200)
+        .body(b"v=0".to_vec())
+        .build()
+        .unwrap();
+    res.update_content_length();
+    assert_eq!(&Headers(vec![Header::ContentLength(3)]), res.headers());
+}
+
+#[test]
+fn read_ordered_headers() {
+    let input = b"SIP/2.0 486 Busy Here\r\nVia: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\nExpires: 10\r\nVia: SIP/2.0/UDP bigbox3.site3.atlanta.com;branch=z9hG4bK77ef4c2312983.1\r\nExpires: 20\r\nContent-Length: 0\r\n\r\n";
+    let res = parse_message::<VerboseError<&[u8]>>(input).unwrap().1;
+    assert_eq!(Some(486), res.status_code());
+    assert_eq!(Some("Busy Here"), res.reason());
+    let headers = &res.headers().0;
+    assert_eq!(5, headers.len());
+    assert!(matches!(headers[0], Header::Via(_)));
+    assert_eq!(Header::Expires(10), headers[1]);
+    assert!(matches!(headers[2], Header::Via(_)));
+    assert_eq!(Header::Expires(20), headers[3]);
+}
+
+#[test]
+fn read_body_content_length() {
+    let input = b"MESSAGE sip:user@example.com SIP/2.0\r\nContent-Length: 5\r\n\r\nHelloSIP/2.0 200 OK\r\n\r\n";
+    let (remains, req) = parse_message::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(&b"Hello".to_vec(), req.body());
+    assert_eq!(b"SIP/2.0 200 OK\r\n\r\n".as_ref(), remains);
+
+    assert!(parse_message::<VerboseError<&[u8]>>(b"MESSAGE sip:user@example.com SIP/2.0\r\nContent-Length: 10\r\n\r\nHello").is_err());
+}
+
+#[test]
+fn reason_phrase() {
+    let res = ResponseGenerator::new().code(200).reason("Okie Dokie").build().unwrap();
+    assert_eq!(Some("Okie Dokie"), res.reason());
+    assert_eq!("SIP/2.0 200 Okie Dokie\r\n\r\n".to_string(), format!("{}", res));
+    assert_eq!(
+        Ok((&b""[..], res)),
+        parse_message::<VerboseError<&[u8]>>(b"SIP/2.0 200 Okie Dokie\r\n\r\n")
+    );
+}
+
+#[test]
+fn to_bytes() {
+    let uri = Uri::sip(domain!("example.com"));
+    let req = RequestGenerator::new()
+        .uri(uri)
+        .method(Method::Message)
+        .header(Header::ContentLength(3))
+        .body(vec![0xff, 0x00, 0xfe])
+        .build()
+        .unwrap();
+    let bytes = req.to_bytes();
+    assert_eq!(
+        b"MESSAGE sip:example.com SIP/2.0\r\nContent-Length: 3\r\n\r\n\xff\x00\xfe".to_vec(),
+        bytes
+    );
+    assert_eq!(Ok((&b""[..], req)), parse_message::<VerboseError<&[u8]>>(&bytes));
+}
+
+#[test]
+fn read_compact_forms() {
+    let long = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:alice@pc33.atlanta.com>\r\n\
+Subject: Lunch\r\n\
+Supported: 100rel, timer\r\n\
+Content-Encoding: gzip\r\n\
+Content-Type: application/sdp\r\n\
+Content-Length: 4\r\n\r\nv=0\n";
+    let compact = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+v: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+t: Bob <sip:bob@biloxi.com>\r\n\
+f: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+i: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+m: <sip:alice@pc33.atlanta.com>\r\n\
+s: Lunch\r\n\
+k: 100rel, timer\r\n\
+e: gzip\r\n\
+c: application/sdp\r\n\
+l: 4\r\n\r\nv=0\n";
+    let long = parse_message::<VerboseError<&[u8]>>(long).unwrap();
+    let compact = parse_message::<VerboseError<&[u8]>>(compact).unwrap();
+    assert_eq!(long, compact);
+    assert_eq!(11, compact.1.headers().0.len());
+    assert!(compact.1.headers().iter().all(|h| !matches!(h, Header::Other(_, _))));
+    assert_eq!(format!("{}", long.1), format!("{}", compact.1));
+}
+
+#[test]
+fn read_folded_headers() {
+    let separate = b"SIP/2.0 200 OK\r\n\
+Via: SIP/2.0/UDP server10.biloxi.com;branch=z9hG4bKnashds8\r\n\
+Via: SIP/2.0/UDP bigbox3.site3.atlanta.com;branch=z9hG4bK77ef4c2312983.1\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Record-Route: <sip:server10.biloxi.com;lr>\r\n\
+Record-Route: <sip:bigbox3.site3.atlanta.com;lr>\r\n\
+Contact: <sip:bob@192.0.2.4>\r\n\
+Contact: \"Bob, Jr\" <sip:bob@biloxi.com>\r\n\
+Allow: INVITE\r\n\
+Allow: ACK, UPDATE\r\n\
+Content-Length: 0\r\n\r\n";
+    let joined = b"SIP/2.0 200 OK\r\n\
+Via: SIP/2.0/UDP server10.biloxi.com;branch=z9hG4bKnashds8, SIP/2.0/UDP bigbox3.site3.atlanta.com;branch=z9hG4bK77ef4c2312983.1 ,SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Record-Route: <sip:server10.biloxi.com;lr>, <sip:bigbox3.site3.atlanta.com;lr>\r\n\
+Contact: <sip:bob@192.0.2.4>, \"Bob, Jr\" <sip:bob@biloxi.com>\r\n\
+Allow: INVITE, ACK, UPDATE\r\n\
+Content-Length: 0\r\n\r\n";
+    let separate = parse_message::<VerboseError<&[u8]>>(separate).unwrap().1;
+    let joined = parse_message::<VerboseError<&[u8]>>(joined).unwrap().1;
+
+    assert_eq!(separate.headers().vias(), joined.headers().vias());
+    assert_eq!(3, joined.headers().vias().len());
+    assert_eq!(Some("z9hG4bK776asdhds"), joined.headers().vias()[2].branch());
+    assert_eq!(separate.headers().record_routes(), joined.headers().record_routes());
+    assert_eq!(2, joined.headers().record_routes().len());
+    assert_eq!(separate.headers().contacts(), joined.headers().contacts());
+    assert_eq!(Some(&"Bob, Jr".to_string()), joined.headers().contacts()[1].display_name.as_ref());
+    assert_eq!(separate.headers().allowed_methods(), joined.headers().allowed_methods());
+    assert!(joined.headers().allowed_methods().contains(&&Method::Update));
+}
+
+#[test]
+fn read_mixed_case_headers() {
+    let input = b"SIP/2.0 200 OK\r\n\
+vIA: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+cONTact: <sip:bob@192.0.2.4>\r\n\
+CALL-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+cseq: 314159 INVITE\r\n\
+MAX-FORWARDS: 70\r\n\
+expires: 60\r\n\
+X-Vendor-ID: Mixed Case\r\n\
+content-LENGTH: 0\r\n\r\n";
+    let message = parse_message::<VerboseError<&[u8]>>(input).unwrap().1;
+    let headers = &message.headers().0;
+    assert!(matches!(headers[0], Header::Via(_)));
+    assert!(matches!(headers[1], Header::Contact(_)));
+    assert_eq!(Header::CallId("a84b4c76e66710@pc33.atlanta.com".into()), headers[2]);
+    assert_eq!(Header::CSeq(314159, Method::Invite), headers[3]);
+    assert_eq!(Header::MaxForwards(70), headers[4]);
+    assert_eq!(Header::Expires(60), headers[5]);
+    assert_eq!(Header::Other("X-Vendor-ID".into(), "Mixed Case".into()), headers[6]);
+    assert_eq!(Header::ContentLength(0), headers[7]);
+}
+
+#[test]
+fn accessors() {
+    let input = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+v: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+i: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+l: 0\r\n\r\n";
+    let request = parse_message::<VerboseError<&[u8]>>(input).unwrap().1;
+    assert!(request.is_request());
+    assert!(!request.is_response());
+    assert_eq!(Some(&Method::Invite), request.method());
+    assert_eq!(None, request.status_code());
+    assert_eq!(Some(&Header::CallId("a84b4c76e66710@pc33.atlanta.com".into())), request.header("call-id"));
+    assert_eq!(Some(&Header::ContentLength(0)), request.header("L"));
+    assert!(matches!(request.header("Via"), Some(Header::Via(_))));
+    assert_eq!(None, request.header("Contact"));
+
+    let response = request.make_response(180, None);
+    assert!(response.is_response());
+    assert_eq!(None, response.method());
+    assert_eq!(Some(180), response.status_code());
+    assert_eq!(Some(&Header::CSeq(314159, Method::Invite)), response.header("cseq"));
+}
+
+#[test]
+fn headers_named() {
+    let input = b"SIP/2.0 200 OK\r\n\
+Via: SIP/2.0/UDP server10.biloxi.com;branch=z9hG4bKnashds8\r\n\
+Record-Route: <sip:server10.biloxi.com;lr>\r\n\
+v: SIP/2.0/UDP bigbox3.site3.atlanta.com;branch=z9hG4bK77ef4c2312983.1\r\n\
+VIA: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+CSeq: 314159 INVITE\r\n\
+Content-Length: 0\r\n\r\n";
+    let response = parse_message::<VerboseError<&[u8]>>(input).unwrap().1;
+    let branches: Vec<Option<&str>> = response.headers_named("via").map(|h| match h {
+        Header::Via(via) => via.branch(),
+        _ => panic!("expected a Via header"),
+    }).collect();
+    assert_eq!(vec![Some("z9hG4bKnashds8"), Some("z9hG4bK77ef4c2312983.1"), Some("z9hG4bK776asdhds")], branches);
+    assert_eq!(3, response.headers_named("v").count());
+    assert_eq!(1, response.headers_named("Record-Route").count());
+    assert_eq!(0, response.headers_named("Route").count());
+}
@@ -0,0 +1,35 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+#[test]
+fn write_method() {
+    assert_eq!("INVITE".to_string(), format!("{}", Method::Invite));
+    assert_eq!("PRACK".to_string(), format!("{}", Method::PRack));
+    assert_eq!("FOOBAR".to_string(), format!("{}", Method::Extension("FOOBAR".into())));
+}
+
+#[test]
+fn read_method() {
+    let remains = vec![b' '];
+    assert_eq!(
+        Ok((remains.as_ref(), Method::Subscribe)),
+        parse_method::<VerboseError<&[u8]>>(b"subscribe ")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), Method::Extension("FOOBAR".into()))),
+        parse_method::<VerboseError<&[u8]>>(b"FOOBAR ")
+    );
+}
+
+#[test]
+fn from_str() {
+    for method in Method::all() {
+        assert_eq!(method, format!("{}", method).parse().unwrap());
+        assert_eq!(method, format!("{}", method).to_lowercase().parse().unwrap());
+    }
+    assert_eq!(Method::Invite, "Invite".parse().unwrap());
+    assert_eq!(Method::Extension("foo".into()), "foo".parse().unwrap());
+    assert!("".parse::<Method>().is_err());
+    assert!("IN VITE".parse::<Method>().is_err());
+}
@@ -1,4 +1,19 @@
+mod ack;
+mod cancel;
+mod code;
+mod error;
+mod framer;
+mod from_str;
+mod line;
+mod max_forwards;
 mod message;
+mod method;
 mod request;
 mod response;
+mod route;
+mod transaction;
+mod transport;
+mod try_from;
+mod validate;
 mod version;
+mod view;
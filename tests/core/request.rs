@@ -61,3 +61,78 @@ fn read_complex() {
         .unwrap();
     assert_eq!(Ok((remains.as_ref(), req)), parse_request::<VerboseError<&[u8]>>(b"REGISTER sip:user@example.com SIP/2.0\r\nExpires: 10\r\nContent-Length: 5\r\n\r\n66666"));
 }
+
+fn invite_builder() -> RequestBuilder {
+    RequestBuilder::new(Method::Invite, Uri::sip(domain!("biloxi.com")).auth(uri_auth!("bob")))
+        .via(ViaHeader::new(Uri::new_schemaless(domain!("pc33.atlanta.com")), Transport::Udp).with_branch("z9hG4bK776asdhds"))
+        .max_forwards(70)
+        .to(NamedHeader::new(Uri::sip(domain!("biloxi.com")).auth(uri_auth!("bob"))).name("Bob"))
+        .from(NamedHeader::new(Uri::sip(domain!("atlanta.com")).auth(uri_auth!("alice"))).name("Alice").with_tag("1928301774"))
+        .call_id("a84b4c76e66710@pc33.atlanta.com")
+        .cseq(314159)
+}
+
+#[test]
+fn write_builder() {
+    let req = invite_builder()
+        .header(Header::ContentType(MediaType::new("application", "sdp")))
+        .body(b"v=0\r\n".to_vec())
+        .build()
+        .unwrap();
+    assert_eq!(
+        "INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+         Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+         Max-Forwards: 70\r\n\
+         To: Bob <sip:bob@biloxi.com>\r\n\
+         From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+         Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+         CSeq: 314159 INVITE\r\n\
+         Content-Type: application/sdp\r\n\
+         Content-Length: 5\r\n\r\n\
+         v=0\r\n"
+            .to_string(),
+        format!("{}", req)
+    );
+}
+
+#[test]
+fn builder_replaces_content_length() {
+    let req = invite_builder().header(Header::ContentLength(100)).build().unwrap();
+    assert_eq!(Some(Header::ContentLength(0)), req.headers().content_length());
+    assert_eq!(1, req.headers().iter().filter(|h| matches!(h, Header::ContentLength(_))).count());
+}
+
+#[test]
+fn builder_missing_headers() {
+    let uri = Uri::sip(domain!("biloxi.com"));
+    let err = RequestBuilder::new(Method::Options, uri.clone()).build().unwrap_err();
+    assert_eq!("RequestBuilder requires a Via header", err.to_string());
+
+    let err = RequestBuilder::new(Method::Options, uri)
+        .via(ViaHeader::new(Uri::new_schemaless(domain!("pc33.atlanta.com")), Transport::Udp))
+        .max_forwards(70)
+        .to(NamedHeader::new(Uri::sip(domain!("biloxi.com"))))
+        .from(NamedHeader::new(Uri::sip(domain!("atlanta.com"))))
+        .cseq(1)
+        .build()
+        .unwrap_err();
+    assert_eq!("RequestBuilder requires a Call-ID header", err.to_string());
+}
+
+#[test]
+fn builder_default_max_forwards() {
+    let uri = Uri::sip(domain!("biloxi.com"));
+    let builder = || RequestBuilder::new(Method::Options, uri.clone())
+        .via(ViaHeader::new(Uri::new_schemaless(domain!("pc33.atlanta.com")), Transport::Udp))
+        .to(NamedHeader::new(Uri::sip(domain!("biloxi.com"))))
+        .from(NamedHeader::new(Uri::sip(domain!("atlanta.com"))))
+        .call_id("a84b4c76e66710@pc33.atlanta.com")
+        .cseq(1);
+    let req = builder().build().unwrap();
+    assert_eq!(vec![&Header::MaxForwards(70)], req.headers_named("Max-Forwards").collect::<Vec<_>>());
+
+    let req = builder().max_forwards(10).build().unwrap();
+    assert_eq!(vec![&Header::MaxForwards(10)], req.headers_named("Max-Forwards").collect::<Vec<_>>());
+    let req = builder().header(Header::MaxForwards(0)).build().unwrap();
+    assert_eq!(vec![&Header::MaxForwards(0)], req.headers_named("Max-Forwards").collect::<Vec<_>>());
+}
@@ -48,3 +48,64 @@ fn read_complex() {
         parse_response::<VerboseError<&[u8]>>(b"SIP/2.0 180 Ringing\r\nExpires: 10\r\nContent-Length: 5\r\n\r\n55555")
     );
 }
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP server10.biloxi.com;branch=z9hG4bK4b43c2ff8.1\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 69\r\n\
+Record-Route: <sip:server10.biloxi.com;lr>\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Contact: <sip:alice@pc33.atlanta.com>\r\n\
+Content-Length: 4\r\n\r\nv=0\n";
+
+#[test]
+fn make_response_ok() {
+    let (_, invite) = parse_message::<VerboseError<&[u8]>>(INVITE).unwrap();
+    let response = invite.make_response(200, None);
+    assert_eq!(Some(200), response.status_code());
+    assert_eq!(Some("OK"), response.reason());
+    assert!(response.body().is_empty());
+
+    let vias: Vec<String> = response.headers().vias().iter().map(|v| v.to_string()).collect();
+    let expected: Vec<String> = invite.headers().vias().iter().map(|v| v.to_string()).collect();
+    assert_eq!(expected, vias);
+    assert_eq!(invite.headers().from(), response.headers().from());
+    assert_eq!(invite.headers().call_id(), response.headers().call_id());
+    assert_eq!(invite.headers().cseq(), response.headers().cseq());
+    assert_eq!(invite.headers().record_routes(), response.headers().record_routes());
+    assert_eq!(None, response.headers().contact());
+    assert_eq!(Some(Header::ContentLength(0)), response.headers().content_length());
+
+    match response.headers().to() {
+        Some(Header::To(to)) => {
+            assert!(to.tag().is_some());
+            assert_eq!(Some("Bob"), to.display_name.as_deref());
+        },
+        _ => panic!("response is missing its To header"),
+    }
+}
+
+#[test]
+fn make_response_provisional() {
+    let (_, invite) = parse_message::<VerboseError<&[u8]>>(INVITE).unwrap();
+    let response = invite.make_response(100, Some("Trying"));
+    assert_eq!(Some("Trying"), response.reason());
+    assert_eq!(invite.headers().to(), response.headers().to());
+    assert!(response.headers().record_routes().is_empty());
+    assert_eq!(2, response.headers().vias().len());
+}
+
+#[test]
+fn make_response_keeps_to_tag() {
+    let (_, mut invite) = parse_message::<VerboseError<&[u8]>>(INVITE).unwrap();
+    for header in invite.headers_mut().0.iter_mut() {
+        if let Header::To(to) = header {
+            *to = to.clone().with_tag("a6c85cf");
+        }
+    }
+    let response = invite.make_response(200, None);
+    assert_eq!(invite.headers().to(), response.headers().to());
+}
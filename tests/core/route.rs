@@ -0,0 +1,68 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+fn request(routes: &str) -> SipMessage {
+    let input = format!("INVITE sip:callee@u2.domain.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+{}\
+To: <sip:callee@domain.com>\r\n\
+From: <sip:caller@u1.example.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 1 INVITE\r\n\
+Content-Length: 0\r\n\r\n", routes);
+    parse_message::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1
+}
+
+fn request_uri(message: &SipMessage) -> String {
+    match message {
+        SipMessage::Request { uri, .. } => uri.to_string(),
+        SipMessage::Response { .. } => panic!("expected a request"),
+    }
+}
+
+fn route_uris(message: &SipMessage) -> Vec<String> {
+    message.headers().routes().iter().map(|route| route.uri.to_string()).collect()
+}
+
+#[test]
+fn no_route() {
+    let mut message = request("");
+    let decision = route_request(&mut message).unwrap();
+    assert_eq!("sip:callee@u2.domain.com", decision.next_hop().to_string());
+    assert!(matches!(decision, RouteDecision::RequestUri(_)));
+}
+
+#[test]
+fn loose_route() {
+    let mut message = request("Route: <sip:p2.domain.com;lr>, <sip:p3.domain.com;lr>\r\n");
+    let original = message.clone();
+    let decision = route_request(&mut message).unwrap();
+    assert_eq!(RouteDecision::LooseRoute(Uri::sip(domain!("p2.domain.com")).parameter(UriParam::Lr)), decision);
+    assert_eq!(original, message);
+}
+
+#[test]
+fn strict_route() {
+    let mut message = request("Route: <sip:p2.domain.com>\r\nRoute: <sip:p3.domain.com;lr>\r\n");
+    let decision = route_request(&mut message).unwrap();
+    assert_eq!(RouteDecision::StrictRoute(Uri::sip(domain!("p2.domain.com"))), decision);
+    assert_eq!("sip:p2.domain.com", request_uri(&message));
+    assert_eq!(vec!["sip:p3.domain.com;lr", "sip:callee@u2.domain.com"], route_uris(&message));
+    assert_eq!(1, message.headers().iter().filter(|h| matches!(h, Header::Route(_))).count());
+}
+
+#[test]
+fn strict_route_last() {
+    let mut message = request("Route: <sip:p2.domain.com;transport=tcp>\r\n");
+    let decision = route_request(&mut message).unwrap();
+    assert_eq!("sip:p2.domain.com;transport=tcp", decision.next_hop().to_string());
+    assert_eq!(vec!["sip:callee@u2.domain.com"], route_uris(&message));
+}
+
+#[test]
+fn response() {
+    let mut message = request("").make_response(200, None);
+    assert!(route_request(&mut message).is_err());
+}
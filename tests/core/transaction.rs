@@ -0,0 +1,56 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+use std::collections::HashMap;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Content-Length: 0\r\n\r\n";
+
+fn parse(input: &[u8]) -> SipMessage {
+    parse_message::<VerboseError<&[u8]>>(input).unwrap().1
+}
+
+#[test]
+fn request_and_response() {
+    let invite = parse(INVITE);
+    let id = invite.transaction_id().unwrap();
+    assert_eq!("z9hG4bK776asdhds", id.branch);
+    assert_eq!("pc33.atlanta.com", id.sent_by);
+    assert_eq!(Method::Invite, id.method);
+
+    let response = invite.make_response(486, None);
+    assert_eq!(Some(id.clone()), response.transaction_id());
+
+    let mut transactions = HashMap::new();
+    transactions.insert(id, "INVITE");
+    assert_eq!(Some(&"INVITE"), transactions.get(&response.transaction_id().unwrap()));
+}
+
+#[test]
+fn ack_and_cancel() {
+    let invite = parse(INVITE);
+    let busy = invite.make_response(486, None);
+    let ack = invite.make_ack(&busy).unwrap();
+    assert_eq!(invite.transaction_id(), ack.transaction_id());
+
+    let cancel = invite.make_cancel().unwrap();
+    let id = cancel.transaction_id().unwrap();
+    assert_eq!(Method::Cancel, id.method);
+    assert_ne!(invite.transaction_id(), Some(id));
+}
+
+#[test]
+fn missing_branch() {
+    let input = b"OPTIONS sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com\r\n\
+CSeq: 1 OPTIONS\r\n\
+Content-Length: 0\r\n\r\n";
+    assert_eq!(None, parse(input).transaction_id());
+}
@@ -0,0 +1,55 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+#[test]
+fn transport_round_trip() {
+    let cases = [
+        (Transport::Udp, "UDP", "udp", 5060),
+        (Transport::Tcp, "TCP", "tcp", 5060),
+        (Transport::Tls, "TLS", "tls", 5061),
+        (Transport::Sctp, "SCTP", "sctp", 5060),
+        (Transport::Ws, "WS", "ws", 80),
+        (Transport::Wss, "WSS", "wss", 443),
+    ];
+    for (transport, via, param, port) in cases.iter() {
+        assert_eq!(*via, format!("{}", transport));
+        assert_eq!(*param, transport.param_value());
+        assert_eq!(*port, transport.default_port());
+        assert_eq!(Ok((&b""[..], *transport)), parse_transport::<VerboseError<&[u8]>>(via.as_bytes()));
+        assert_eq!(Ok((&b""[..], *transport)), parse_transport::<VerboseError<&[u8]>>(via.to_lowercase().as_bytes()));
+
+        let input = format!("sip:example.com;transport={}", param);
+        let uri = parse_uri::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+        assert_eq!(Some(*transport), uri.transport());
+        assert_eq!(input, format!("{}", uri));
+    }
+}
+
+#[test]
+fn transport_param_case_insensitive() {
+    let cases = [
+        ("udp", Transport::Udp),
+        ("UDP", Transport::Udp),
+        ("Udp", Transport::Udp),
+        ("tcp", Transport::Tcp),
+        ("TCP", Transport::Tcp),
+        ("Tls", Transport::Tls),
+        ("WSS", Transport::Wss),
+    ];
+    for (param, transport) in cases.iter() {
+        for key in ["transport", "Transport", "TRANSPORT"].iter() {
+            let input = format!("sip:example.com;{}={}", key, param);
+            let uri = parse_uri::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+            assert_eq!(Some(*transport), uri.transport(), "{}", input);
+            assert_eq!(format!("sip:example.com;transport={}", transport.param_value()), format!("{}", uri));
+        }
+    }
+}
+
+#[test]
+fn unknown_transport_param() {
+    let uri = parse_uri::<VerboseError<&[u8]>>(b"sip:example.com;transport=udpx").unwrap().1;
+    assert_eq!(None, uri.transport());
+    assert_eq!("sip:example.com;transport=udpx", format!("{}", uri));
+}
@@ -0,0 +1,37 @@
+use libsip::*;
+
+use std::convert::TryFrom;
+
+const MESSAGE: &[u8] = b"MESSAGE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+CSeq: 1 MESSAGE\r\n\
+Content-Length: 5\r\n\r\n\
+hello";
+
+#[test]
+fn complete_datagram() {
+    let message = SipMessage::try_from(MESSAGE).unwrap();
+    assert_eq!(Some(&Method::Message), message.method());
+    assert_eq!(b"hello", &message.body()[..]);
+}
+
+#[test]
+fn trailing_data() {
+    let mut input = MESSAGE.to_vec();
+    input.extend_from_slice(b"\r\nSIP/2.0");
+    assert_eq!(Err(MessageError::TrailingData(9)), SipMessage::try_from(&input[..]));
+}
+
+#[test]
+fn incomplete() {
+    assert_eq!(Err(MessageError::Incomplete), SipMessage::try_from(&MESSAGE[..MESSAGE.len() - 2]));
+    assert_eq!(Err(MessageError::Incomplete), SipMessage::try_from(&MESSAGE[..40]));
+}
+
+#[test]
+fn invalid() {
+    match SipMessage::try_from(&b"INVITE sip:bob@biloxi.com SIP/3\r\n\r\n"[..]) {
+        Err(MessageError::Invalid(err)) => assert_eq!(31, err.offset),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
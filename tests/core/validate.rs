@@ -0,0 +1,64 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Content-Length: 0\r\n\r\n";
+
+fn parse(input: &[u8]) -> SipMessage {
+    parse_message::<VerboseError<&[u8]>>(input).unwrap().1
+}
+
+#[test]
+fn valid_request() {
+    let invite = parse(INVITE);
+    assert_eq!(Ok(()), invite.validate());
+    assert_eq!(Ok(()), invite.make_response(200, None).validate());
+}
+
+#[test]
+fn missing_headers() {
+    let input = b"OPTIONS sip:bob@biloxi.com SIP/2.0\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+CSeq: 1 OPTIONS\r\n\
+Content-Length: 0\r\n\r\n";
+    let err = parse(input).validate().unwrap_err();
+    assert_eq!(vec!["Via", "From", "Call-ID", "Max-Forwards"], err.missing);
+    assert!(err.duplicated.is_empty());
+    assert_eq!("missing headers: Via, From, Call-ID, Max-Forwards", err.to_string());
+}
+
+#[test]
+fn duplicated_headers() {
+    let input = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+i: 77asd@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Content-Length: 0\r\n\r\n";
+    let message = parse(input);
+    let copy = message.clone();
+    let err = message.validate().unwrap_err();
+    assert!(err.missing.is_empty());
+    assert_eq!(vec!["Call-ID"], err.duplicated);
+    assert_eq!("duplicated headers: Call-ID", err.to_string());
+    assert_eq!(copy, message);
+}
+
+#[test]
+fn response() {
+    let input = b"SIP/2.0 200 OK\r\nCSeq: 1 OPTIONS\r\nCSeq: 2 OPTIONS\r\n\r\n";
+    let err = parse(input).validate().unwrap_err();
+    assert_eq!(vec!["Via"], err.missing);
+    assert_eq!(vec!["CSeq"], err.duplicated);
+    assert_eq!("missing headers: Via; duplicated headers: CSeq", err.to_string());
+}
@@ -20,3 +20,14 @@ fn read_version() {
         parse_version::<VerboseError<&[u8]>>(b"SIP/1.1 ")
     );
 }
+
+#[test]
+fn read_future_version() {
+    let (_, version) = parse_version::<VerboseError<&[u8]>>(b"SIP/2.1 ").unwrap();
+    assert_eq!(Version { major: 2, minor: 1 }, version);
+    assert_eq!(1, version.minor);
+    assert_eq!("SIP/2.1", version.to_string());
+
+    assert!(parse_version::<VerboseError<&[u8]>>(b"HTTP/1.1 ").is_err());
+    assert!(parse_version::<VerboseError<&[u8]>>(b"SIP/2 ").is_err());
+}
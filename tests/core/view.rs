@@ -0,0 +1,65 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+const INVITE: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n\
+Max-Forwards: 70\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+f: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+CSeq: 314159 INVITE\r\n\
+Subject: long\r\n folded value\r\n\
+Content-Length: 4\r\n\r\nv=0\nSIP/2.0 200 OK\r\n\r\n";
+
+#[test]
+fn read_message_view() {
+    let (rest, view) = parse_message_view::<VerboseError<&[u8]>>(INVITE).unwrap();
+    assert_eq!(b"SIP/2.0 200 OK\r\n\r\n", rest);
+    assert!(view.is_request());
+    assert_eq!(Some("INVITE"), view.method());
+    assert_eq!(Some("sip:bob@biloxi.com"), view.request_uri());
+    assert_eq!(b"v=0\n", view.body());
+    assert_eq!(8, view.headers().count());
+    assert_eq!(Some("70"), view.header("max-forwards").map(|h| h.value()));
+    assert_eq!(Some("long\r\n folded value"), view.header("Subject").map(|h| h.value()));
+
+    let from = view.header("From").unwrap();
+    assert_eq!("f", from.name());
+    assert_eq!("Alice <sip:alice@atlanta.com>;tag=1928301774", from.value());
+    assert_eq!(Some("1928301774".to_string()), from.to_header().ok().and_then(|h| match h {
+        Header::From(from) => from.tag().map(String::from),
+        _ => None
+    }));
+    assert!(view.header("v").unwrap().is("Via"));
+
+    let (_, response) = parse_message_view::<VerboseError<&[u8]>>(rest).unwrap();
+    assert!(response.is_response());
+    assert_eq!(Some(200), response.status_code());
+    assert!(response.body().is_empty());
+}
+
+#[test]
+fn view_to_message() {
+    let input = b"MESSAGE sip:bob@biloxi.com SIP/2.0\r\n\
+To: Bob <sip:bob@biloxi.com>\r\n\
+Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n\
+Content-Length: 5\r\n\r\nhello";
+    let (_, view) = parse_message_view::<VerboseError<&[u8]>>(input).unwrap();
+    let (_, message) = parse_message::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(message, view.to_message().unwrap());
+    assert_eq!(&input[..], view.as_bytes());
+
+    let input = b"SIP/2.0 180 Ringing\r\n\
+Via: SIP/2.0/UDP a.atlanta.com;branch=z9hG4bK1, SIP/2.0/UDP b.atlanta.com;branch=z9hG4bK2\r\n\
+Content-Length: 0\r\n\r\n";
+    let (_, view) = parse_message_view::<VerboseError<&[u8]>>(input).unwrap();
+    let (_, message) = parse_message::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(message, view.to_message().unwrap());
+}
+
+#[test]
+fn read_message_view_truncated() {
+    assert!(parse_message_view::<VerboseError<&[u8]>>(&INVITE[..INVITE.len() - 21]).is_err());
+    assert!(parse_message_view::<VerboseError<&[u8]>>(b"INVITE sip:bob@biloxi.com SIP/2.0\r\nTo\r\n\r\n").is_err());
+}
@@ -1,25 +1,25 @@
-use libsip::{
-    headers::{parse::parse_accept_encoding_header, ContentType},
-    Header,
-};
+use libsip::{headers::parse::parse_accept_encoding_header, AcceptValue, Header, QValue};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::AcceptEncoding(ContentType::Sdp);
-    assert_eq!(
-        "Accept-Encoding: application/sdp".to_string(),
-        format!("{}", header)
-    );
+    let header = Header::AcceptEncoding(vec![
+        AcceptValue::new("gzip"),
+        AcceptValue::new("identity").with_q(QValue::new(100).unwrap()),
+    ]);
+    assert_eq!("Accept-Encoding: gzip, identity;q=0.1".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::AcceptEncoding(ContentType::Sdp);
+    let header = Header::AcceptEncoding(vec![
+        AcceptValue::new("gzip").with_q(QValue::new(1000).unwrap()),
+        AcceptValue::new("*").with_q(QValue::new(0).unwrap()),
+    ]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_accept_encoding_header::<VerboseError<&[u8]>>(b"Accept-Encoding: application/sdp")
+        parse_accept_encoding_header::<VerboseError<&[u8]>>(b"Accept-Encoding: gzip;q=1.000, *;q=0.0\r\n")
     );
 }
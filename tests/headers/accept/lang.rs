@@ -1,22 +1,27 @@
-use libsip::{
-    headers::{parse::parse_accept_language_header, Language},
-    Header,
-};
+use libsip::{headers::parse::parse_accept_language_header, AcceptValue, Header, QValue};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::AcceptLanguage(Language::English);
-    assert_eq!("Accept-Language: en".to_string(), format!("{}", header));
+    let header = Header::AcceptLanguage(vec![
+        AcceptValue::new("da"),
+        AcceptValue::new("en-gb").with_q(QValue::new(800).unwrap()),
+        AcceptValue::new("en").with_q(QValue::new(700).unwrap()),
+    ]);
+    assert_eq!("Accept-Language: da, en-gb;q=0.8, en;q=0.7".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::AcceptLanguage(Language::English);
+    let header = Header::AcceptLanguage(vec![
+        AcceptValue::new("da"),
+        AcceptValue::new("en-gb").with_q(QValue::new(800).unwrap()),
+        AcceptValue::new("en").with_q(QValue::new(700).unwrap()),
+    ]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_accept_language_header::<VerboseError<&[u8]>>(b"Accept-Language: en")
+        parse_accept_language_header::<VerboseError<&[u8]>>(b"Accept-Language: da, en-gb;q=0.8, en;q=0.7\r\n")
     );
 }
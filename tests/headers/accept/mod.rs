@@ -7,16 +7,53 @@ use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::Accept(vec![Method::Invite, Method::Options]);
-    assert_eq!("Accept: INVITE,OPTIONS".to_string(), format!("{}", header));
+    let header = Header::Accept(vec![
+        MediaRange::new("application", "sdp"),
+        MediaRange::new("text", "html").with_q(QValue::new(500).unwrap()),
+    ]);
+    assert_eq!("Accept: application/sdp, text/html;q=0.5".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Accept(vec![Method::Register, Method::Invite]);
+    let mut multipart = MediaRange::new("multipart", "mixed").with_q(QValue::new(0).unwrap());
+    multipart.params.push(("level".into(), Some("1".into())));
+    let header = Header::Accept(vec![
+        MediaRange::new("application", "sdp"),
+        MediaRange::new("application", "*").with_q(QValue::new(125).unwrap()),
+        multipart,
+    ]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_accept_header::<VerboseError<&[u8]>>(b"Accept: REGISTER, INVITE\r\n")
+        parse_accept_header::<VerboseError<&[u8]>>(b"Accept: application/sdp, application/*;q=0.125 ,multipart/mixed;level=1; q=0\r\n")
     );
 }
+
+#[test]
+fn read_empty() {
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Accept(vec![]))),
+        parse_accept_header::<VerboseError<&[u8]>>(b"Accept: \r\n")
+    );
+}
+
+#[test]
+fn read_invalid_qvalue() {
+    assert!(parse_accept_header::<VerboseError<&[u8]>>(b"Accept: application/sdp;q=1.5\r\n").is_err());
+    assert!(parse_accept_header::<VerboseError<&[u8]>>(b"Accept: application/sdp;q=0.1234\r\n").is_err());
+    assert!(parse_accept_header::<VerboseError<&[u8]>>(b"Accept: application/sdp;q=high\r\n").is_err());
+}
+
+#[test]
+fn sort_by_q() {
+    let input = b"Accept: text/html;q=0.5, application/sdp, text/plain;q=0.75\r\n";
+    let mut ranges = match parse_accept_header::<VerboseError<&[u8]>>(input).unwrap().1 {
+        Header::Accept(ranges) => ranges,
+        _ => panic!("expected Accept header"),
+    };
+    ranges.sort_by_key(|range| std::cmp::Reverse(range.q()));
+    let subtypes: Vec<&str> = ranges.iter().map(|r| r.subtype.as_str()).collect();
+    assert_eq!(vec!["sdp", "plain", "html"], subtypes);
+}
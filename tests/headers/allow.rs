@@ -4,10 +4,12 @@ use libsip::headers::parse::parse_allow_header;
 
 use nom::error::VerboseError;
 
+use crate::headers::assert_round_trip;
+
 #[test]
 fn write() {
     let header = Header::Allow(vec![Method::Invite, Method::Options]);
-    assert_eq!("Allow: INVITE,OPTIONS".to_string(), format!("{}", header));
+    assert_eq!("Allow: INVITE, OPTIONS".to_string(), format!("{}", header));
 }
 
 #[test]
@@ -19,3 +21,38 @@ fn read() {
         parse_allow_header::<VerboseError<&[u8]>>(b"Allow: REGISTER,INVITE\r\n")
     );
 }
+
+#[test]
+fn read_extension() {
+    let remains = vec![];
+    let header = Header::Allow(vec![
+        Method::Invite,
+        Method::Ack,
+        Method::Bye,
+        Method::Cancel,
+        Method::Options,
+        Method::Extension("FOO".into()),
+    ]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_allow_header::<VerboseError<&[u8]>>(b"Allow: INVITE, ACK, BYE , CANCEL,OPTIONS, FOO\r\n")
+    );
+}
+
+#[test]
+fn read_empty() {
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Allow(vec![]))),
+        parse_allow_header::<VerboseError<&[u8]>>(b"Allow:\r\n")
+    );
+    assert_eq!("Allow: ".to_string(), format!("{}", Header::Allow(vec![])));
+}
+
+#[test]
+fn round_trip() {
+    let header = assert_round_trip("Allow: INVITE, ACK, BYE, CANCEL, OPTIONS, UPDATE");
+    if let Header::Allow(methods) = header {
+        assert!(methods.contains(&Method::Update));
+    }
+}
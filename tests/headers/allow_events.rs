@@ -0,0 +1,58 @@
+use libsip::{headers::parse::parse_allow_events_header, parse_message, Header};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::AllowEvents(vec!["presence".into(), "dialog".into()]);
+    assert_eq!(
+        "Allow-Events: presence, dialog".to_string(),
+        format!("{}", header)
+    );
+    assert_eq!("Allow-Events: ".to_string(), format!("{}", Header::AllowEvents(vec![])));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::AllowEvents(vec!["presence".into(), "dialog".into(), "message-summary".into()]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_allow_events_header::<VerboseError<&[u8]>>(b"Allow-Events: presence, dialog, message-summary\r\n")
+    );
+}
+
+#[test]
+fn read_compact() {
+    let remains = vec![];
+    let header = Header::AllowEvents(vec!["refer".into()]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_allow_events_header::<VerboseError<&[u8]>>(b"u: refer\r\n")
+    );
+}
+
+#[test]
+fn read_empty() {
+    let remains = vec![];
+    let header = Header::AllowEvents(vec![]);
+    assert_eq!(
+        Ok((remains.as_ref(), header.clone())),
+        parse_allow_events_header::<VerboseError<&[u8]>>(format!("{}\r\n", header).as_bytes())
+    );
+}
+
+#[test]
+fn allowed_events() {
+    let msg = b"OPTIONS sip:bob@biloxi.com SIP/2.0\r\n\
+        Allow: INVITE, SUBSCRIBE\r\n\
+        Allow-Events: presence\r\n\
+        u: dialog\r\n\
+        User-Agent: softphone\r\n\
+        Content-Length: 0\r\n\r\n";
+    let (_, msg) = parse_message::<VerboseError<&[u8]>>(msg).unwrap();
+    let headers = msg.headers();
+    assert_eq!(vec!["presence", "dialog"], headers.allowed_events());
+    assert_eq!(2, headers.allowed_methods().len());
+    assert!(headers.iter().any(|h| h == &Header::UserAgent("softphone".into())));
+}
@@ -0,0 +1,75 @@
+use libsip::*;
+use libsip::headers::parse::{parse_accept_contact_header, parse_reject_contact_header, parse_request_disposition_header};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::AcceptContact(vec![CallerPreference::new().flag("+sip.audio").param("language", "en")]);
+    assert_eq!("Accept-Contact: *;+sip.audio;language=en".to_string(), format!("{}", header));
+    let header = Header::RequestDisposition(vec!["proxy".into(), "recurse".into(), "parallel".into()]);
+    assert_eq!("Request-Disposition: proxy, recurse, parallel".to_string(), format!("{}", header));
+}
+
+#[test]
+fn round_trip() {
+    let input = b"Accept-Contact: *;+sip.audio\r\n";
+    let (_, header) = parse_accept_contact_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(Header::AcceptContact(vec![CallerPreference::new().flag("+sip.audio")]), header);
+    assert_eq!(format!("{}\r\n", header).as_bytes(), &input[..]);
+}
+
+#[test]
+fn read_feature_params() {
+    let input = b"a: *;+sip.audio;language=\"en\";require, *;+sip.automata=\"TRUE\";explicit\r\n";
+    let (_, header) = parse_accept_contact_header::<VerboseError<&[u8]>>(input).unwrap();
+    let prefs = match header {
+        Header::AcceptContact(prefs) => prefs,
+        _ => panic!("expected an Accept-Contact header"),
+    };
+    assert_eq!(2, prefs.len());
+    assert!(prefs[0].has_param("+sip.audio"));
+    assert_eq!(Some("en"), prefs[0].get_param("language"));
+    assert!(prefs[0].require());
+    assert!(!prefs[0].explicit());
+    assert_eq!(Some("TRUE"), prefs[1].get_param("+sip.automata"));
+    assert!(prefs[1].explicit());
+
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), Header::RejectContact(vec![CallerPreference::new().flag("+sip.video")]))),
+        parse_reject_contact_header::<VerboseError<&[u8]>>(b"j: *;+sip.video\r\n")
+    );
+}
+
+#[test]
+fn read_request_disposition() {
+    let remains = vec![];
+    let header = Header::RequestDisposition(vec!["redirect".into(), "no-cancel".into()]);
+    assert_eq!(
+        Ok((remains.as_ref(), header.clone())),
+        parse_request_disposition_header::<VerboseError<&[u8]>>(b"Request-Disposition: redirect, no-cancel\r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_request_disposition_header::<VerboseError<&[u8]>>(b"d: redirect, no-cancel\r\n")
+    );
+}
+
+#[test]
+fn read_in_message() {
+    let input = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+Accept: application/sdp\r\n\
+a: *;+sip.audio\r\n\
+d: proxy\r\n\
+Date: Sat, 13 Nov 2010 23:29:00 GMT\r\n\
+Content-Length: 0\r\n\r\n";
+    let message = parse_message::<VerboseError<&[u8]>>(input).unwrap().1;
+    assert!(matches!(message.header("Accept"), Some(Header::Accept(_))));
+    assert_eq!(
+        Some(&Header::AcceptContact(vec![CallerPreference::new().flag("+sip.audio")])),
+        message.header("accept-contact")
+    );
+    assert_eq!(Some(&Header::RequestDisposition(vec!["proxy".into()])), message.header("d"));
+    assert!(matches!(message.header("Date"), Some(Header::Date(_))));
+}
@@ -21,3 +21,32 @@ fn read() {
         parse_callid_header::<VerboseError<&[u8]>>(b"Call-ID: Sofngfwertwowert.0\r\n")
     );
 }
+
+#[test]
+fn read_word_at_word() {
+    let remains = vec![];
+    let header = Header::CallId("a84b4c76e66710@pc33.example.com".into());
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_callid_header::<VerboseError<&[u8]>>(b"Call-ID: a84b4c76e66710@pc33.example.com\r\n")
+    );
+}
+
+#[test]
+fn read_embedded_space() {
+    assert!(parse_callid_header::<VerboseError<&[u8]>>(b"Call-ID: a84b4c76 e66710@pc33.example.com\r\n").is_err());
+}
+
+#[test]
+fn generate() {
+    let call_id = gen_call_id("pc33.example.com");
+    assert!(call_id.ends_with("@pc33.example.com"));
+    assert_ne!(call_id, gen_call_id("pc33.example.com"));
+
+    let remains = vec![];
+    let input = format!("Call-ID: {}\r\n", call_id);
+    assert_eq!(
+        Ok((remains.as_ref(), Header::CallId(call_id))),
+        parse_callid_header::<VerboseError<&[u8]>>(input.as_bytes())
+    );
+}
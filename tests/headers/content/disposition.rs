@@ -1,24 +1,49 @@
-use libsip::{headers::parse::parse_content_disposition_header, Header};
+use libsip::{headers::parse::parse_content_disposition_header, ContentDisposition, Handling, Header};
 
 use nom::error::VerboseError;
 
+use crate::headers::assert_round_trip;
+
 #[test]
 fn write() {
-    let header = Header::ContentDisposition("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::ContentDisposition(ContentDisposition::new("session").with_handling(Handling::Optional));
     assert_eq!(
-        "Content-Disposition: <http://www.example.com/sounds/moo.wav>".to_string(),
+        "Content-Disposition: session;handling=optional".to_string(),
         format!("{}", header)
     );
+
+    let header = Header::ContentDisposition(ContentDisposition::new("render"));
+    assert_eq!("Content-Disposition: render".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::ContentDisposition("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::ContentDisposition(ContentDisposition::new("session").with_handling(Handling::Required));
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_content_disposition_header::<VerboseError<&[u8]>>(
-            b"Content-Disposition: <http://www.example.com/sounds/moo.wav>\r\n"
-        )
+        parse_content_disposition_header::<VerboseError<&[u8]>>(b"Content-Disposition: session;handling=required\r\n")
     );
 }
+
+#[test]
+fn handling() {
+    let disposition = ContentDisposition::new("signal");
+    assert_eq!(None, disposition.handling());
+    let disposition = disposition.with_handling(Handling::Required).with_handling(Handling::Optional);
+    assert_eq!(Some(Handling::Optional), disposition.handling());
+    assert_eq!(1, disposition.params.len());
+}
+
+#[test]
+fn round_trip() {
+    let header = assert_round_trip("Content-Disposition: icon;x-size=128;handling=OPTIONAL;x-flag;x-label=\"big icon\"");
+    match &header {
+        Header::ContentDisposition(disposition) => {
+            assert_eq!("icon", disposition.disposition);
+            assert_eq!(Some(Handling::Optional), disposition.handling());
+            assert_eq!(Some("128"), disposition.get_param("x-size"));
+        },
+        _ => panic!("expected Content-Disposition header"),
+    }
+}
@@ -1,25 +1,31 @@
-use libsip::{
-    headers::{parse::parse_content_encoding_header, ContentType},
-    Header,
-};
+use libsip::{headers::parse::parse_content_encoding_header, parse_header, Header};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::ContentEncoding(ContentType::Sdp);
-    assert_eq!(
-        "Content-Encoding: application/sdp".to_string(),
-        format!("{}", header)
-    );
+    let header = Header::ContentEncoding(vec!["gzip".into(), "deflate".into()]);
+    assert_eq!("Content-Encoding: gzip, deflate".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::ContentEncoding(ContentType::Sdp);
+    let header = Header::ContentEncoding(vec!["gzip".into(), "deflate".into()]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_content_encoding_header::<VerboseError<&[u8]>>(b"Content-Encoding: application/sdp")
+        parse_content_encoding_header::<VerboseError<&[u8]>>(b"Content-Encoding: gzip ,deflate\r\n")
     );
+
+    let header = Header::ContentEncoding(vec!["gzip".into()]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_content_encoding_header::<VerboseError<&[u8]>>(b"e: gzip\r\n")
+    );
+}
+
+#[test]
+fn round_trip() {
+    let (_, header) = parse_header::<VerboseError<&[u8]>>(b"content-encoding: gzip, deflate\r\n").unwrap();
+    assert_eq!("Content-Encoding: gzip, deflate", format!("{}", header));
 }
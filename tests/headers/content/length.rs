@@ -17,3 +17,17 @@ fn read() {
         parse_content_length_header::<VerboseError<&[u8]>>(b"Content-Length: 60\r\n")
     );
 }
+
+#[test]
+fn read_compact() {
+    let remains = vec![];
+    let header = Header::ContentLength(12);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_content_length_header::<VerboseError<&[u8]>>(b"l: 12\r\n")
+    );
+    assert_eq!(
+        "Content-Length: 12".to_string(),
+        format!("{}", parse_content_length_header::<VerboseError<&[u8]>>(b"l: 12\r\n").unwrap().1)
+    );
+}
@@ -1,22 +1,93 @@
-use libsip::headers::{parse::parse_content_type_header, ContentType, Header};
+use libsip::headers::{parse::parse_content_type_header, ContentType, Header, MediaType};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::ContentType(ContentType::Sdp);
+    let header = Header::ContentType(ContentType::Sdp.into());
     assert_eq!(
         "Content-Type: application/sdp".to_string(),
         format!("{}", header)
     );
 }
 
+#[test]
+fn write_params() {
+    let header = Header::ContentType(MediaType::new("multipart", "mixed").param("boundary", "unique-boundary-1"));
+    assert_eq!(
+        "Content-Type: multipart/mixed;boundary=unique-boundary-1".to_string(),
+        format!("{}", header)
+    );
+
+    let header = Header::ContentType(MediaType::new("text", "plain").param("charset", "utf 8"));
+    assert_eq!(
+        "Content-Type: text/plain;charset=\"utf 8\"".to_string(),
+        format!("{}", header)
+    );
+}
+
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::ContentType(ContentType::Sdp);
+    let header = Header::ContentType(ContentType::Sdp.into());
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_content_type_header::<VerboseError<&[u8]>>(b"Content-Type: application/sdp")
+        parse_content_type_header::<VerboseError<&[u8]>>(b"Content-Type: application/sdp\r\n")
+    );
+}
+
+#[test]
+fn read_params() {
+    let remains = vec![];
+    let media = MediaType::new("multipart", "mixed").param("boundary", "unique-boundary-1");
+    assert_eq!(
+        Ok((remains.as_ref(), Header::ContentType(media.clone()))),
+        parse_content_type_header::<VerboseError<&[u8]>>(b"Content-Type: multipart/mixed; boundary=unique-boundary-1\r\n")
+    );
+    assert_eq!(Some("unique-boundary-1"), media.get_param("Boundary"));
+
+    let remains = vec![];
+    let media = MediaType::new("text", "plain").param("charset", "utf-8").param("format", "flowed");
+    assert_eq!(
+        Ok((remains.as_ref(), Header::ContentType(media))),
+        parse_content_type_header::<VerboseError<&[u8]>>(b"Content-Type: text/plain;charset=\"utf-8\" ; format = flowed\r\n")
+    );
+}
+
+#[test]
+fn read_compact() {
+    let remains = vec![];
+    let header = Header::ContentType(MediaType::new("application", "sdp"));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_content_type_header::<VerboseError<&[u8]>>(b"c: application/sdp\r\n")
+    );
+}
+
+#[test]
+fn round_trip() {
+    let input = "Content-Type: multipart/mixed;boundary=\"unique boundary\"\r\n";
+    let header = parse_content_type_header::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+    assert_eq!(input.trim_end(), format!("{}", header));
+}
+
+#[test]
+fn param_order() {
+    let media = MediaType::new("multipart", "related")
+        .param("boundary", "unique-boundary-1")
+        .param("type", "application/sdp")
+        .param("start", "<sdp@atlanta.com>");
+    let written = "Content-Type: multipart/related;boundary=unique-boundary-1;type=\"application/sdp\";start=\"<sdp@atlanta.com>\"";
+    assert_eq!(written, format!("{}", Header::ContentType(media.clone())));
+
+    let input = format!("{}\r\n", written);
+    let header = parse_content_type_header::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+    assert_eq!(Header::ContentType(media.clone()), header);
+    assert_eq!(written, format!("{}", header));
+
+    let media = media.param("TYPE", "application/pidf+xml");
+    assert_eq!(
+        "multipart/related;boundary=unique-boundary-1;TYPE=\"application/pidf+xml\";start=\"<sdp@atlanta.com>\"",
+        format!("{}", media)
     );
 }
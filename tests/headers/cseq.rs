@@ -18,3 +18,40 @@ fn read() {
         parse_cseq_header::<VerboseError<&[u8]>>(b"CSeq: 60 REGISTER\r\n")
     );
 }
+
+#[test]
+fn read_whitespace() {
+    let remains = vec![];
+    let header = Header::CSeq(314159, Method::Invite);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_cseq_header::<VerboseError<&[u8]>>(b"CSeq:  314159 \t INVITE\r\n")
+    );
+}
+
+#[test]
+fn extension_method() {
+    let remains = vec![];
+    let header = Header::CSeq(2, Method::Extension("FOOBAR".into()));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_cseq_header::<VerboseError<&[u8]>>(b"CSeq: 2 FOOBAR\r\n")
+    );
+
+    let remains = vec![];
+    let header = Header::CSeq(2, Method::Invite);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_cseq_header::<VerboseError<&[u8]>>(b"CSeq: 2 invite\r\n")
+    );
+
+    let remains = vec![];
+    let header = Header::CSeq(2, Method::Extension("INVITEX".into()));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_cseq_header::<VerboseError<&[u8]>>(b"CSeq: 2 INVITEX\r\n")
+    );
+
+    let header = Header::CSeq(314159, Method::Extension("FOOBAR".into()));
+    assert_eq!("CSeq: 314159 FOOBAR".to_string(), format!("{}", header));
+}
@@ -1,20 +1,53 @@
 use libsip::*;
-use libsip::headers::parse::parse_date_header;
+use libsip::headers::{date::*, parse::parse_date_header};
 
 use nom::error::VerboseError;
 
+fn date() -> SipDate {
+    SipDate {
+        weekday: Weekday::Sat,
+        day: 13,
+        month: Month::Nov,
+        year: 2010,
+        hour: 23,
+        minute: 29,
+        second: 0,
+    }
+}
+
 #[test]
 fn write() {
-    let header = Header::Date("wed 1 2001".into());
-    assert_eq!("Date: wed 1 2001".to_string(), format!("{}", header));
+    let header = Header::Date(date());
+    assert_eq!("Date: Sat, 13 Nov 2010 23:29:00 GMT".to_string(), format!("{}", header));
+
+    let header = Header::Date(SipDate { weekday: Weekday::Mon, day: 1, month: Month::Feb, hour: 4, minute: 5, second: 6, year: 2021 });
+    assert_eq!("Date: Mon, 01 Feb 2021 04:05:06 GMT".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Date("wed 1 2001".into());
+    let header = Header::Date(date());
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_date_header::<VerboseError<&[u8]>>(b"Date: wed 1 2001\r\n")
+        parse_date_header::<VerboseError<&[u8]>>(b"Date: Sat, 13 Nov 2010 23:29:00 GMT\r\n")
     );
 }
+
+#[test]
+fn read_invalid() {
+    let invalid: &[&[u8]] = &[
+        b"Date: Sat, 13 Nov 2010 23:29:00 PST\r\n",
+        b"Date: Sat, 13 Nov 2010 23:29:00\r\n",
+        b"Date: Sut, 13 Nov 2010 23:29:00 GMT\r\n",
+        b"Date: Sat, 13 Nuv 2010 23:29:00 GMT\r\n",
+        b"Date: Sat, 32 Nov 2010 23:29:00 GMT\r\n",
+        b"Date: Sat, 00 Nov 2010 23:29:00 GMT\r\n",
+        b"Date: Sat, 13 Nov 2010 24:29:00 GMT\r\n",
+        b"Date: Sat, 3 Nov 2010 23:29:00 GMT\r\n",
+        b"Date: wed 1 2001\r\n",
+    ];
+    for input in invalid {
+        assert!(parse_date_header::<VerboseError<&[u8]>>(input).is_err());
+    }
+}
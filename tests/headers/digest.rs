@@ -0,0 +1,110 @@
+use libsip::*;
+use libsip::headers::digest::{authorize, authorize_with, strongest_challenge, DigestAlgorithm};
+use libsip::headers::parse::{parse_authorization_header, parse_www_authenticate_header};
+
+use nom::error::VerboseError;
+
+fn rfc2617_challenge() -> AuthChallenge {
+    let mut challenge = AuthChallenge::new("testrealm@host.com", "dcd98b7102dd2f0e8b11d0f600bfb0c093");
+    challenge.qop = vec!["auth".into(), "auth-int".into()];
+    challenge.opaque = Some("5ccc069c403ebaf9f0171e9517f40e41".into());
+    challenge
+}
+
+#[test]
+fn rfc2617_qop_auth() {
+    let get = Method::Extension("GET".into());
+    let header = authorize_with(&rfc2617_challenge(), "Mufasa", "Circle Of Life", &get, "/dir/index.html", "0a4f113b", 1).unwrap();
+    assert_eq!(Some(&"6629fae49393a05397450978507c4ef1".to_string()), header.1.get("response"));
+    assert_eq!(Some(&"00000001".to_string()), header.1.get("nc"));
+    assert_eq!(Some(&"0a4f113b".to_string()), header.1.get("cnonce"));
+    assert_eq!(Some(&"auth".to_string()), header.1.get("qop"));
+    assert_eq!(Some(&"5ccc069c403ebaf9f0171e9517f40e41".to_string()), header.1.get("opaque"));
+    assert_eq!(Some(&"/dir/index.html".to_string()), header.1.get("uri"));
+}
+
+#[test]
+fn rfc2069_no_qop() {
+    let mut challenge = rfc2617_challenge();
+    challenge.qop = vec![];
+    let get = Method::Extension("GET".into());
+    let header = authorize_with(&challenge, "Mufasa", "Circle Of Life", &get, "/dir/index.html", "0a4f113b", 1).unwrap();
+    assert_eq!(Some(&"670fd8c2df070c60b045671b8b24ff02".to_string()), header.1.get("response"));
+    assert_eq!(None, header.1.get("cnonce"));
+    assert_eq!(None, header.1.get("nc"));
+}
+
+#[test]
+fn sip_challenge() {
+    let input = b"WWW-Authenticate: Digest realm=\"atlanta.com\", nonce=\"84a4cc6f3082121f32b42a2187831a9e\", qop=\"auth\", algorithm=MD5\r\n";
+    let challenge = if let Header::WwwAuthenticate(challenge) = parse_www_authenticate_header::<VerboseError<&[u8]>>(input).unwrap().1 {
+        challenge
+    } else {
+        panic!("expected a WWW-Authenticate header");
+    };
+    let uri = Uri::sip(domain!("atlanta.com")).auth(uri_auth!("bob"));
+    let header = authorize(&challenge, "bob", "zanzibar", &Method::Register, &uri).unwrap();
+    assert_eq!(Some(&"sip:bob@atlanta.com".to_string()), header.1.get("uri"));
+    assert_eq!(32, header.1.get("response").unwrap().len());
+
+    let expected = authorize_with(&challenge, "bob", "zanzibar", &Method::Register, &uri, header.1.get("cnonce").unwrap(), 1).unwrap();
+    assert_eq!(expected, header);
+
+    let written = format!("{}\r\n", Header::Authorization(header.clone()));
+    assert_eq!(
+        Ok((&b""[..], Header::Authorization(header))),
+        parse_authorization_header::<VerboseError<&[u8]>>(written.as_bytes())
+    );
+}
+
+#[test]
+fn unsupported_algorithm() {
+    let mut challenge = rfc2617_challenge();
+    challenge.algorithm = Some("AKAv1-MD5".into());
+    assert_eq!(None, DigestAlgorithm::from_challenge(&challenge));
+    assert!(authorize(&challenge, "Mufasa", "Circle Of Life", &Method::Register, "sip:host").is_err());
+}
+
+fn rfc7616_challenge(algorithm: &str) -> AuthChallenge {
+    let mut challenge = AuthChallenge::new("http-auth@example.org", "7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v");
+    challenge.qop = vec!["auth".into(), "auth-int".into()];
+    challenge.algorithm = Some(algorithm.into());
+    challenge.opaque = Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS".into());
+    challenge
+}
+
+#[test]
+fn rfc7616_sha256() {
+    let get = Method::Extension("GET".into());
+    let cnonce = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ";
+    let header = authorize_with(&rfc7616_challenge("SHA-256"), "Mufasa", "Circle of Life", &get, "/dir/index.html", cnonce, 1).unwrap();
+    let response = header.1.get("response").unwrap();
+    assert_eq!(64, response.len());
+    assert!(response.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!("753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1", response);
+    assert_eq!(Some(&"SHA-256".to_string()), header.1.get("algorithm"));
+
+    let header = authorize_with(&rfc7616_challenge("MD5"), "Mufasa", "Circle of Life", &get, "/dir/index.html", cnonce, 1).unwrap();
+    assert_eq!(Some(&"8ca523f5e9506fed4657c9700eebdbec".to_string()), header.1.get("response"));
+}
+
+#[test]
+fn sha256_sess() {
+    let get = Method::Extension("GET".into());
+    let cnonce = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ";
+    let header = authorize_with(&rfc7616_challenge("SHA-256-sess"), "Mufasa", "Circle of Life", &get, "/dir/index.html", cnonce, 1).unwrap();
+    assert_eq!(Some(&"2fd51b3a77ad75bad6afad6003e818d767133c46d9e2749e7f5232ae1ea3efd7".to_string()), header.1.get("response"));
+}
+
+#[test]
+fn strongest() {
+    let challenges = vec![
+        rfc7616_challenge("MD5"),
+        rfc7616_challenge("AKAv1-MD5"),
+        rfc7616_challenge("SHA-256"),
+        rfc7616_challenge("SHA-256-sess"),
+    ];
+    assert_eq!(Some(&challenges[2]), strongest_challenge(&challenges));
+    assert_eq!(Some(&challenges[0]), strongest_challenge(&challenges[..2]));
+    assert_eq!(None, strongest_challenge(&challenges[1..2]));
+}
@@ -0,0 +1,33 @@
+use libsip::{headers::parse::parse_diversion_header, *};
+
+use nom::error::VerboseError;
+
+use crate::headers::assert_round_trip;
+
+#[test]
+fn read() {
+    let input = b"Diversion: <sip:user@host>;reason=unconditional;counter=1, <sip:office@example.com>;reason=\"user-busy\";counter=2\r\n";
+    let header = parse_diversion_header::<VerboseError<&[u8]>>(input).unwrap().1;
+    if let Header::Diversion(entries) = &header {
+        assert_eq!(2, entries.len());
+        assert_eq!(Some("unconditional"), entries[0].reason());
+        assert_eq!(Some(1), entries[0].counter());
+        assert_eq!(Some("user-busy"), entries[1].reason());
+        assert_eq!(Some(2), entries[1].counter());
+    } else {
+        panic!("expected a Diversion header");
+    }
+}
+
+#[test]
+fn round_trip() {
+    assert_round_trip("Diversion: sip:user@host;reason=unconditional;counter=1");
+}
+
+#[test]
+fn missing_params() {
+    let uri = Uri::sip(domain!("host")).auth(uri_auth!("user"));
+    let entry = NamedHeader::new(uri).with_param("counter", Some("many".into()));
+    assert_eq!(None, entry.reason());
+    assert_eq!(None, entry.counter());
+}
@@ -0,0 +1,58 @@
+use libsip::*;
+use libsip::headers::parse::{parse_event_header, parse_subscription_state_header};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::Event(EventHeader::new("presence").with_id("1234"));
+    assert_eq!("Event: presence;id=1234".to_string(), format!("{}", header));
+
+    let header = Header::SubscriptionState(SubscriptionState::new(SubscriptionStatus::Terminated).with_reason("timeout"));
+    assert_eq!("Subscription-State: terminated;reason=timeout".to_string(), format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::Event(EventHeader::new("dialog").with_id("a1"));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_event_header::<VerboseError<&[u8]>>(b"o: dialog;id=a1\r\n")
+    );
+
+    let (_, header) = parse_event_header::<VerboseError<&[u8]>>(b"Event: presence.winfo\r\n").unwrap();
+    match header {
+        Header::Event(event) => {
+            assert_eq!("presence.winfo", event.event);
+            assert_eq!(None, event.id());
+        },
+        _ => panic!("expected an Event header"),
+    }
+
+    let (_, header) = parse_subscription_state_header::<VerboseError<&[u8]>>(b"Subscription-State: pending;reason=probation;retry-after=30\r\n").unwrap();
+    match header {
+        Header::SubscriptionState(state) => {
+            assert_eq!(SubscriptionStatus::Pending, state.state);
+            assert_eq!(Some("probation"), state.reason());
+            assert_eq!(Some(30), state.retry_after());
+            assert_eq!(None, state.expires());
+        },
+        _ => panic!("expected a Subscription-State header"),
+    }
+}
+
+#[test]
+fn round_trip() {
+    let input = b"Subscription-State: active;expires=599\r\n";
+    let (_, header) = parse_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(
+        Header::SubscriptionState(SubscriptionState::new(SubscriptionStatus::Active).with_expires(599)),
+        header
+    );
+    assert_eq!("Subscription-State: active;expires=599", format!("{}", header));
+
+    let input = b"Event: conference;id=1;call-id=\"a@b\"\r\n";
+    let (_, header) = parse_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!("Event: conference;id=1;call-id=\"a@b\"", format!("{}", header));
+}
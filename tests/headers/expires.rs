@@ -17,3 +17,18 @@ fn read() {
         parse_expires_header::<VerboseError<&[u8]>>(b"Expires: 60\r\n")
     );
 }
+
+#[test]
+fn read_zero() {
+    let remains = vec![];
+    let header = Header::Expires(0);
+    assert_eq!(
+        Ok((remains.as_ref(), header.clone())),
+        parse_expires_header::<VerboseError<&[u8]>>(b"Expires: 0\r\n")
+    );
+    assert_eq!("Expires: 0".to_string(), format!("{}", header));
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Expires(3600))),
+        parse_expires_header::<VerboseError<&[u8]>>(b"Expires: 3600\r\n")
+    );
+}
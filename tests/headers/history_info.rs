@@ -0,0 +1,33 @@
+use libsip::{headers::parse::parse_history_info_header, *};
+
+use nom::error::VerboseError;
+
+use crate::headers::assert_round_trip;
+
+#[test]
+fn read() {
+    let input = b"History-Info: <sip:bob@example.com>;index=1, <sip:office@example.com>;index=1.1;rc=1\r\n";
+    let header = parse_history_info_header::<VerboseError<&[u8]>>(input).unwrap().1;
+    if let Header::HistoryInfo(entries) = &header {
+        assert_eq!(2, entries.len());
+        assert_eq!("sip:bob@example.com", entries[0].uri.to_string());
+        assert_eq!(Some(vec![1]), entries[0].index());
+        assert_eq!(Some(vec![1, 1]), entries[1].index());
+        assert_eq!(Some("1"), entries[1].get_param("rc"));
+    } else {
+        panic!("expected a History-Info header");
+    }
+}
+
+#[test]
+fn round_trip() {
+    assert_round_trip("History-Info: <sip:bob@example.com?Reason=SIP%3Bcause%3D302>;index=1, sip:office@example.com;index=1.1;mp=1");
+}
+
+#[test]
+fn invalid_index() {
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("bob"));
+    let entry = NamedHeader::new(uri).with_param("index", Some("1..2".into()));
+    assert_eq!(None, entry.index());
+    assert_eq!(None, entry.with_param("index", None).index());
+}
@@ -1,10 +1,10 @@
-use libsip::{headers::parse::parse_alert_info_header, Header};
+use libsip::{headers::parse::parse_alert_info_header, Header, InfoValue};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::AlertInfo("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::AlertInfo(vec![InfoValue::new("http://www.example.com/sounds/moo.wav")]);
     assert_eq!(
         "Alert-Info: <http://www.example.com/sounds/moo.wav>".to_string(),
         format!("{}", header)
@@ -14,9 +14,24 @@ fn write() {
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::AlertInfo("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::AlertInfo(vec![InfoValue::new("http://www.example.com/sounds/moo.wav")]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
         parse_alert_info_header::<VerboseError<&[u8]>>(b"Alert-Info: <http://www.example.com/sounds/moo.wav>\r\n")
     );
 }
+
+#[test]
+fn read_with_params() {
+    let remains = vec![];
+    let header = Header::AlertInfo(vec![
+        InfoValue::new("urn:alert:tone:internal"),
+        InfoValue::new("http://www.example.com/sounds/ring.wav").param("appearance", "2"),
+    ]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_alert_info_header::<VerboseError<&[u8]>>(
+            b"Alert-Info: <urn:alert:tone:internal>, <http://www.example.com/sounds/ring.wav>;appearance=2\r\n"
+        )
+    );
+}
@@ -1,24 +1,70 @@
-use libsip::{headers::parse::parse_authentication_info_header, Header};
+use libsip::{headers::parse::parse_authentication_info_header, AuthenticationInfo, Header};
 
 use nom::error::VerboseError;
 
+use crate::headers::assert_round_trip;
+
 #[test]
 fn write() {
-    let header = Header::AuthenticationInfo("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::AuthenticationInfo(AuthenticationInfo::new().nextnonce("47364c23432d2e131a5fb210812c"));
     assert_eq!(
-        "Authentication-Info: <http://www.example.com/sounds/moo.wav>".to_string(),
+        "Authentication-Info: nextnonce=\"47364c23432d2e131a5fb210812c\"".to_string(),
         format!("{}", header)
     );
+
+    let mut info = AuthenticationInfo::new().nextnonce("47364c23432d2e131a5fb210812c");
+    info.qop = Some("auth".into());
+    info.rspauth = Some("6629fae49393a05397450978507c4ef1".into());
+    info.cnonce = Some("0a4f113b".into());
+    info.nc = Some("00000001".into());
+    assert_eq!(
+        "Authentication-Info: nextnonce=\"47364c23432d2e131a5fb210812c\", qop=auth, \
+         rspauth=\"6629fae49393a05397450978507c4ef1\", cnonce=\"0a4f113b\", nc=00000001"
+            .to_string(),
+        format!("{}", Header::AuthenticationInfo(info))
+    );
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::AuthenticationInfo("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::AuthenticationInfo(AuthenticationInfo::new().nextnonce("47364c23432d2e131a5fb210812c"));
     assert_eq!(
         Ok((remains.as_ref(), header)),
         parse_authentication_info_header::<VerboseError<&[u8]>>(
-            b"Authentication-Info: <http://www.example.com/sounds/moo.wav>\r\n"
+            b"Authentication-Info: nextnonce=\"47364c23432d2e131a5fb210812c\"\r\n"
         )
     );
+
+    let mut info = AuthenticationInfo::new().nextnonce("47364c23432d2e131a5fb210812c");
+    info.qop = Some("auth".into());
+    info.rspauth = Some("6629fae49393a05397450978507c4ef1".into());
+    info.cnonce = Some("0a4f113b".into());
+    info.nc = Some("00000001".into());
+    info.params.push(("ext".into(), "value".into()));
+    assert_eq!(
+        Ok((remains.as_ref(), Header::AuthenticationInfo(info))),
+        parse_authentication_info_header::<VerboseError<&[u8]>>(
+            b"Authentication-Info: qop=auth,rspauth=\"6629fae49393a05397450978507c4ef1\" , \
+              cnonce=\"0a4f113b\", nc=00000001, nextnonce=\"47364c23432d2e131a5fb210812c\", ext=value\r\n"
+        )
+    );
+}
+
+#[test]
+fn round_trip_other_params() {
+    assert_round_trip("Authentication-Info: nextnonce=\"abc\", zeta=\"1\", ext=\"a \\\"b\\\"\", alpha=\"2\"");
+}
+
+#[test]
+fn round_trip_escaped_quote() {
+    let header = assert_round_trip("Authentication-Info: nextnonce=\"a\\\"b\", rspauth=\"r\\\\1\", cnonce=\"c\\\"\"");
+    match &header {
+        Header::AuthenticationInfo(info) => {
+            assert_eq!(Some("a\"b".to_string()), info.nextnonce);
+            assert_eq!(Some("r\\1".to_string()), info.rspauth);
+            assert_eq!(Some("c\"".to_string()), info.cnonce);
+        },
+        _ => panic!("expected an Authentication-Info header"),
+    }
 }
@@ -1,10 +1,10 @@
-use libsip::{headers::parse::parse_call_info_header, Header};
+use libsip::{headers::parse::parse_call_info_header, Header, InfoValue};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::CallInfo("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::CallInfo(vec![InfoValue::new("http://www.example.com/sounds/moo.wav")]);
     assert_eq!(
         "Call-Info: <http://www.example.com/sounds/moo.wav>".to_string(),
         format!("{}", header)
@@ -14,9 +14,29 @@ fn write() {
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::CallInfo("<http://www.example.com/sounds/moo.wav>".into());
+    let header = Header::CallInfo(vec![InfoValue::new("http://www.example.com/sounds/moo.wav")]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
         parse_call_info_header::<VerboseError<&[u8]>>(b"Call-Info: <http://www.example.com/sounds/moo.wav>\r\n")
     );
 }
+
+#[test]
+fn read_multiple() {
+    let input = b"Call-Info: <http://wwww.example.com/alice/photo.jpg> ;purpose=icon, \
+<https://www.example.com/alice/>;PURPOSE=info\r\n";
+    let (_, header) = parse_call_info_header::<VerboseError<&[u8]>>(input).unwrap();
+    let entries = match &header {
+        Header::CallInfo(entries) => entries,
+        _ => panic!("expected a Call-Info header"),
+    };
+    assert_eq!(2, entries.len());
+    assert_eq!("http://wwww.example.com/alice/photo.jpg", entries[0].uri);
+    assert_eq!(Some("icon"), entries[0].purpose());
+    assert_eq!("https://www.example.com/alice/", entries[1].uri);
+    assert_eq!(Some("info"), entries[1].purpose());
+    assert_eq!(
+        "Call-Info: <http://wwww.example.com/alice/photo.jpg>;purpose=icon, <https://www.example.com/alice/>;PURPOSE=info",
+        header.to_string()
+    );
+}
@@ -1,19 +1,27 @@
-use libsip::{headers::parse::parse_mime_version_header, Header};
+use libsip::{headers::parse::parse_mime_version_header, parse_header, Header};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::MimeVersion(1.0);
-    assert_eq!("MIME-Version: 1".to_string(), format!("{}", header));
+    let header = Header::MimeVersion("1.0".into());
+    assert_eq!("MIME-Version: 1.0".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
-    let remains = vec![b' '];
-    let header = Header::MimeVersion(1.0);
+    let remains = vec![];
+    let header = Header::MimeVersion("1.0".into());
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_mime_version_header::<VerboseError<&[u8]>>(b"MIME-Version: 1.0 ")
+        parse_mime_version_header::<VerboseError<&[u8]>>(b"MIME-Version: 1.0 \r\n")
     );
+    assert!(parse_mime_version_header::<VerboseError<&[u8]>>(b"MIME-Version: 1\r\n").is_err());
+}
+
+#[test]
+fn round_trip() {
+    let (_, header) = parse_header::<VerboseError<&[u8]>>(b"mime-version: 1.0\r\n").unwrap();
+    assert_eq!(Header::MimeVersion("1.0".into()), header);
+    assert_eq!("MIME-Version: 1.0", format!("{}", header));
 }
@@ -1,4 +1,4 @@
-use libsip::{headers::parse::parse_min_expires_header, Header};
+use libsip::{headers::parse::parse_min_expires_header, parse_header, parse_message, Header};
 
 use nom::error::VerboseError;
 
@@ -17,3 +17,18 @@ fn read() {
         parse_min_expires_header::<VerboseError<&[u8]>>(b"Min-Expires: 60\r\n")
     );
 }
+
+#[test]
+fn round_trip() {
+    let (_, header) = parse_header::<VerboseError<&[u8]>>(b"Min-Expires: 3600\r\n").unwrap();
+    assert_eq!(Header::MinExpires(3600), header);
+    assert_eq!("Min-Expires: 3600", format!("{}", header));
+}
+
+#[test]
+fn read_interval_too_brief() {
+    let input = b"SIP/2.0 423 Interval Too Brief\r\nExpires: 60\r\nMin-Expires: 3600\r\nContent-Length: 0\r\n\r\n";
+    let (_, response) = parse_message::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(Some(Header::Expires(60)), response.headers().expires());
+    assert_eq!(Some(Header::MinExpires(3600)), response.headers().min_expires());
+}
@@ -1,24 +1,39 @@
 mod accept;
 mod allow;
+mod allow_events;
 mod authorization;
+mod caller_prefs;
 mod callid;
 mod content;
 mod cseq;
 mod date;
+mod digest;
+mod diversion;
+mod event;
 mod expires;
+mod history_info;
 mod info;
 mod max_forwards;
 mod mime_version;
 mod min_expires;
 mod named;
+mod names;
 mod organization;
+mod other;
+mod p_asserted_identity;
+mod path;
 mod priority;
 mod proxy;
+mod rack;
+mod reason;
 mod record_route;
+mod replaces;
 mod require;
 mod retry_after;
 mod route;
 mod server;
+mod service_route;
+mod session_expires;
 mod subject;
 mod supported;
 mod timestamp;
@@ -27,3 +42,13 @@ mod useragent;
 mod via;
 mod warning;
 mod www_authenticate;
+
+use libsip::Header;
+
+/// Parse `input` as a single header line and check that
+/// writing the header gives back the same text.
+pub fn assert_round_trip(input: &str) -> Header {
+    let header: Header = input.parse().unwrap();
+    assert_eq!(input, header.to_string());
+    header
+}
@@ -6,15 +6,15 @@ use nom::error::VerboseError;
 #[test]
 fn write() {
     let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
-    let header = Header::Contact(named_header!(uri, "Guy"));
+    let header = Header::Contact(named_header!(uri, "Guy").into());
     assert_eq!("Contact: Guy <sip:guy@example.com>".to_string(), format!("{}", header));
 
     let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
-    let header = Header::Contact(named_header!(uri, "Guy With Face"));
+    let header = Header::Contact(named_header!(uri, "Guy With Face").into());
     assert_eq!("Contact: \"Guy With Face\" <sip:guy@example.com>".to_string(), format!("{}", header));
 
     let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
-    let header = Header::Contact(named_header!(uri));
+    let header = Header::Contact(named_header!(uri).into());
     assert_eq!("Contact: sip:guy@example.com".to_string(), format!("{}", header));
 }
 
@@ -22,14 +22,229 @@ fn write() {
 fn read() {
     let remains = vec![];
     let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
-    let header = Header::Contact(named_header!(uri, "Guy"));
+    let header = Header::Contact(named_header!(uri, "Guy").into());
     assert_eq!(Ok((remains.as_ref(), header)), parse_contact_header::<VerboseError<&[u8]>>(b"Contact: Guy <sip:guy@example.com>\r\n"));
 
     let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
-    let header = Header::Contact(named_header!(uri, "Guy with face"));
+    let header = Header::Contact(named_header!(uri, "Guy with face").into());
     assert_eq!(Ok((remains.as_ref(), header)), parse_contact_header::<VerboseError<&[u8]>>(b"Contact: \"Guy with face\" <sip:guy@example.com>\r\n"));
 
     let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
-    let header = Header::Contact(named_header!(uri));
+    let header = Header::Contact(named_header!(uri).into());
     assert_eq!(Ok((remains.as_ref(), header)), parse_contact_header::<VerboseError<&[u8]>>(b"Contact: <sip:guy@example.com>\r\n"));
 }
+
+#[test]
+fn read_quoted_params() {
+    let remains = vec![];
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
+    let mut named = named_header!(uri);
+    named.params.push(("+sip.instance".into(), Some("<urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6>".into())));
+    named.params.push(("expires".into(), Some("3600".into())));
+    named.params.push(("reg-id".into(), Some("1.a_b".into())));
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Contact(named.into()))),
+        parse_contact_header::<VerboseError<&[u8]>>(
+            b"Contact: <sip:guy@example.com>;+sip.instance=\"<urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6>\";expires=3600;reg-id=1.a_b\r\n"
+        )
+    );
+}
+
+#[test]
+fn write_quoted_params() {
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
+    let mut named = named_header!(uri);
+    named.params.push(("+sip.instance".into(), Some("<urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6>".into())));
+    assert_eq!(
+        "Contact: sip:guy@example.com;+sip.instance=\"<urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6>\"".to_string(),
+        format!("{}", Header::Contact(named.into()))
+    );
+
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
+    let mut named = named_header!(uri);
+    named.params.push(("reg-id".into(), Some("1".into())));
+    assert_eq!("Contact: sip:guy@example.com;reg-id=1".to_string(), format!("{}", Header::Contact(named.into())));
+}
+
+#[test]
+fn read_flag_params() {
+    let remains = vec![];
+    let uri = Uri::sip(domain!("b")).auth(uri_auth!("a"));
+    let mut named = named_header!(uri);
+    named.params.push(("lr".into(), None));
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Contact(named.into()))),
+        parse_contact_header::<VerboseError<&[u8]>>(b"Contact: <sip:a@b>;lr\r\n")
+    );
+
+    let remains = vec![];
+    let uri = Uri::sip(domain!("b")).auth(uri_auth!("a"));
+    let mut named = named_header!(uri, "A");
+    named.params.push(("received".into(), None));
+    named.params.push(("expires".into(), Some("60".into())));
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Contact(named.into()))),
+        parse_contact_header::<VerboseError<&[u8]>>(b"Contact: A <sip:a@b>;received;expires=60\r\n")
+    );
+}
+
+#[test]
+fn write_flag_params() {
+    let uri = Uri::sip(domain!("b")).auth(uri_auth!("a"));
+    let mut named = named_header!(uri, "A");
+    named.params.push(("lr".into(), None));
+    assert_eq!("Contact: A <sip:a@b>;lr".to_string(), format!("{}", Header::Contact(named.into())));
+}
+
+#[test]
+fn expires_param() {
+    let header = parse_contact_header::<VerboseError<&[u8]>>(b"Contact: <sip:guy@example.com>;expires=3600\r\n").unwrap().1;
+    if let Header::Contact(contact) = &header {
+        assert_eq!(Some(3600), contact.values()[0].expires());
+    } else {
+        panic!("expected a Contact header");
+    }
+    assert_eq!("Contact: sip:guy@example.com;expires=3600".to_string(), format!("{}", header));
+
+    let header = parse_contact_header::<VerboseError<&[u8]>>(b"Contact: <sip:guy@example.com>;Expires=0\r\n").unwrap().1;
+    if let Header::Contact(contact) = &header {
+        assert_eq!(Some(0), contact.values()[0].expires());
+    }
+
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
+    let contact = named_header!(uri);
+    assert_eq!(None, contact.expires());
+    let contact = contact.with_expires(0);
+    assert_eq!(Some(0), contact.expires());
+    let header = Header::Contact(contact.into());
+    assert_eq!(
+        Ok((&b""[..], header.clone())),
+        parse_contact_header::<VerboseError<&[u8]>>(format!("{}\r\n", header).as_bytes())
+    );
+}
+
+#[test]
+fn wildcard() {
+    let remains = vec![];
+    let header = Header::Contact(ContactHeader::Wildcard);
+    assert_eq!("Contact: *".to_string(), format!("{}", header));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_contact_header::<VerboseError<&[u8]>>(b"Contact: *\r\n")
+    );
+    assert!(ContactHeader::Wildcard.is_wildcard());
+    assert!(ContactHeader::Wildcard.values().is_empty());
+}
+
+#[test]
+fn multiple_values() {
+    let remains = vec![];
+    let first = named_header!((Uri::sip(domain!("example.com")).auth(uri_auth!("guy"))), "Guy, Jr")
+        .with_expires(60);
+    let mut second = named_header!((Uri::sip(domain!("example.org")).auth(uri_auth!("guy"))));
+    second.params.push(("q".into(), Some("0.7".into())));
+    let header = Header::Contact(ContactHeader::Values(vec![first, second]));
+    assert_eq!(
+        Ok((remains.as_ref(), header.clone())),
+        parse_contact_header::<VerboseError<&[u8]>>(b"Contact: \"Guy, Jr\" <sip:guy@example.com>;expires=60 , <sip:guy@example.org>;q=0.7\r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), header.clone())),
+        parse_contact_header::<VerboseError<&[u8]>>(format!("{}\r\n", header).as_bytes())
+    );
+}
+
+#[test]
+fn q_param() {
+    let header = parse_contact_header::<VerboseError<&[u8]>>(b"m: <sip:a@b>;q=0.7, <sip:c@d>;q=1, <sip:e@f>;q=1.5, <sip:g@h>\r\n").unwrap().1;
+    if let Header::Contact(contact) = header {
+        let q: Vec<Option<f32>> = contact.values().iter().map(|value| value.q()).collect();
+        assert_eq!(vec![Some(0.7), Some(1.0), None, None], q);
+    } else {
+        panic!("expected a Contact header");
+    }
+}
+
+#[test]
+fn with_q() {
+    let uri = Uri::sip(domain!("b")).auth(uri_auth!("a"));
+    let contact = NamedHeader::new(uri).with_q(0.5).unwrap();
+    assert_eq!(Some("0.5"), contact.get_param("q"));
+    assert_eq!(Some(0.5), contact.q());
+
+    let contact = contact.with_q(1.0).unwrap();
+    assert_eq!("sip:a@b;q=1", contact.to_string());
+    assert_eq!(Some(1.0), contact.q());
+
+    let contact = contact.with_q(0.1234).unwrap();
+    assert_eq!(Some("0.123"), contact.get_param("q"));
+    assert_eq!(1, contact.params.len());
+
+    assert!(contact.clone().with_q(1.5).is_err());
+    assert!(contact.with_q(-0.1).is_err());
+}
+
+#[test]
+fn param_order() {
+    let input = b"Contact: Alice <sip:alice@192.0.2.4>;reg-id=1;expires=3600;+sip.instance=\"<urn:uuid:00000000-0000-1000-8000-000a95a0e128>\"\r\n";
+    let header = parse_contact_header::<VerboseError<&[u8]>>(input).unwrap().1;
+    if let Header::Contact(contact) = &header {
+        let keys: Vec<&str> = contact.values()[0].params.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(vec!["reg-id", "expires", "+sip.instance"], keys);
+    } else {
+        panic!("expected a Contact header");
+    }
+    assert_eq!(
+        "Contact: Alice <sip:alice@192.0.2.4>;reg-id=1;expires=3600;+sip.instance=\"<urn:uuid:00000000-0000-1000-8000-000a95a0e128>\"",
+        format!("{}", header)
+    );
+}
+
+#[test]
+fn gruu() {
+    let input = b"Contact: <sip:callee@192.0.2.1;gr=urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6>;+sip.instance=\"<urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6>\";reg-id=1\r\n";
+    let header = parse_contact_header::<VerboseError<&[u8]>>(input).unwrap().1;
+    if let Header::Contact(contact) = &header {
+        let value = &contact.values()[0];
+        assert_eq!(Some("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6"), value.instance_id());
+        assert!(value.uri.parameters.contains(&UriParam::Other("gr".into(), Some("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6".into()))));
+    } else {
+        panic!("expected a Contact header");
+    }
+    assert_eq!(
+        Ok((&b""[..], header.clone())),
+        parse_contact_header::<VerboseError<&[u8]>>(format!("{}\r\n", header).as_bytes())
+    );
+
+    let header = parse_contact_header::<VerboseError<&[u8]>>(b"Contact: <sip:alice@pc33.atlanta.com>;gr;+sip.instance=\"<urn:uuid:00000000-0000-1000-8000-000a95a0e128>\"\r\n").unwrap().1;
+    if let Header::Contact(contact) = &header {
+        assert!(contact.values()[0].has_param("gr"));
+        assert_eq!(Some("urn:uuid:00000000-0000-1000-8000-000a95a0e128"), contact.values()[0].instance_id());
+    } else {
+        panic!("expected a Contact header");
+    }
+    assert_eq!(
+        "Contact: sip:alice@pc33.atlanta.com;gr;+sip.instance=\"<urn:uuid:00000000-0000-1000-8000-000a95a0e128>\"",
+        format!("{}", header)
+    );
+}
+
+#[test]
+fn param_builder() {
+    let contact = NamedHeader::new(Uri::sip(domain!("192.0.2.4")).auth(uri_auth!("alice")))
+        .name("Alice")
+        .param("expires", "3600")
+        .flag("ob")
+        .param("+sip.instance", "<urn:uuid:00000000-0000-1000-8000-000a95a0e128>");
+    assert_eq!(
+        "Alice <sip:alice@192.0.2.4>;expires=3600;ob;+sip.instance=\"<urn:uuid:00000000-0000-1000-8000-000a95a0e128>\"",
+        contact.to_string()
+    );
+    assert!(contact.has_param("ob"));
+    assert_eq!(None, contact.get_param("ob"));
+    assert_eq!(Some(3600), contact.expires());
+
+    let contact = contact.param("Expires", "60");
+    assert_eq!(3, contact.params.len());
+    assert_eq!(Some(60), contact.expires());
+}
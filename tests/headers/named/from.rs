@@ -38,3 +38,116 @@ fn read() {
     assert_eq!(Ok((remains.as_ref(), header)), parse_from_header::<VerboseError<&[u8]>>(b"From: sip:unknown@127.0.0.1\r\n"));
 
 }
+
+#[test]
+fn tag() {
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
+    let named = NamedHeader::new(uri).name("Guy").with_tag("1928301774");
+    assert_eq!(Some("1928301774"), named.tag());
+    assert_eq!(
+        "From: Guy <sip:guy@example.com>;tag=1928301774".to_string(),
+        format!("{}", Header::From(named.clone()))
+    );
+    assert_eq!(Some("abc"), named.with_tag("abc").tag());
+
+    let header = parse_from_header::<VerboseError<&[u8]>>(b"From: Guy <sip:guy@example.com>;Tag=a6c85cf\r\n").unwrap().1;
+    if let Header::From(named) = header {
+        assert_eq!(Some("a6c85cf"), named.tag());
+    } else {
+        panic!("expected a From header");
+    }
+
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
+    assert_eq!(None, NamedHeader::new(uri).tag());
+}
+
+#[test]
+fn param_case() {
+    let uri = Uri::sip(domain!("example.com")).auth(uri_auth!("guy"));
+    let mut named = NamedHeader::new(uri);
+    named.params.push(("TAG".into(), Some("1928301774".into())));
+    assert_eq!(Some("1928301774"), named.tag());
+    assert!(named.has_param("tag"));
+    assert_eq!(
+        "From: sip:guy@example.com;TAG=1928301774".to_string(),
+        format!("{}", Header::From(named.clone()))
+    );
+
+    let named = named.with_tag("abc");
+    assert_eq!(1, named.params.len());
+    assert_eq!(Some("abc"), named.tag());
+
+    let named = named.with_param("Expires", Some("60".into())).with_param("Q", Some("0.5".into()));
+    assert_eq!(Some(60), named.expires());
+    assert_eq!(Some(0.5), named.q());
+    assert_eq!(Some("60"), named.get_param("EXPIRES"));
+
+    let header = parse_from_header::<VerboseError<&[u8]>>(b"From: <sip:guy@example.com>;tag=a;TAG=b\r\n").unwrap().1;
+    if let Header::From(named) = header {
+        assert_eq!(1, named.params.len());
+        assert_eq!(Some("b"), named.tag());
+    } else {
+        panic!("expected a From header");
+    }
+}
+
+#[test]
+fn unquoted_name() {
+    let header = parse_from_header::<VerboseError<&[u8]>>(b"From: Alice<sip:alice@a>\r\n").unwrap().1;
+    if let Header::From(named) = header {
+        assert_eq!(Some("Alice"), named.display_name.as_deref());
+        assert_eq!("sip:alice@a", named.uri.to_string());
+    } else {
+        panic!("expected a From header");
+    }
+
+    let header = parse_from_header::<VerboseError<&[u8]>>(b"From: Alice  Smith <sip:alice@a>\r\n").unwrap().1;
+    if let Header::From(named) = header {
+        assert_eq!(Some("Alice Smith"), named.display_name.as_deref());
+    } else {
+        panic!("expected a From header");
+    }
+
+    assert!(parse_from_header::<VerboseError<&[u8]>>(b"From: Alice sip:alice@a\r\n").is_err());
+}
+
+#[test]
+fn escaped_name() {
+    let input = b"From: \"Bob \\\"the boss\\\" Smith \\\\ Co\" <sip:bob@biloxi.com>;tag=a6c85cf\r\n";
+    let header = parse_from_header::<VerboseError<&[u8]>>(input).unwrap().1;
+    if let Header::From(named) = &header {
+        assert_eq!(Some("Bob \"the boss\" Smith \\ Co"), named.display_name.as_deref());
+    } else {
+        panic!("expected a From header");
+    }
+    assert_eq!(
+        "From: \"Bob \\\"the boss\\\" Smith \\\\ Co\" <sip:bob@biloxi.com>;tag=a6c85cf",
+        format!("{}", header)
+    );
+}
+
+#[test]
+fn utf8_name() {
+    let input = "From: \"Иван Петров\" <sip:ivan@example.ru>;tag=1\r\n";
+    let header = parse_from_header::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+    if let Header::From(named) = &header {
+        assert_eq!(Some("Иван Петров"), named.display_name.as_deref());
+    } else {
+        panic!("expected a From header");
+    }
+    assert_eq!(input.trim_end(), format!("{}", header));
+}
+
+#[test]
+fn encoded_words() {
+    assert_eq!("Иван Петров", decode_encoded_words("=?UTF-8?B?0JjQstCw0L0=?= =?utf-8?b?INCf0LXRgtGA0L7Qsg==?="));
+    assert_eq!("André Dupont", decode_encoded_words("=?ISO-8859-1?Q?Andr=E9?= Dupont"));
+    assert_eq!("plain =?bogus", decode_encoded_words("plain =?bogus"));
+
+    let header = parse_from_header::<VerboseError<&[u8]>>(b"From: \"=?UTF-8?B?0JjQstCw0L0=?=\" <sip:ivan@example.ru>\r\n").unwrap().1;
+    if let Header::From(named) = header {
+        assert_eq!(Some("Иван".to_string()), named.decoded_display_name());
+    } else {
+        panic!("expected a From header");
+    }
+}
@@ -5,13 +5,24 @@ use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::InReplyTo("call@id.com".into());
+    let header = Header::InReplyTo(vec!["call@id.com".into()]);
     assert_eq!("In-Reply-To: call@id.com".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::InReplyTo("call@id.com".into());
+    let header = Header::InReplyTo(vec!["call@id.com".into()]);
     assert_eq!(Ok((remains.as_ref(), header)), parse_in_reply_to_header::<VerboseError<&[u8]>>(b"In-Reply-To: call@id.com\r\n"));
 }
+
+#[test]
+fn round_trip() {
+    let input = b"In-Reply-To: 70710@saturn.bell-tel.com, 17320@saturn.bell-tel.com\r\n";
+    let (_, header) = parse_in_reply_to_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(
+        Header::InReplyTo(vec!["70710@saturn.bell-tel.com".into(), "17320@saturn.bell-tel.com".into()]),
+        header
+    );
+    assert_eq!(format!("{}\r\n", header).as_bytes(), &input[..]);
+}
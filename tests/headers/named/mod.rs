@@ -1,5 +1,8 @@
 mod contact;
 mod from;
 mod in_reply_to;
+mod refer_to;
 mod reply_to;
 mod to;
+#[cfg(feature = "serde")]
+mod serialize;
@@ -0,0 +1,43 @@
+use libsip::*;
+use libsip::headers::parse::{parse_refer_to_header, parse_referred_by_header};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let uri = Uri::sip(domain!("chicago.com")).auth(uri_auth!("carol"));
+    let header = Header::ReferTo(named_header!(uri));
+    assert_eq!("Refer-To: sip:carol@chicago.com".to_string(), format!("{}", header));
+
+    let uri = Uri::sip(domain!("atlanta.example.com")).auth(uri_auth!("alice"));
+    let header = Header::ReferredBy(named_header!(uri, "Alice"));
+    assert_eq!("Referred-By: Alice <sip:alice@atlanta.example.com>".to_string(), format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let uri = Uri::sip(domain!("chicago.com")).auth(uri_auth!("carol"));
+    let header = Header::ReferTo(named_header!(uri));
+    assert_eq!(Ok((remains.as_ref(), header)), parse_refer_to_header::<VerboseError<&[u8]>>(b"r: <sip:carol@chicago.com>\r\n"));
+
+    let uri = Uri::sip(domain!("atlanta.example.com")).auth(uri_auth!("alice"));
+    let header = Header::ReferredBy(named_header!(uri));
+    assert_eq!(Ok((remains.as_ref(), header)), parse_referred_by_header::<VerboseError<&[u8]>>(b"b: <sip:alice@atlanta.example.com>\r\n"));
+}
+
+#[test]
+fn read_embedded_replaces() {
+    let input = "Refer-To: <sip:carol@chicago.com?Replaces=12345%40192.0.2.1%3Bfrom-tag%3D1234%3Bto-tag%3D5678>";
+    let line = format!("{}\r\n", input);
+    let (rest, header) = parse_header::<VerboseError<&[u8]>>(line.as_bytes()).unwrap();
+    assert!(rest.is_empty());
+    match &header {
+        Header::ReferTo(refer) => assert_eq!(
+            vec![("Replaces".to_string(), "12345@192.0.2.1;from-tag=1234;to-tag=5678".to_string())],
+            refer.uri.headers
+        ),
+        _ => panic!("expected a Refer-To header"),
+    }
+    assert_eq!(input, format!("{}", header));
+}
@@ -32,3 +32,17 @@ fn read() {
     let header = Header::ReplyTo(named_header!(uri));
     assert_eq!(Ok((remains.as_ref(), header)), parse_reply_to_header::<VerboseError<&[u8]>>(b"Reply-To: <sip:guy@example.com>\r\n"));
 }
+
+#[test]
+fn round_trip() {
+    let input = b"Reply-To: Bob <sip:bob@biloxi.com>\r\n";
+    let (_, header) = parse_header::<VerboseError<&[u8]>>(input).unwrap();
+    match &header {
+        Header::ReplyTo(reply_to) => {
+            assert_eq!(Some("Bob"), reply_to.display_name.as_deref());
+            assert_eq!("sip:bob@biloxi.com", reply_to.uri.to_string());
+        },
+        _ => panic!("expected a Reply-To header"),
+    }
+    assert_eq!(format!("{}\r\n", header).as_bytes(), &input[..]);
+}
@@ -0,0 +1,21 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+#[test]
+fn named_header_json() {
+    let uri = parse_uri::<VerboseError<&[u8]>>(b"sips:alice:secret@atlanta.com:5061;transport=TCP;lr?subject=project").unwrap().1;
+    let header = named_header!(uri, "Alice Smith")
+        .with_tag("1928301774")
+        .with_expires(3600);
+
+    let json = serde_json::to_string(&header).unwrap();
+    assert_eq!(header, serde_json::from_str::<NamedHeader>(&json).unwrap());
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(value["uri"].is_object());
+
+    let header = Header::Contact(header.into());
+    let json = serde_json::to_string(&header).unwrap();
+    assert_eq!(header, serde_json::from_str::<Header>(&json).unwrap());
+}
@@ -0,0 +1,27 @@
+use libsip::*;
+
+#[test]
+fn canonical_names() {
+    let uri = Uri::sip(domain!("example.com"));
+    let via = ViaHeader::new(Uri::new_schemaless(domain!("example.com")), Transport::Udp);
+    let headers = vec![
+        (Header::CallId("a84b4c76e66710".into()), "Call-ID"),
+        (Header::CSeq(1, Method::Invite), "CSeq"),
+        (Header::MaxForwards(70), "Max-Forwards"),
+        (Header::Via(via), "Via"),
+        (Header::To(NamedHeader::new(uri.clone())), "To"),
+        (Header::RecordRoute(vec![NamedHeader::new(uri.clone())]), "Record-Route"),
+        (Header::PAssertedIdentity(vec![NamedHeader::new(uri)]), "P-Asserted-Identity"),
+        (Header::WwwAuthenticate(AuthChallenge::new("atlanta.com", "84a4cc6f3082121f32b42a2187831a9e")), "WWW-Authenticate"),
+        (Header::MimeVersion("1.0".into()), "MIME-Version"),
+        (Header::MinSE(90), "Min-SE"),
+        (Header::ContentLength(0), "Content-Length"),
+        (Header::XFsSendingMessage("f9c4adc8".into()), "X-FS-Sending-Message"),
+        (Header::Other("X-Custom".into(), "1".into()), "X-Custom"),
+    ];
+    for (header, name) in headers {
+        assert_eq!(name, header.name());
+        let written = header.to_string();
+        assert_eq!(Some(name), written.split(": ").next(), "{}", written);
+    }
+}
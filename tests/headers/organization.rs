@@ -20,3 +20,11 @@ fn read() {
         parse_organization_header::<VerboseError<&[u8]>>(b"Organization: Softphone 1.0\r\n")
     );
 }
+
+#[test]
+fn round_trip() {
+    let input = b"Organization: Boxes by Bob, Inc.\r\n";
+    let (_, header) = parse_organization_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(Header::Organization("Boxes by Bob, Inc.".into()), header);
+    assert_eq!(format!("{}\r\n", header).as_bytes(), &input[..]);
+}
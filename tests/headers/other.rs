@@ -0,0 +1,34 @@
+use libsip::*;
+use libsip::headers::parse::parse_other_header;
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::Other("P-Asserted-Identity".into(), "\"Cullen Jennings\" <sip:fluffy@cisco.com>".into());
+    assert_eq!("P-Asserted-Identity: \"Cullen Jennings\" <sip:fluffy@cisco.com>", format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::Other("X-Vendor.Ext_1".into(), "a=b;c, d".into());
+    assert_eq!(Ok((remains.as_ref(), header)), parse_other_header::<VerboseError<&[u8]>>(b"X-Vendor.Ext_1 :  a=b;c, d\r\n"));
+
+    let header = Header::Other("X-Empty".into(), "".into());
+    assert_eq!(Ok((remains.as_ref(), header)), parse_other_header::<VerboseError<&[u8]>>(b"X-Empty:\r\n"));
+}
+
+#[test]
+fn round_trip() {
+    let lines = [
+        "P-Charging-Vector: icid-value=1234bc9876e; icid-generated-at=192.0.6.8",
+        "p-access-network-info: 3GPP-UTRAN-TDD; utran-cell-id-3gpp=23456789ABCDE",
+        "X-Custom-Header: value\twith tab, and \"quoted, text\"",
+    ];
+    for line in lines.iter() {
+        let input = format!("{}\r\n", line);
+        let (_, header) = parse_header::<VerboseError<&[u8]>>(input.as_bytes()).unwrap();
+        assert_eq!(*line, format!("{}", header));
+    }
+}
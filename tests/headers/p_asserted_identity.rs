@@ -0,0 +1,52 @@
+use libsip::{
+    headers::parse::{parse_p_asserted_identity_header, parse_p_preferred_identity_header},
+    *,
+};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::PAssertedIdentity(vec![
+        NamedHeader::new(Uri::sip(domain!("example.com")).auth(uri_auth!("alice"))).name("Alice"),
+        NamedHeader::new(Uri::tel("+15551234567")),
+    ]);
+    assert_eq!(
+        "P-Asserted-Identity: Alice <sip:alice@example.com>, tel:+15551234567".to_string(),
+        format!("{}", header)
+    );
+
+    let header = Header::PPreferredIdentity(vec![NamedHeader::new(Uri::sip(domain!("example.com")).auth(uri_auth!("alice")))]);
+    assert_eq!("P-Preferred-Identity: sip:alice@example.com".to_string(), format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::PAssertedIdentity(vec![
+        NamedHeader::new(Uri::sip(domain!("example.com")).auth(uri_auth!("alice"))).name("Alice"),
+        NamedHeader::new(Uri::tel("+15551234567")),
+    ]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_p_asserted_identity_header::<VerboseError<&[u8]>>(
+            b"P-Asserted-Identity: \"Alice\" <sip:alice@example.com>, <tel:+15551234567>\r\n"
+        )
+    );
+
+    let header = Header::PPreferredIdentity(vec![NamedHeader::new(Uri::tel("+15551234567"))]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_p_preferred_identity_header::<VerboseError<&[u8]>>(b"P-Preferred-Identity: <tel:+15551234567>\r\n")
+    );
+}
+
+#[test]
+fn recognized_by_name() {
+    let remains = vec![];
+    let header = Header::PAssertedIdentity(vec![NamedHeader::new(Uri::tel("+15551234567"))]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_header::<VerboseError<&[u8]>>(b"p-asserted-identity: <tel:+15551234567>\r\n")
+    );
+}
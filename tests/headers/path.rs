@@ -0,0 +1,43 @@
+use libsip::{headers::parse::{parse_path_header, parse_supported_header}, *};
+
+use nom::error::VerboseError;
+
+use crate::headers::assert_round_trip;
+
+#[test]
+fn write() {
+    let header = Header::Path(vec![
+        NamedHeader::new(Uri::sip(domain!("p1.example.com")).parameter(UriParam::Lr)),
+    ]);
+    assert_eq!("Path: <sip:p1.example.com;lr>".to_string(), format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::Path(vec![
+        NamedHeader::new(Uri::sip(domain!("p3.middle.example.com")).parameter(UriParam::Lr)),
+        NamedHeader::new(Uri::sip(domain!("p1.example.com")).parameter(UriParam::Lr)),
+    ]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_path_header::<VerboseError<&[u8]>>(b"Path: <sip:p3.middle.example.com;lr>, <sip:p1.example.com;lr>\r\n")
+    );
+}
+
+#[test]
+fn round_trip() {
+    let header = assert_round_trip("Path: <sip:P3.middle.example.com;lr>, <sip:P1.EXAMPLE.COM;lr>");
+
+    let mut headers = Headers::new();
+    headers.push(header);
+    let hosts: Vec<String> = headers.paths().iter().map(|path| path.uri.host.to_string()).collect();
+    assert_eq!(vec!["P3.middle.example.com", "P1.EXAMPLE.COM"], hosts);
+    assert!(headers.paths().iter().all(|path| path.uri.lr()));
+}
+
+#[test]
+fn option_tag() {
+    let header = parse_supported_header::<VerboseError<&[u8]>>(b"Supported: path, outbound\r\n").unwrap().1;
+    assert_eq!(Header::Supported(vec!["path".into(), "outbound".into()]), header);
+}
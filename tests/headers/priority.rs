@@ -1,19 +1,40 @@
-use libsip::{headers::parse::parse_priority_header, Header};
+use libsip::{headers::parse::parse_priority_header, Header, Priority};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::Priority("Softphone 1.0".into());
-    assert_eq!("Priority: Softphone 1.0".to_string(), format!("{}", header));
+    let header = Header::Priority(Priority::NonUrgent);
+    assert_eq!("Priority: non-urgent".to_string(), format!("{}", header));
+    let header = Header::Priority(Priority::Extension("x-low".into()));
+    assert_eq!("Priority: x-low".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Priority("Softphone 1.0".into());
+    let header = Header::Priority(Priority::Emergency);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_priority_header::<VerboseError<&[u8]>>(b"Priority: Softphone 1.0\r\n")
+        parse_priority_header::<VerboseError<&[u8]>>(b"Priority: emergency\r\n")
     );
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Priority(Priority::Extension("x-low".into())))),
+        parse_priority_header::<VerboseError<&[u8]>>(b"Priority: x-low\r\n")
+    );
+}
+
+#[test]
+fn read_case_insensitive() {
+    let values = vec![
+        ("URGENT", Priority::Urgent, "urgent"),
+        ("Normal", Priority::Normal, "normal"),
+        ("Non-Urgent", Priority::NonUrgent, "non-urgent"),
+    ];
+    for (input, priority, canonical) in values {
+        let input = format!("Priority: {}\r\n", input);
+        let (_, header) = parse_priority_header::<VerboseError<&[u8]>>(input.as_bytes()).unwrap();
+        assert_eq!(Header::Priority(priority), header);
+        assert_eq!(format!("Priority: {}", canonical), format!("{}", header));
+    }
 }
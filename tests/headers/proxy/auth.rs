@@ -1,12 +1,14 @@
-use libsip::{headers::parse::parse_proxy_authenticate_header, Header};
+use libsip::{headers::parse::parse_proxy_authenticate_header, AuthChallenge, Header};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::ProxyAuthenticate("call@id.com".into());
+    let mut challenge = AuthChallenge::new("atlanta.com", "wf84f1ceczx41ae6cbe5aea9c8e88d359");
+    challenge.qop = vec!["auth".into()];
+    let header = Header::ProxyAuthenticate(challenge);
     assert_eq!(
-        "Proxy-Authenticate: call@id.com".to_string(),
+        "Proxy-Authenticate: Digest realm=\"atlanta.com\", nonce=\"wf84f1ceczx41ae6cbe5aea9c8e88d359\", qop=auth".to_string(),
         format!("{}", header)
     );
 }
@@ -14,9 +16,14 @@ fn write() {
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::ProxyAuthenticate("call@id.com".into());
+    let mut challenge = AuthChallenge::new("atlanta.com", "wf84f1ceczx41ae6cbe5aea9c8e88d359");
+    challenge.opaque = Some("".into());
+    challenge.algorithm = Some("MD5".into());
+    challenge.qop = vec!["auth".into()];
+    challenge.params.push(("domain".into(), "sip:ss1.carrier.com".into()));
+    let header = Header::ProxyAuthenticate(challenge);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_proxy_authenticate_header::<VerboseError<&[u8]>>(b"Proxy-Authenticate: call@id.com\r\n")
+        parse_proxy_authenticate_header::<VerboseError<&[u8]>>(b"Proxy-Authenticate: Digest realm=\"atlanta.com\", domain=\"sip:ss1.carrier.com\", qop=\"auth\", nonce=\"wf84f1ceczx41ae6cbe5aea9c8e88d359\", opaque=\"\", stale=FALSE, algorithm=MD5\r\n")
     );
 }
@@ -0,0 +1,52 @@
+use libsip::*;
+use libsip::headers::parse::{parse_rack_header, parse_rseq_header};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    assert_eq!("RSeq: 988789".to_string(), format!("{}", Header::RSeq(988789)));
+    let header = Header::RAck(776656, 1, Method::Invite);
+    assert_eq!("RAck: 776656 1 INVITE".to_string(), format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), Header::RSeq(988789))),
+        parse_rseq_header::<VerboseError<&[u8]>>(b"RSeq: 988789\r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), Header::RAck(776656, 1, Method::Invite))),
+        parse_rack_header::<VerboseError<&[u8]>>(b"RAck:  776656 \t1 invite\r\n")
+    );
+    assert!(parse_rack_header::<VerboseError<&[u8]>>(b"RAck: 776656 INVITE\r\n").is_err());
+}
+
+#[test]
+fn round_trip() {
+    let input = b"RAck: 776656 1 INVITE\r\n";
+    let (_, header) = parse_rack_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(Header::RAck(776656, 1, Method::Invite), header);
+    assert_eq!(format!("{}\r\n", header).as_bytes(), &input[..]);
+}
+
+#[test]
+fn rack_from_provisional() {
+    let response = ResponseGenerator::new()
+        .code(183)
+        .header(Header::CSeq(1, Method::Invite))
+        .header(Header::Require(vec!["100rel".into()]))
+        .header(Header::RSeq(776656))
+        .build()
+        .unwrap();
+    assert_eq!(Some(Header::RAck(776656, 1, Method::Invite)), response.rack());
+
+    let response = ResponseGenerator::new()
+        .code(180)
+        .header(Header::CSeq(1, Method::Invite))
+        .build()
+        .unwrap();
+    assert_eq!(None, response.rack());
+}
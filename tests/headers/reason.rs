@@ -0,0 +1,50 @@
+use libsip::{headers::parse::parse_reason_header, Header, Reason};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::Reason(vec![Reason::new("SIP", 200).text("Call completed")]);
+    assert_eq!("Reason: SIP;cause=200;text=\"Call completed\"".to_string(), format!("{}", header));
+
+    let header = Header::Reason(vec![Reason::new("SIP", 600).text("Busy Everywhere"), Reason::new("Q.850", 17)]);
+    assert_eq!(
+        "Reason: SIP;cause=600;text=\"Busy Everywhere\", Q.850;cause=17".to_string(),
+        format!("{}", header)
+    );
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::Reason(vec![Reason::new("Q.850", 16).text("Normal")]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_reason_header::<VerboseError<&[u8]>>(b"Reason: Q.850;cause=16;text=\"Normal\"\r\n")
+    );
+
+    let header = Header::Reason(vec![
+        Reason::new("SIP", 200).text("Call completed"),
+        Reason::new("Q.850", 16).text("Normal call clearing"),
+    ]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_reason_header::<VerboseError<&[u8]>>(
+            b"Reason: SIP;cause=200;text=\"Call completed\", Q.850 ;text=\"Normal call clearing\";cause=16\r\n"
+        )
+    );
+}
+
+#[test]
+fn read_without_cause() {
+    assert!(parse_reason_header::<VerboseError<&[u8]>>(b"Reason: SIP;text=\"Call completed\"\r\n").is_err());
+    assert!(parse_reason_header::<VerboseError<&[u8]>>(b"Reason: SIP;cause=abc\r\n").is_err());
+}
+
+#[test]
+fn round_trip_escaped_text() {
+    let input = b"Reason: SIP;cause=200;text=\"say \\\"hi\\\" \\\\ bye\"\r\n";
+    let (_, header) = parse_reason_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(Header::Reason(vec![Reason::new("SIP", 200).text("say \"hi\" \\ bye")]), header);
+    assert_eq!(format!("{}\r\n", header).as_bytes(), &input[..]);
+}
@@ -1,12 +1,14 @@
-use libsip::{headers::parse::parse_record_route_header, Header};
+use libsip::{headers::parse::parse_record_route_header, *};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::RecordRoute("Softphone 1.0".into());
+    let header = Header::RecordRoute(vec![
+        NamedHeader::new(Uri::sip(domain!("p1.example.com")).parameter(UriParam::Lr)),
+    ]);
     assert_eq!(
-        "Record-Route: Softphone 1.0".to_string(),
+        "Record-Route: <sip:p1.example.com;lr>".to_string(),
         format!("{}", header)
     );
 }
@@ -14,9 +16,12 @@ fn write() {
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::RecordRoute("Softphone 1.0".into());
+    let header = Header::RecordRoute(vec![
+        NamedHeader::new(Uri::sip(domain!("server10.biloxi.com")).parameter(UriParam::Lr)),
+        NamedHeader::new(Uri::sip(domain!("bigbox3.site3.atlanta.com")).parameter(UriParam::Lr)),
+    ]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_record_route_header::<VerboseError<&[u8]>>(b"Record-Route: Softphone 1.0\r\n")
+        parse_record_route_header::<VerboseError<&[u8]>>(b"Record-Route: <sip:server10.biloxi.com;lr>, <sip:bigbox3.site3.atlanta.com;lr>\r\n")
     );
 }
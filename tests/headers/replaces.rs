@@ -0,0 +1,46 @@
+use libsip::*;
+use libsip::headers::parse::parse_replaces_header;
+
+use nom::error::VerboseError;
+
+use crate::headers::assert_round_trip;
+
+#[test]
+fn write() {
+    let header = Header::Replaces(Replaces::new("12345@192.0.2.1", "abc", "def"));
+    assert_eq!("Replaces: 12345@192.0.2.1;from-tag=abc;to-tag=def".to_string(), format!("{}", header));
+
+    let header = Header::Replaces(Replaces::new("98732@sip.example.com", "r33th4x0r", "ff87ff").early_only());
+    assert_eq!(
+        "Replaces: 98732@sip.example.com;from-tag=r33th4x0r;to-tag=ff87ff;early-only".to_string(),
+        format!("{}", header)
+    );
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::Replaces(Replaces::new("12345@192.0.2.1", "abc", "def"));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_replaces_header::<VerboseError<&[u8]>>(b"Replaces: 12345@192.0.2.1;from-tag=abc;to-tag=def\r\n")
+    );
+
+    let header = assert_round_trip("Replaces: 425928@bobster.example.org;early-only;to-tag=6472;from-tag=7743");
+    match &header {
+        Header::Replaces(replaces) => {
+            assert_eq!("425928@bobster.example.org", replaces.call_id);
+            assert_eq!(Some("7743"), replaces.from_tag());
+            assert_eq!(Some("6472"), replaces.to_tag());
+            assert!(replaces.is_early_only());
+        },
+        _ => panic!("expected a Replaces header"),
+    }
+}
+
+#[test]
+fn read_missing_tags() {
+    assert!(parse_replaces_header::<VerboseError<&[u8]>>(b"Replaces: 12345@192.0.2.1;from-tag=abc\r\n").is_err());
+    assert!(parse_replaces_header::<VerboseError<&[u8]>>(b"Replaces: 12345@192.0.2.1;to-tag=def\r\n").is_err());
+    assert!(parse_replaces_header::<VerboseError<&[u8]>>(b"Replaces: 12345@192.0.2.1\r\n").is_err());
+}
@@ -4,16 +4,16 @@ use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::Require("Softphone 1.0".into());
-    assert_eq!("Require: Softphone 1.0".to_string(), format!("{}", header));
+    let header = Header::Require(vec!["100rel".into(), "timer".into()]);
+    assert_eq!("Require: 100rel, timer".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Require("Softphone 1.0".into());
+    let header = Header::Require(vec!["100rel".into(), "sec-agree".into()]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_require_header::<VerboseError<&[u8]>>(b"Require: Softphone 1.0\r\n")
+        parse_require_header::<VerboseError<&[u8]>>(b"Require: 100rel ,sec-agree\r\n")
     );
 }
@@ -1,12 +1,15 @@
-use libsip::{headers::parse::parse_retry_after_header, Header};
+use libsip::{headers::parse::parse_retry_after_header, Header, RetryAfter};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::RetryAfter("Softphone 1.0".into());
+    let header = Header::RetryAfter(RetryAfter::new(18000));
+    assert_eq!("Retry-After: 18000".to_string(), format!("{}", header));
+
+    let header = Header::RetryAfter(RetryAfter::new(120).comment("I'm in a meeting").duration(3600));
     assert_eq!(
-        "Retry-After: Softphone 1.0".to_string(),
+        "Retry-After: 120 (I'm in a meeting);duration=3600".to_string(),
         format!("{}", header)
     );
 }
@@ -14,9 +17,30 @@ fn write() {
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::RetryAfter("Softphone 1.0".into());
+    let header = Header::RetryAfter(RetryAfter::new(18000).duration(3600));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_retry_after_header::<VerboseError<&[u8]>>(b"Retry-After: 18000;duration=3600\r\n")
+    );
+
+    let header = Header::RetryAfter(RetryAfter::new(120).comment("I'm in a meeting").duration(3600));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_retry_after_header::<VerboseError<&[u8]>>(b"Retry-After: 120 (I'm in a meeting) ;duration=3600\r\n")
+    );
+
+    let mut retry = RetryAfter::new(5).comment("busy (really)");
+    retry.params.push(("reason".into(), Some("load".into())));
+    let header = Header::RetryAfter(retry);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_retry_after_header::<VerboseError<&[u8]>>(b"Retry-After: Softphone 1.0\r\n")
+        parse_retry_after_header::<VerboseError<&[u8]>>(b"Retry-After: 5 (busy (really));reason=load\r\n")
     );
 }
+
+#[test]
+fn read_invalid() {
+    assert!(parse_retry_after_header::<VerboseError<&[u8]>>(b"Retry-After: soon\r\n").is_err());
+    assert!(parse_retry_after_header::<VerboseError<&[u8]>>(b"Retry-After: 120 (unterminated\r\n").is_err());
+    assert!(parse_retry_after_header::<VerboseError<&[u8]>>(b"Retry-After: 120;duration=long\r\n").is_err());
+}
@@ -1,19 +1,35 @@
-use libsip::{headers::parse::parse_route_header, Header};
+use libsip::{headers::parse::parse_route_header, *};
 
 use nom::error::VerboseError;
 
+use crate::headers::assert_round_trip;
+
 #[test]
 fn write() {
-    let header = Header::Route("Softphone 1.0".into());
-    assert_eq!("Route: Softphone 1.0".to_string(), format!("{}", header));
+    let header = Header::Route(vec![
+        NamedHeader::new(Uri::sip(domain!("p1.example.com")).parameter(UriParam::Lr)),
+        NamedHeader::new(Uri::sip(domain!("p2.example.com")).parameter(UriParam::Lr)),
+    ]);
+    assert_eq!(
+        "Route: <sip:p1.example.com;lr>, <sip:p2.example.com;lr>".to_string(),
+        format!("{}", header)
+    );
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Route("Softphone 1.0".into());
+    let header = Header::Route(vec![
+        NamedHeader::new(Uri::sip(domain!("p1.example.com")).parameter(UriParam::Lr)),
+        NamedHeader::new(Uri::sip(domain!("p2.example.com")).parameter(UriParam::Lr)).name("Proxy, Two"),
+    ]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_route_header::<VerboseError<&[u8]>>(b"Route: Softphone 1.0\r\n")
+        parse_route_header::<VerboseError<&[u8]>>(b"Route: <sip:p1.example.com;lr> ,\"Proxy, Two\" <sip:p2.example.com;lr>\r\n")
     );
 }
+
+#[test]
+fn round_trip() {
+    assert_round_trip("Route: <sip:p1.example.com;lr>, \"Proxy, Two\" <sip:p2.example.com;transport=udp;lr>");
+}
@@ -1,4 +1,4 @@
-use libsip::{headers::parse::parse_server_header, Header};
+use libsip::{headers::parse::parse_server_header, parse_header, Header};
 
 use nom::error::VerboseError;
 
@@ -17,3 +17,10 @@ fn read() {
         parse_server_header::<VerboseError<&[u8]>>(b"Server: Softphone 1.0\r\n")
     );
 }
+
+#[test]
+fn round_trip() {
+    let (_, header) = parse_header::<VerboseError<&[u8]>>(b"server:HomeServer/2 (SIP Edge; rev 7)\r\n").unwrap();
+    assert_eq!(Header::Server("HomeServer/2 (SIP Edge; rev 7)".into()), header);
+    assert_eq!("Server: HomeServer/2 (SIP Edge; rev 7)", format!("{}", header));
+}
@@ -0,0 +1,38 @@
+use libsip::{headers::parse::parse_service_route_header, *};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::ServiceRoute(vec![
+        NamedHeader::new(Uri::sip(domain!("orig.scscf.home.net")).parameter(UriParam::Lr)),
+    ]);
+    assert_eq!("Service-Route: <sip:orig.scscf.home.net;lr>".to_string(), format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::ServiceRoute(vec![
+        NamedHeader::new(Uri::sip(domain!("P2.HOME.EXAMPLE.COM")).parameter(UriParam::Lr)),
+        NamedHeader::new(Uri::sip(domain!("HSP.HOME.EXAMPLE.COM")).parameter(UriParam::Lr)),
+    ]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_service_route_header::<VerboseError<&[u8]>>(b"Service-Route: <sip:P2.HOME.EXAMPLE.COM;lr>,<sip:HSP.HOME.EXAMPLE.COM;lr>\r\n")
+    );
+}
+
+#[test]
+fn route_set() {
+    let input = b"SIP/2.0 200 OK\r\n\
+Service-Route: <sip:P2.HOME.EXAMPLE.COM;lr>\r\n\
+Service-Route: <sip:HSP.HOME.EXAMPLE.COM;lr>, <sip:scscf.home.example.com;lr>\r\n\
+Content-Length: 0\r\n\r\n";
+    let (_, response) = parse_message::<VerboseError<&[u8]>>(input).unwrap();
+    let routes: Vec<String> = response.headers().service_routes().iter().map(|route| route.uri.to_string()).collect();
+    assert_eq!(
+        vec!["sip:P2.HOME.EXAMPLE.COM;lr", "sip:HSP.HOME.EXAMPLE.COM;lr", "sip:scscf.home.example.com;lr"],
+        routes
+    );
+}
@@ -0,0 +1,48 @@
+use libsip::*;
+use libsip::headers::parse::{parse_min_se_header, parse_session_expires_header};
+
+use nom::error::VerboseError;
+
+#[test]
+fn write() {
+    let header = Header::SessionExpires(SessionExpires::new(1800).refresher(Refresher::Uac));
+    assert_eq!("Session-Expires: 1800;refresher=uac".to_string(), format!("{}", header));
+
+    let header = Header::SessionExpires(SessionExpires::new(4000));
+    assert_eq!("Session-Expires: 4000".to_string(), format!("{}", header));
+
+    let header = Header::MinSE(90);
+    assert_eq!("Min-SE: 90".to_string(), format!("{}", header));
+}
+
+#[test]
+fn read() {
+    let remains = vec![];
+    let header = Header::SessionExpires(SessionExpires::new(1800).refresher(Refresher::Uac));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_session_expires_header::<VerboseError<&[u8]>>(b"Session-Expires: 1800;refresher=uac\r\n")
+    );
+
+    let header = Header::SessionExpires(SessionExpires::new(4000).refresher(Refresher::Uas));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_session_expires_header::<VerboseError<&[u8]>>(b"x: 4000 ;refresher=UAS\r\n")
+    );
+
+    let header = Header::MinSE(90);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_min_se_header::<VerboseError<&[u8]>>(b"Min-SE: 90\r\n")
+    );
+    assert_eq!(
+        Ok((remains.as_ref(), Header::SessionExpires(SessionExpires::new(90)))),
+        parse_header::<VerboseError<&[u8]>>(b"X: 90\r\n")
+    );
+}
+
+#[test]
+fn read_invalid_refresher() {
+    assert!(parse_session_expires_header::<VerboseError<&[u8]>>(b"Session-Expires: 1800;refresher=proxy\r\n").is_err());
+    assert!(parse_session_expires_header::<VerboseError<&[u8]>>(b"Session-Expires: 1800;refresher\r\n").is_err());
+}
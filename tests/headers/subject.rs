@@ -17,3 +17,43 @@ fn read() {
         parse_subject_header::<VerboseError<&[u8]>>(b"Subject: Softphone 1.0\r\n")
     );
 }
+
+#[test]
+fn read_compact() {
+    let remains = vec![];
+    let header = Header::Subject("Project X: status, agenda & notes!".into());
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_subject_header::<VerboseError<&[u8]>>(b"s: Project X: status, agenda & notes!\r\n")
+    );
+}
+
+#[test]
+fn read_utf8() {
+    let remains = vec![];
+    let header = Header::Subject("Réunion à 15h — café".into());
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_subject_header::<VerboseError<&[u8]>>("Subject: Réunion à 15h — café\r\n".as_bytes())
+    );
+}
+
+#[test]
+fn round_trip() {
+    let input = b"Subject: Need more boxes\r\n";
+    let (_, header) = parse_subject_header::<VerboseError<&[u8]>>(input).unwrap();
+    assert_eq!(Header::Subject("Need more boxes".into()), header);
+    assert_eq!(format!("{}\r\n", header).as_bytes(), &input[..]);
+}
+
+#[test]
+fn read_in_message() {
+    let input = b"MESSAGE sip:bob@biloxi.com SIP/2.0\r\n\
+s: Need more boxes\r\n\
+Supported: timer\r\n\
+Server: softphone\r\n\
+Content-Length: 0\r\n\r\n";
+    let message = libsip::parse_message::<VerboseError<&[u8]>>(input).unwrap().1;
+    assert_eq!(Some(&Header::Subject("Need more boxes".into())), message.header("subject"));
+    assert_eq!(Some(&Header::Server("softphone".into())), message.header("Server"));
+}
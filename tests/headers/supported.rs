@@ -6,9 +6,10 @@ use nom::error::VerboseError;
 fn write() {
     let header = Header::Supported(vec!["allowed".into(), "events".into()]);
     assert_eq!(
-        "Supported: allowed,events".to_string(),
+        "Supported: allowed, events".to_string(),
         format!("{}", header)
     );
+    assert_eq!("Supported: ".to_string(), format!("{}", Header::Supported(vec![])));
 }
 
 #[test]
@@ -20,3 +21,22 @@ fn read() {
         parse_supported_header::<VerboseError<&[u8]>>(b"Supported: allowed, events\r\n")
     );
 }
+
+#[test]
+fn read_compact() {
+    let remains = vec![];
+    let header = Header::Supported(vec!["100rel".into(), "timer".into(), "path".into()]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_supported_header::<VerboseError<&[u8]>>(b"k: 100rel, timer, path\r\n")
+    );
+}
+
+#[test]
+fn read_empty() {
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Supported(vec![]))),
+        parse_supported_header::<VerboseError<&[u8]>>(b"Supported: \r\n")
+    );
+}
@@ -4,16 +4,31 @@ use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::Timestamp(60);
+    let header = Header::Timestamp(60.0, None);
     assert_eq!("Timestamp: 60".to_string(), format!("{}", header));
+
+    let header = Header::Timestamp(54.0, Some(1.5));
+    assert_eq!("Timestamp: 54 1.5".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Timestamp(60);
+    let header = Header::Timestamp(54.0, None);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_timestamp_header::<VerboseError<&[u8]>>(b"Timestamp: 54\r\n")
+    );
+
+    let header = Header::Timestamp(54.0, Some(1.5));
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_timestamp_header::<VerboseError<&[u8]>>(b"Timestamp: 54 1.5\r\n")
+    );
+
+    let header = Header::Timestamp(1_602_681_600.25, Some(0.0));
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_timestamp_header::<VerboseError<&[u8]>>(b"Timestamp: 60\r\n")
+        parse_timestamp_header::<VerboseError<&[u8]>>(b"Timestamp: 1602681600.25  0.\r\n")
     );
 }
@@ -4,19 +4,16 @@ use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::Unsupported("Softphone 1.0".into());
-    assert_eq!(
-        "Unsupported: Softphone 1.0".to_string(),
-        format!("{}", header)
-    );
+    let header = Header::Unsupported(vec!["100rel".into(), "timer".into()]);
+    assert_eq!("Unsupported: 100rel, timer".to_string(), format!("{}", header));
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Unsupported("Softphone 1.0".into());
+    let header = Header::Unsupported(vec!["100rel".into(), "sec-agree".into()]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_unsupported_header::<VerboseError<&[u8]>>(b"Unsupported: Softphone 1.0\r\n")
+        parse_unsupported_header::<VerboseError<&[u8]>>(b"Unsupported: 100rel ,sec-agree\r\n")
     );
 }
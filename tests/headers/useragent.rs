@@ -1,4 +1,4 @@
-use libsip::{headers::parse::parse_useragent_header, Header};
+use libsip::{headers::parse::parse_useragent_header, parse_header, Header};
 
 use nom::error::VerboseError;
 
@@ -20,3 +20,17 @@ fn read() {
         parse_useragent_header::<VerboseError<&[u8]>>(b"User-Agent: Softphone 1.0\r\n")
     );
 }
+
+#[test]
+fn round_trip() {
+    let lines = [
+        "User-Agent: MyPhone/1.2 (build 99)",
+        "User-Agent: Vendor-X/4.0.1 (Linux; armv7) libfoo/2 (comment (nested))",
+    ];
+    for line in lines.iter() {
+        let input = format!("{}\r\n", line);
+        let (_, header) = parse_header::<VerboseError<&[u8]>>(input.as_bytes()).unwrap();
+        assert_eq!(Header::UserAgent(line[12..].into()), header);
+        assert_eq!(*line, format!("{}", header));
+    }
+}
@@ -3,6 +3,8 @@ use libsip::headers::parse::parse_via_header;
 
 use nom::error::VerboseError;
 
+use crate::headers::assert_round_trip;
+
 #[test]
 fn write() {
     let header = ViaHeader {
@@ -35,7 +37,7 @@ fn read() {
         version: Version::default(),
         transport: Transport::Udp,
         uri: Uri::new_schemaless(ip_domain!(192, 168, 1, 120))
-            .parameter(UriParam::RPort)
+            .parameter(UriParam::RPort(None))
             .parameter(UriParam::Branch("z9hG4bK7Q6y313Qrt6Uc".into())),
     };
     assert_eq!(
@@ -57,3 +59,122 @@ fn read() {
         parse_via_header::<VerboseError<&[u8]>>(input)
     );
 }
+
+#[test]
+fn read_rport_value() {
+    let input = b"Via: SIP/2.0/UDP 192.168.1.120:5060;rport=5061;received=10.0.0.2;branch=z9hG4bK776asdhds;ttl=16;maddr=224.0.1.75\r\n";
+    let remains = vec![b'\r', b'\n'];
+    let header = ViaHeader {
+        version: Version::default(),
+        transport: Transport::Udp,
+        uri: Uri::new_schemaless(ip_domain!(192, 168, 1, 120, 5060))
+            .parameter(UriParam::RPort(Some(5061)))
+            .parameter(UriParam::Received(ip_domain!(10, 0, 0, 2)))
+            .parameter(UriParam::Branch("z9hG4bK776asdhds".into()))
+            .parameter(UriParam::Ttl(16))
+            .parameter(UriParam::Maddr(ip_domain!(224, 0, 1, 75))),
+    };
+    assert_eq!(
+        Ok((remains.as_ref(), Header::Via(header))),
+        parse_via_header::<VerboseError<&[u8]>>(input)
+    );
+}
+
+#[test]
+fn accessors() {
+    let header = parse_via_header::<VerboseError<&[u8]>>(
+        b"Via: SIP/2.0/UDP pc.example.com;rport=5061;received=10.0.0.2;branch=z9hG4bKnashds8;ttl=16;maddr=224.0.1.75\r\n"
+    ).unwrap().1;
+    if let Header::Via(via) = header {
+        assert_eq!(Some("z9hG4bKnashds8"), via.branch());
+        assert_eq!(Some(&ip_domain!(10, 0, 0, 2)), via.received());
+        assert_eq!(Some(Some(5061)), via.rport());
+        assert_eq!(Some(16), via.ttl());
+        assert_eq!(Some(&ip_domain!(224, 0, 1, 75)), via.maddr());
+    } else {
+        panic!("expected a Via header");
+    }
+
+    let header = parse_via_header::<VerboseError<&[u8]>>(b"Via: SIP/2.0/UDP pc.example.com;rport\r\n").unwrap().1;
+    if let Header::Via(via) = header {
+        assert_eq!(Some(None), via.rport());
+        assert_eq!(None, via.branch());
+        assert_eq!(None, via.received());
+    } else {
+        panic!("expected a Via header");
+    }
+}
+
+#[test]
+fn round_trip() {
+    assert_round_trip("Via: SIP/2.0/UDP pc.example.com;branch=z9hG4bKnashds8");
+
+    assert_round_trip("Via: SIP/2.0/UDP pc.example.com:5060;rport=5061;branch=z9hG4bKnashds8");
+}
+
+#[test]
+fn generate_branch() {
+    let branch = gen_branch();
+    assert!(branch.starts_with("z9hG4bK"));
+    assert!(branch.len() >= "z9hG4bK".len() + 32);
+    assert_ne!(branch, gen_branch());
+
+    let header = ViaHeader::new(Uri::new_schemaless(domain!("pc33.atlanta.com")), Transport::Udp)
+        .with_branch(branch.clone());
+    assert_eq!(Some(branch.as_str()), header.branch());
+    assert_eq!(
+        format!("Via: SIP/2.0/UDP pc33.atlanta.com;branch={}", branch),
+        format!("{}", header)
+    );
+
+    let header = header.with_branch("z9hG4bKnew");
+    assert_eq!(Some("z9hG4bKnew"), header.branch());
+    assert_eq!(1, header.uri.parameters.len());
+}
+
+#[test]
+fn read_websocket() {
+    let input = b"Via: SIP/2.0/WSS df7jal23ls0d.invalid;branch=z9hG4bK56sdasks\r\n";
+    let (_, header) = parse_via_header::<VerboseError<&[u8]>>(input).unwrap();
+    match &header {
+        Header::Via(via) => assert_eq!(Transport::Wss, via.transport),
+        _ => panic!("expected a Via header"),
+    }
+    assert_eq!("Via: SIP/2.0/WSS df7jal23ls0d.invalid;branch=z9hG4bK56sdasks", format!("{}", header));
+}
+
+fn via(input: &str) -> ViaHeader {
+    match parse_via_header::<VerboseError<&[u8]>>(format!("{}\r\n", input).as_bytes()).unwrap().1 {
+        Header::Via(via) => via,
+        _ => panic!("expected a Via header"),
+    }
+}
+
+#[test]
+fn set_received_nat() {
+    let mut header = via("Via: SIP/2.0/UDP 10.0.0.1:5060;rport;branch=z9hG4bKnashds7");
+    header.set_received("192.0.2.1".parse().unwrap(), Some(9988));
+    assert_eq!(Some(&ip_domain!(192, 0, 2, 1)), header.received());
+    assert_eq!(Some(Some(9988)), header.rport());
+    assert_eq!(
+        "Via: SIP/2.0/UDP 10.0.0.1:5060;rport=9988;branch=z9hG4bKnashds7;received=192.0.2.1",
+        header.to_string()
+    );
+}
+
+#[test]
+fn set_received_without_rport() {
+    let mut header = via("Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bKnashds7");
+    header.set_received("192.0.2.1".parse().unwrap(), Some(9988));
+    assert_eq!(Some(&ip_domain!(192, 0, 2, 1)), header.received());
+    assert_eq!(None, header.rport());
+
+    let mut header = via("Via: SIP/2.0/UDP 192.0.2.1:5060;branch=z9hG4bKnashds7");
+    header.set_received("192.0.2.1".parse().unwrap(), Some(5060));
+    assert_eq!(None, header.received());
+
+    let mut header = via("Via: SIP/2.0/UDP 192.0.2.1;rport;branch=z9hG4bKnashds7");
+    header.set_received("192.0.2.1".parse().unwrap(), Some(5060));
+    assert_eq!(Some(&ip_domain!(192, 0, 2, 1)), header.received());
+    assert_eq!(Some(Some(5060)), header.rport());
+}
@@ -1,19 +1,46 @@
-use libsip::{headers::parse::parse_warning_header, Header};
+use libsip::{headers::parse::parse_warning_header, Header, Warning};
 
 use nom::error::VerboseError;
 
 #[test]
 fn write() {
-    let header = Header::Warning("Softphone 1.0".into());
-    assert_eq!("Warning: Softphone 1.0".to_string(), format!("{}", header));
+    let header = Header::Warning(vec![Warning::new(370, "devnull", "Choose a bigger pipe")]);
+    assert_eq!("Warning: 370 devnull \"Choose a bigger pipe\"".to_string(), format!("{}", header));
+
+    let header = Header::Warning(vec![
+        Warning::new(307, "isi.edu", "Session parameter 'foo' not understood"),
+        Warning::new(301, "isi.edu:5060", "Incompatible \"network\" address type"),
+    ]);
+    assert_eq!(
+        "Warning: 307 isi.edu \"Session parameter 'foo' not understood\", 301 isi.edu:5060 \"Incompatible \\\"network\\\" address type\"".to_string(),
+        format!("{}", header)
+    );
 }
 
 #[test]
 fn read() {
     let remains = vec![];
-    let header = Header::Warning("Softphone 1.0".into());
+    let header = Header::Warning(vec![Warning::new(370, "devnull", "Choose a bigger pipe")]);
+    assert_eq!(
+        Ok((remains.as_ref(), header)),
+        parse_warning_header::<VerboseError<&[u8]>>(b"Warning: 370 devnull \"Choose a bigger pipe\"\r\n")
+    );
+
+    let header = Header::Warning(vec![
+        Warning::new(307, "isi.edu", "Session parameter 'foo', not understood"),
+        Warning::new(301, "isi.edu:5060", "Incompatible \"network\" address type"),
+    ]);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_warning_header::<VerboseError<&[u8]>>(b"Warning: Softphone 1.0\r\n")
+        parse_warning_header::<VerboseError<&[u8]>>(
+            b"Warning: 307 isi.edu \"Session parameter 'foo', not understood\" , 301 isi.edu:5060 \"Incompatible \\\"network\\\" address type\"\r\n"
+        )
     );
 }
+
+#[test]
+fn read_invalid() {
+    assert!(parse_warning_header::<VerboseError<&[u8]>>(b"Warning: 37 devnull \"text\"\r\n").is_err());
+    assert!(parse_warning_header::<VerboseError<&[u8]>>(b"Warning: 370 devnull text\r\n").is_err());
+    assert!(parse_warning_header::<VerboseError<&[u8]>>(b"Warning: 370 devnull \"text\r\n").is_err());
+}
@@ -1,16 +1,18 @@
 use libsip::*;
 use nom::error::VerboseError;
-use libsip::headers::parse::parse_www_authenticate_header;
 
-use std::collections::HashMap;
+use crate::headers::assert_round_trip;
+use libsip::headers::parse::parse_www_authenticate_header;
 
 #[test]
 fn write() {
-    let mut map = HashMap::new();
-    map.insert("key".into(), "value".into());
-    let header = Header::WwwAuthenticate(AuthHeader(AuthSchema::Digest, map));
+    let mut challenge = AuthChallenge::new("atlanta.com", "84a4cc6f3082121f32b42a2187831a9e");
+    challenge.opaque = Some("5ccc069c403ebaf9f0171e9517f40e41".into());
+    challenge.algorithm = Some("MD5".into());
+    challenge.qop = vec!["auth".into()];
+    let header = Header::WwwAuthenticate(challenge);
     assert_eq!(
-        "WWW-Authenticate: Digest key=\"value\"".to_string(),
+        "WWW-Authenticate: Digest realm=\"atlanta.com\", nonce=\"84a4cc6f3082121f32b42a2187831a9e\", opaque=\"5ccc069c403ebaf9f0171e9517f40e41\", algorithm=MD5, qop=auth".to_string(),
         format!("{}", header)
     );
 }
@@ -18,11 +20,53 @@ fn write() {
 #[test]
 fn read() {
     let remains = vec![];
-    let mut map = HashMap::new();
-    map.insert("key".into(), "value".into());
-    let header = Header::WwwAuthenticate(AuthHeader(AuthSchema::Digest, map));
+    let mut challenge = AuthChallenge::new("atlanta.com", "84a4cc6f3082121f32b42a2187831a9e");
+    challenge.algorithm = Some("MD5".into());
+    challenge.qop = vec!["auth".into(), "auth-int".into()];
+    challenge.stale = true;
+    let header = Header::WwwAuthenticate(challenge);
     assert_eq!(
         Ok((remains.as_ref(), header)),
-        parse_www_authenticate_header::<VerboseError<&[u8]>>(b"WWW-Authenticate: Digest key=value \r\n")
+        parse_www_authenticate_header::<VerboseError<&[u8]>>(b"WWW-Authenticate: Digest stale=TRUE ,algorithm=MD5,  qop=\"auth, auth-int\", nonce=\"84a4cc6f3082121f32b42a2187831a9e\" , realm=\"atlanta.com\" \r\n")
     );
 }
+
+#[test]
+fn read_other_params() {
+    let header = parse_www_authenticate_header::<VerboseError<&[u8]>>(b"WWW-Authenticate: Digest realm=\"biloxi, com\", domain=\"sip:ss1.carrier.com\", nonce=abc\r\n").unwrap().1;
+    if let Header::WwwAuthenticate(challenge) = header {
+        assert_eq!("biloxi, com", challenge.realm);
+        assert_eq!("abc", challenge.nonce);
+        assert_eq!(vec![("domain".to_string(), "sip:ss1.carrier.com".to_string())], challenge.params);
+    } else {
+        panic!("expected a WWW-Authenticate header");
+    }
+}
+
+#[test]
+fn read_missing_nonce() {
+    assert!(parse_www_authenticate_header::<VerboseError<&[u8]>>(b"WWW-Authenticate: Digest realm=\"atlanta.com\"\r\n").is_err());
+}
+
+#[test]
+fn round_trip() {
+    assert_round_trip("WWW-Authenticate: Digest realm=\"atlanta.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", opaque=\"\", algorithm=MD5, qop=\"auth,auth-int\", stale=true");
+}
+
+#[test]
+fn round_trip_other_params() {
+    assert_round_trip("WWW-Authenticate: Digest realm=\"atlanta.com\", nonce=\"abc\", domain=\"sip:ss1.carrier.com\", ext=\"a \\\"b\\\"\", other=\"c\"");
+}
+
+#[test]
+fn round_trip_escaped_quote() {
+    let header = assert_round_trip("WWW-Authenticate: Digest realm=\"a\\\"b\", nonce=\"n\\\\1\", opaque=\"o\\\"\"");
+    match &header {
+        Header::WwwAuthenticate(challenge) => {
+            assert_eq!("a\"b", challenge.realm);
+            assert_eq!("n\\1", challenge.nonce);
+            assert_eq!(Some("o\"".to_string()), challenge.opaque);
+        },
+        _ => panic!("expected a WWW-Authenticate header"),
+    }
+}
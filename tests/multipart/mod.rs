@@ -0,0 +1,94 @@
+use libsip::*;
+
+use nom::error::VerboseError;
+
+const BODY: &[u8] = b"preamble\r\n\
+--boundary1\r\n\
+Content-Type: application/sdp\r\n\
+\r\n\
+v=0\r\n\
+o=- 1 1 IN IP4 192.0.2.1\r\n\
+\r\n\
+--boundary1\r\n\
+Content-Type: application/isup;version=itu-t92+\r\n\
+Content-Disposition: signal;handling=optional\r\n\
+\r\n\
+\x01\x00\x49\x00\r\n\
+--boundary1--\r\n";
+
+#[test]
+fn read() {
+    let (remains, body) = parse_multipart::<VerboseError<&[u8]>>(BODY, "boundary1").unwrap();
+    assert!(remains.is_empty());
+    assert_eq!("boundary1", body.boundary);
+    assert_eq!(2, body.parts.len());
+
+    let sdp = &body.parts[0];
+    assert_eq!(Some(&MediaType::new("application", "sdp")), sdp.content_type());
+    assert_eq!(None, sdp.content_disposition());
+    assert_eq!(b"v=0\r\no=- 1 1 IN IP4 192.0.2.1\r\n".to_vec(), sdp.body);
+
+    let isup = &body.parts[1];
+    assert_eq!(
+        Some(&MediaType::new("application", "isup").param("version", "itu-t92+")),
+        isup.content_type()
+    );
+    assert_eq!(Some(Handling::Optional), isup.content_disposition().and_then(|d| d.handling()));
+    assert_eq!(vec![0x01, 0x00, 0x49, 0x00], isup.body);
+}
+
+#[test]
+fn write() {
+    let body = MultipartBody::new("boundary1")
+        .with_part(BodyPart::new(b"v=0\r\n".to_vec()).with_header(Header::ContentType(MediaType::new("application", "sdp"))))
+        .with_part(BodyPart::new(b"<xml/>".to_vec()));
+    assert_eq!(
+        "--boundary1\r\nContent-Type: application/sdp\r\n\r\nv=0\r\n\r\n\
+         --boundary1\r\n\r\n<xml/>\r\n\
+         --boundary1--\r\n",
+        String::from_utf8(body.to_bytes()).unwrap()
+    );
+}
+
+#[test]
+fn round_trip() {
+    let body = MultipartBody::new("unique-boundary-1")
+        .with_part(BodyPart::new(b"v=0\r\n".to_vec()).with_header(Header::ContentType(MediaType::new("application", "sdp"))))
+        .with_part(
+            BodyPart::new(b"<resource-lists/>".to_vec())
+                .with_header(Header::ContentType(MediaType::new("application", "resource-lists+xml")))
+                .with_header(Header::ContentDisposition(ContentDisposition::new("recipient-list")))
+        )
+        .with_part(BodyPart::new(vec![]));
+    let bytes = body.to_bytes();
+    let (remains, parsed) = parse_multipart::<VerboseError<&[u8]>>(&bytes, "unique-boundary-1").unwrap();
+    assert!(remains.is_empty());
+    assert_eq!(body, parsed);
+}
+
+#[test]
+fn read_message_body() {
+    let input = b"MESSAGE sip:bob@example.com SIP/2.0\r\n\
+Content-Type: multipart/mixed;boundary=\"simple boundary\"\r\n\r\n\
+--simple boundary\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+Hello\r\n\
+--simple boundary--\r\n\
+epilogue";
+    let (_, message) = parse_message::<VerboseError<&[u8]>>(input).unwrap();
+    let boundary = message.headers().iter().find_map(|h| match h {
+        Header::ContentType(ty) => ty.get_param("boundary").map(String::from),
+        _ => None,
+    }).unwrap();
+    let (remains, body) = parse_multipart::<VerboseError<&[u8]>>(message.body(), &boundary).unwrap();
+    assert_eq!(b"epilogue", remains);
+    assert_eq!(1, body.parts.len());
+    assert_eq!(b"Hello".to_vec(), body.parts[0].body);
+}
+
+#[test]
+fn read_invalid() {
+    assert!(parse_multipart::<VerboseError<&[u8]>>(b"no delimiter here", "b").is_err());
+    assert!(parse_multipart::<VerboseError<&[u8]>>(b"--b\r\n\r\nunterminated", "b").is_err());
+}
@@ -0,0 +1,58 @@
+use libsip::*;
+use libsip::sdp::*;
+
+use nom::error::VerboseError;
+
+#[test]
+fn build() {
+    let audio = MediaDescription::new("audio", 49172, "RTP/AVP")
+        .with_rtpmap(RtpMap::new(0, "PCMU", 8000))
+        .with_rtpmap(RtpMap::new(97, "opus", 48000).params("2"))
+        .with_format("101")
+        .with_attribute(Attribute::value("rtpmap", "101 telephone-event/8000"))
+        .with_attribute(Attribute::property("sendrecv"));
+    let sdp = SessionDescriptionBuilder::new()
+        .media(audio)
+        .connection(Connection::new("192.0.2.201"))
+        .origin(Origin::new("bob", 2890844730, 2890844730, "host.biloxi.example.com"))
+        .attribute(Attribute::value("group", "BUNDLE audio"))
+        .build()
+        .unwrap();
+    let expected = "v=0\r\n\
+o=bob 2890844730 2890844730 IN IP4 host.biloxi.example.com\r\n\
+s=-\r\n\
+c=IN IP4 192.0.2.201\r\n\
+t=0 0\r\n\
+a=group:BUNDLE audio\r\n\
+m=audio 49172 RTP/AVP 0 97 101\r\n\
+a=rtpmap:0 PCMU/8000\r\n\
+a=rtpmap:97 opus/48000/2\r\n\
+a=rtpmap:101 telephone-event/8000\r\n\
+a=sendrecv\r\n";
+    assert_eq!(expected.as_bytes(), &sdp.to_bytes()[..]);
+    assert_eq!(Ok((&b""[..], sdp)), parse_sdp::<VerboseError<&[u8]>>(expected.as_bytes()));
+}
+
+#[test]
+fn build_rtpmap_existing_format() {
+    let audio = MediaDescription::new("audio", 4000, "RTP/AVP")
+        .with_format("8")
+        .with_rtpmap(RtpMap::new(8, "PCMA", 8000));
+    assert_eq!(vec!["8"], audio.formats);
+}
+
+#[test]
+fn build_requires_origin_and_connection() {
+    assert!(SessionDescriptionBuilder::new().build().is_err());
+    let origin = Origin::new("-", 1, 1, "10.0.0.1");
+    assert!(SessionDescriptionBuilder::new()
+        .origin(origin.clone())
+        .media(MediaDescription::new("audio", 4000, "RTP/AVP"))
+        .build()
+        .is_err());
+    assert!(SessionDescriptionBuilder::new()
+        .origin(origin)
+        .media(MediaDescription::new("audio", 4000, "RTP/AVP").with_connection(Connection::new("10.0.0.1")))
+        .build()
+        .is_ok());
+}
@@ -0,0 +1,87 @@
+use libsip::*;
+use libsip::sdp::*;
+
+use nom::error::VerboseError;
+
+const OFFER: &str = "v=0\r\n\
+o=alice 2890844526 2890844526 IN IP4 host.atlanta.example.com\r\n\
+s=-\r\n\
+i=A Seminar on the session description protocol\r\n\
+c=IN IP4 192.0.2.101\r\n\
+b=AS:64\r\n\
+t=0 0\r\n\
+a=sendrecv\r\n\
+m=audio 49170 RTP/AVP 0 8 97\r\n\
+i=voice\r\n\
+c=IN IP4 192.0.2.102/127\r\n\
+a=rtpmap:0 PCMU/8000\r\n\
+a=rtpmap:8 PCMA/8000\r\n\
+a=rtpmap:97 opus/48000/2\r\n\
+a=ptime:20\r\n\
+m=video 51372/2 RTP/AVP 31\r\n\
+a=rtpmap:31 H261/90000\r\n";
+
+#[test]
+fn read() {
+    let (rest, sdp) = parse_sdp::<VerboseError<&[u8]>>(OFFER.as_bytes()).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(0, sdp.version);
+    assert_eq!(Origin::new("alice", 2890844526, 2890844526, "host.atlanta.example.com"), sdp.origin);
+    assert_eq!("-", sdp.session_name);
+    assert_eq!(Some(Connection::new("192.0.2.101")), sdp.connection);
+    assert_eq!(vec![Timing { start: 0, stop: 0 }], sdp.timing);
+    assert_eq!(Some(&Attribute::property("sendrecv")), sdp.attribute("sendrecv"));
+    assert_eq!(2, sdp.media.len());
+
+    let audio = &sdp.media[0];
+    assert_eq!("audio", audio.media);
+    assert_eq!(49170, audio.port);
+    assert_eq!(None, audio.port_count);
+    assert_eq!("RTP/AVP", audio.protocol);
+    assert_eq!(vec!["0", "8", "97"], audio.formats);
+    assert_eq!(Some(&Attribute::value("ptime", "20")), audio.attribute("ptime"));
+    assert_eq!(Some("192.0.2.102"), sdp.connection_for(audio).map(Connection::host));
+    assert_eq!(
+        vec![
+            RtpMap { payload_type: 0, encoding_name: "PCMU".into(), clock_rate: 8000, encoding_params: None },
+            RtpMap { payload_type: 8, encoding_name: "PCMA".into(), clock_rate: 8000, encoding_params: None },
+            RtpMap { payload_type: 97, encoding_name: "opus".into(), clock_rate: 48000, encoding_params: Some("2".into()) },
+        ],
+        audio.rtpmaps()
+    );
+
+    let video = &sdp.media[1];
+    assert_eq!(Some(2), video.port_count);
+    assert_eq!(Some("192.0.2.101"), sdp.connection_for(video).map(Connection::host));
+}
+
+#[test]
+fn round_trip() {
+    let (_, sdp) = parse_sdp::<VerboseError<&[u8]>>(OFFER.as_bytes()).unwrap();
+    assert_eq!(OFFER, format!("{}", sdp));
+}
+
+#[test]
+fn read_bare_newlines() {
+    let input = b"v=0\no=- 1 1 IN IP4 10.0.0.1\ns= \nt=0 0\nm=audio 4000 RTP/AVP 0\na=rtpmap:0 PCMU/8000";
+    let (rest, sdp) = parse_sdp::<VerboseError<&[u8]>>(input).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(" ", sdp.session_name);
+    assert_eq!(1, sdp.media[0].rtpmaps().len());
+}
+
+#[test]
+fn read_invalid() {
+    assert!(parse_sdp::<VerboseError<&[u8]>>(b"v=0\r\ns=-\r\nt=0 0\r\n").is_err());
+    assert!(parse_sdp::<VerboseError<&[u8]>>(b"v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\n").is_err());
+    assert!(parse_sdp::<VerboseError<&[u8]>>(b"v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 70000 RTP/AVP 0\r\n").is_err());
+}
+
+#[test]
+fn read_from_message_body() {
+    let mut input = b"INVITE sip:bob@biloxi.com SIP/2.0\r\nContent-Type: application/sdp\r\n".to_vec();
+    input.extend_from_slice(format!("Content-Length: {}\r\n\r\n{}", OFFER.len(), OFFER).as_bytes());
+    let (_, message) = parse_message::<VerboseError<&[u8]>>(&input).unwrap();
+    let (_, sdp) = parse_sdp::<VerboseError<&[u8]>>(message.body()).unwrap();
+    assert_eq!(2, sdp.media.len());
+}
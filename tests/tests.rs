@@ -1,3 +1,6 @@
 mod core;
 mod headers;
 mod uri;
+mod sdp;
+mod multipart;
+mod client;
@@ -16,3 +16,27 @@ fn read_auth() {
         parse_uriauth::<VerboseError<&[u8]>>(b"username:password@")
     );
 }
+
+#[test]
+fn read_escaped_auth() {
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), uri_auth!("alice@example.com"))),
+        parse_uriauth::<VerboseError<&[u8]>>(b"alice%40example.com@")
+    );
+
+    let remains = vec![];
+    assert_eq!(
+        Ok((remains.as_ref(), uri_auth!("+1 555", "p@ss"))),
+        parse_uriauth::<VerboseError<&[u8]>>(b"+1%20555:p%40ss@")
+    );
+
+    assert!(parse_uriauth::<VerboseError<&[u8]>>(b"alice%4@").is_err());
+    assert!(parse_uriauth::<VerboseError<&[u8]>>(b"alice%zz@").is_err());
+}
+
+#[test]
+fn write_escaped_auth() {
+    assert_eq!("alice%40example.com".to_string(), format!("{}", uri_auth!("alice@example.com")));
+    assert_eq!("+1%20555:p%40ss".to_string(), format!("{}", uri_auth!("+1 555", "p@ss")));
+}
@@ -52,3 +52,28 @@ fn write_ip_address() {
     let domain = Domain::Ipv4(Ipv4Addr::new(10, 1, 10, 1), Some(8080));
     assert_eq!("10.1.10.1:8080".to_string(), format!("{}", domain));
 }
+
+#[test]
+fn read_ipv6_address() {
+    let remains = vec![b' '];
+    let domain = ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    assert_eq!(Ok((remains.as_ref(), domain)), parse_domain::<VerboseError<&[u8]>>(b"[2001:db8::1] "));
+
+    let remains = vec![b';'];
+    let domain = ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1, 5060);
+    assert_eq!(
+        Ok((remains.as_ref(), domain)),
+        parse_domain::<VerboseError<&[u8]>>(b"[2001:db8::1]:5060;")
+    );
+
+    assert!(parse_domain::<VerboseError<&[u8]>>(b"[2001:db8::1:5060").is_err());
+}
+
+#[test]
+fn write_ipv6_address() {
+    let domain = ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    assert_eq!("[2001:db8::1]".to_string(), format!("{}", domain));
+
+    let domain = ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1, 5060);
+    assert_eq!("[2001:db8::1]:5060".to_string(), format!("{}", domain));
+}
@@ -1,3 +1,4 @@
 mod auth;
 mod domain;
+mod params;
 mod uri;
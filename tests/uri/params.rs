@@ -0,0 +1,102 @@
+use libsip::*;
+use libsip::uri::parse_params;
+use nom::error::VerboseError;
+
+#[test]
+fn read_params() {
+    let remains = vec![b' '];
+    let params = vec![
+        UriParam::Lr,
+        UriParam::Maddr(ip_domain!(239, 255, 255, 1)),
+        UriParam::Ttl(15),
+        UriParam::User(UserParam::Phone),
+        UriParam::Other("custom".into(), Some("value".into())),
+        UriParam::Other("flag".into(), None),
+    ];
+    assert_eq!(
+        Ok((remains.as_ref(), params)),
+        parse_params::<VerboseError<&[u8]>>(b";lr;maddr=239.255.255.1;ttl=15;user=phone;custom=value;flag ")
+    );
+
+    let remains = vec![b' '];
+    let params = vec![
+        UriParam::Other("ttl".into(), Some("300".into())),
+        UriParam::Other("user".into(), Some("dialstring".into())),
+    ];
+    assert_eq!(
+        Ok((remains.as_ref(), params)),
+        parse_params::<VerboseError<&[u8]>>(b";ttl=300;user=dialstring ")
+    );
+
+    let remains = vec![b' '];
+    let params = vec![
+        UriParam::RPort(Some(5061)),
+        UriParam::Other("rport".into(), Some("70000".into())),
+    ];
+    assert_eq!(
+        Ok((remains.as_ref(), params)),
+        parse_params::<VerboseError<&[u8]>>(b";rport=5061;rport=70000 ")
+    );
+}
+
+#[test]
+fn write_params() {
+    let uri = Uri::sip(domain!("example.com"))
+        .parameter(UriParam::Other("flag".into(), None))
+        .parameter(UriParam::Lr)
+        .parameter(UriParam::User(UserParam::Ip))
+        .parameter(UriParam::Maddr(domain!("proxy.example.com")))
+        .parameter(UriParam::Ttl(1));
+    assert_eq!(
+        "sip:example.com;flag;lr;user=ip;maddr=proxy.example.com;ttl=1".to_string(),
+        format!("{}", uri)
+    );
+}
+
+#[test]
+fn param_accessors() {
+    let uri = parse_uri::<VerboseError<&[u8]>>(b"sip:+15551234567@example.com;user=phone;lr;ttl=4;maddr=10.0.0.1;transport=TCP")
+        .unwrap()
+        .1;
+    assert_eq!(Some(UserParam::Phone), uri.user_param());
+    assert_eq!(Some(4), uri.ttl());
+    assert_eq!(Some(&ip_domain!(10, 0, 0, 1)), uri.maddr());
+    assert_eq!(Some(Transport::Tcp), uri.transport());
+    assert!(uri.lr());
+
+    let uri = Uri::sip(domain!("example.com"));
+    assert_eq!(None, uri.user_param());
+    assert_eq!(None, uri.ttl());
+    assert_eq!(None, uri.maddr());
+    assert_eq!(None, uri.transport());
+    assert!(!uri.lr());
+}
+
+#[test]
+fn websocket_transport() {
+    let input = "sips:alice@df7jal23ls0d.invalid;transport=wss";
+    let uri = parse_uri::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+    assert_eq!(Some(Transport::Wss), uri.transport());
+    assert_eq!(input, format!("{}", uri));
+
+    let uri = parse_uri::<VerboseError<&[u8]>>(b"sip:alice@example.com;transport=WS").unwrap().1;
+    assert_eq!(Some(Transport::Ws), uri.transport());
+    assert_eq!("sip:alice@example.com;transport=ws", format!("{}", uri));
+
+    let input = b"Contact: <sips:alice@df7jal23ls0d.invalid;transport=wss>;expires=600\r\n";
+    let header = parse_header::<VerboseError<&[u8]>>(input).unwrap().1;
+    assert_eq!("Contact: <sips:alice@df7jal23ls0d.invalid;transport=wss>;expires=600", format!("{}", header));
+}
+
+#[test]
+fn maddr_round_trip() {
+    let input = "sip:alice@atlanta.com;maddr=239.255.255.1;ttl=15";
+    let uri = parse_uri::<VerboseError<&[u8]>>(format!("{} ", input).as_bytes()).unwrap().1;
+    assert_eq!(Some(&ip_domain!(239, 255, 255, 1)), uri.maddr());
+    assert_eq!(input, uri.to_string());
+
+    let input = "sip:alice@atlanta.com;maddr=proxy.atlanta.com";
+    let uri = parse_uri::<VerboseError<&[u8]>>(format!("{} ", input).as_bytes()).unwrap().1;
+    assert_eq!(Some(&domain!("proxy.atlanta.com")), uri.maddr());
+    assert_eq!(input, uri.to_string());
+}
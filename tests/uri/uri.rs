@@ -111,7 +111,7 @@ fn write_uri() {
         .parameter(UriParam::Transport(Transport::Udp))
         .auth(uri_auth!("username", "password"));
     assert_eq!(
-        "sip:username:password@hostname.com:8080;transport=UDP",
+        "sip:username:password@hostname.com:8080;transport=udp",
         format!("{}", uri)
     );
 
@@ -119,7 +119,224 @@ fn write_uri() {
         .parameter(UriParam::Transport(Transport::Udp))
         .auth(uri_auth!("username", "password"));
     assert_eq!(
-        "username:password@hostname.com:8080;transport=UDP",
+        "username:password@hostname.com:8080;transport=udp",
         format!("{}", uri)
     );
 }
+
+#[test]
+fn read_sips_uri() {
+    let expected_remains = vec![b' '];
+    let expected = Uri::sips(domain!("hostname.com")).auth(uri_auth!("username"));
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"sips:username@hostname.com ")
+    );
+
+    let expected_remains = vec![b' '];
+    let expected = Uri::sips(domain!("hostname.com")).auth(uri_auth!("username"));
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"SIPS:username@hostname.com ")
+    );
+
+    let expected_remains = vec![b' '];
+    let expected = Uri::sip(domain!("hostname.com"));
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"SIP:hostname.com ")
+    );
+}
+
+#[test]
+fn write_sips_uri() {
+    let uri = Uri::sips(domain!("hostname.com")).auth(uri_auth!("username"));
+    assert_eq!("sips:username@hostname.com".to_string(), format!("{}", uri));
+
+    let uri = parse_uri::<VerboseError<&[u8]>>(b"SIPS:username@hostname.com").unwrap().1;
+    assert_eq!("sips:username@hostname.com".to_string(), format!("{}", uri));
+}
+
+#[test]
+fn read_tel_uri() {
+    let expected_remains = vec![b' '];
+    assert_eq!(
+        Ok((expected_remains.as_ref(), Uri::tel("+15551234567"))),
+        parse_uri::<VerboseError<&[u8]>>(b"tel:+15551234567 ")
+    );
+
+    let expected_remains = vec![b' '];
+    let expected = Uri::tel("7042")
+        .parameter(UriParam::Other("phone-context".into(), Some("example.com".into())));
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"tel:7042;phone-context=example.com ")
+    );
+
+    let expected_remains = vec![b'>'];
+    let expected = Uri::tel("+1-555-123-4567");
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"TEL:+1-555-123-4567>")
+    );
+}
+
+#[test]
+fn write_tel_uri() {
+    let uri = Uri::tel("+15551234567")
+        .parameter(UriParam::Other("phone-context".into(), Some("example.com".into())));
+    assert_eq!(
+        "tel:+15551234567;phone-context=example.com".to_string(),
+        format!("{}", uri)
+    );
+}
+
+#[test]
+fn read_escaped_uri() {
+    let expected_remains = vec![b' '];
+    let expected = Uri::sip(domain!("gateway"))
+        .auth(uri_auth!("alice@example.com"))
+        .parameter(UriParam::Other("note".into(), Some("a b".into())));
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"sip:alice%40example.com@gateway;note=a%20b ")
+    );
+
+    assert!(parse_uri::<VerboseError<&[u8]>>(b"sip:alice%4g@gateway ").is_err());
+    assert!(parse_uri::<VerboseError<&[u8]>>(b"sip:gateway;note=a%2 ").is_err());
+}
+
+#[test]
+fn write_escaped_uri() {
+    let uri = Uri::sip(domain!("gateway"))
+        .auth(uri_auth!("alice@example.com"))
+        .parameter(UriParam::Other("note".into(), Some("a b".into())));
+    assert_eq!(
+        "sip:alice%40example.com@gateway;note=a%20b".to_string(),
+        format!("{}", uri)
+    );
+}
+
+#[test]
+fn read_ipv6_uri() {
+    let expected_remains = vec![b' '];
+    let expected = Uri::sip(ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1, 5060)).auth(uri_auth!("user"));
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"sip:user@[2001:db8::1]:5060 ")
+    );
+
+    let uri = Uri::sip(ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1, 5060)).auth(uri_auth!("user"));
+    assert_eq!("sip:user@[2001:db8::1]:5060".to_string(), format!("{}", uri));
+}
+
+#[test]
+fn read_host_port_without_user() {
+    let expected_remains = vec![b'>'];
+    let expected = Uri::sip(domain!("biloxi.com", 5060))
+        .parameter(UriParam::Transport(Transport::Tcp));
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"sip:biloxi.com:5060;transport=tcp>")
+    );
+
+    let uri = parse_uri::<VerboseError<&[u8]>>(b"sips:10.0.0.1:5061;lr;transport=TLS").unwrap().1;
+    assert_eq!(None, uri.auth);
+    assert_eq!(ip_domain!(10, 0, 0, 1, 5061), uri.host);
+    assert!(uri.lr());
+    assert_eq!(Some(Transport::Tls), uri.transport());
+    assert_eq!("sips:10.0.0.1:5061;lr;transport=tls", format!("{}", uri));
+}
+
+#[test]
+fn read_uri_headers() {
+    let expected_remains = vec![b'>'];
+    let expected = Uri::sip(domain!("example.com"))
+        .auth(uri_auth!("alice"))
+        .header("subject", "Project")
+        .header("priority", "urgent");
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"sip:alice@example.com?subject=Project&priority=urgent>")
+    );
+
+    let expected_remains = vec![];
+    let expected = Uri::sip(domain!("example.com"))
+        .auth(uri_auth!("alice"))
+        .parameter(UriParam::Transport(Transport::Tcp))
+        .header("subject", "Project X & Y");
+    assert_eq!(
+        Ok((expected_remains.as_ref(), expected)),
+        parse_uri::<VerboseError<&[u8]>>(b"sip:alice@example.com;transport=TCP?subject=Project%20X%20%26%20Y")
+    );
+
+    assert!(parse_uri::<VerboseError<&[u8]>>(b"sip:alice@example.com?subject=%ZZ").is_err());
+}
+
+#[test]
+fn write_uri_headers() {
+    let uri = Uri::sip(domain!("example.com"))
+        .auth(uri_auth!("alice"))
+        .header("subject", "Project X & Y")
+        .header("priority", "urgent");
+    assert_eq!(
+        "sip:alice@example.com?subject=Project%20X%20%26%20Y&priority=urgent".to_string(),
+        format!("{}", uri)
+    );
+}
+
+#[test]
+fn uri_matches() {
+    let parse = |input: &str| parse_uri::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+
+    assert!(parse("sip:Host.com").matches(&parse("sip:host.com")));
+    assert!(parse("SIP:alice@host.com").matches(&parse("sip:alice@HOST.COM")));
+    assert!(!parse("sip:Alice@h").matches(&parse("sip:alice@h")));
+    assert!(!parse("sip:alice@h").matches(&parse("sips:alice@h")));
+    assert!(!parse("sip:alice@h").matches(&parse("sip:alice@h:5060")));
+    assert!(!parse("sip:alice:secret@h").matches(&parse("sip:alice:Secret@h")));
+
+    assert!(parse("sip:alice@h;transport=TCP;lr").matches(&parse("sip:alice@h;lr;transport=tcp")));
+    assert!(!parse("sip:alice@h").matches(&parse("sip:alice@h;transport=udp")));
+    assert!(!parse("sip:alice@h;ttl=1").matches(&parse("sip:alice@h")));
+    assert!(!parse("sip:alice@h;maddr=10.0.0.1").matches(&parse("sip:alice@h")));
+    assert!(parse("sip:alice@h;newparam=5").matches(&parse("sip:alice@h")));
+    assert!(parse("sip:alice@h;newparam=ABC").matches(&parse("sip:alice@h;newparam=abc")));
+    assert!(!parse("sip:alice@h;newparam=5").matches(&parse("sip:alice@h;newparam=6")));
+
+    assert!(parse("sip:alice@h?subject=Hi").matches(&parse("sip:alice@h?Subject=Hi")));
+    assert!(!parse("sip:alice@h?subject=Hi").matches(&parse("sip:alice@h")));
+}
+
+#[test]
+fn strip_for_request_uri() {
+    let uri = parse_uri::<VerboseError<&[u8]>>(b"sip:bob@biloxi.com;transport=tcp;method=REGISTER;maddr=239.255.255.1?to=sip:bob%40biloxi.com&priority=urgent ")
+        .unwrap()
+        .1;
+    let stripped = uri.strip_for_request_uri();
+    assert!(stripped.headers.is_empty());
+    assert_eq!(Some(Transport::Tcp), stripped.transport());
+    assert_eq!(Some(&ip_domain!(239, 255, 255, 1)), stripped.maddr());
+    assert_eq!("sip:bob@biloxi.com;transport=tcp;maddr=239.255.255.1", stripped.to_string());
+    assert_eq!(2, uri.headers.len());
+}
+
+#[test]
+fn host_port_defaults() {
+    let parse = |input: &str| parse_uri::<VerboseError<&[u8]>>(input.as_bytes()).unwrap().1;
+    let cases = vec![
+        ("sip:alice@atlanta.com", domain!("atlanta.com"), 5060),
+        ("sip:alice@atlanta.com:5070", domain!("atlanta.com"), 5070),
+        ("sips:alice@atlanta.com", domain!("atlanta.com"), 5061),
+        ("sips:alice@atlanta.com;transport=tcp", domain!("atlanta.com"), 5061),
+        ("sip:alice@atlanta.com;transport=tcp", domain!("atlanta.com"), 5060),
+        ("sip:alice@atlanta.com;transport=tls", domain!("atlanta.com"), 5061),
+        ("sip:alice@atlanta.com;transport=ws", domain!("atlanta.com"), 80),
+        ("sips:alice@atlanta.com;transport=ws", domain!("atlanta.com"), 443),
+        ("sip:10.0.0.1:6060;transport=tls", ip_domain!(10, 0, 0, 1), 6060),
+        ("sip:[2001:db8::1]", ip_domain!(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 5060),
+    ];
+    for (input, domain, port) in cases {
+        assert_eq!((domain, port), parse(input).host_port(), "{}", input);
+    }
+}